@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+use crate::{
+	input::{Input, InputFile},
+	parse::{parse_code, parse_fields},
+};
+
+/// Entry from `BidiMirroring.txt` with the `Bidi_Mirroring_Glyph` for a
+/// mirrored character.
+///
+/// This provides the glyph for characters flagged as [`Mirrored::Yes`] in
+/// [`UnicodeData`](crate::UnicodeData), though not every mirrored character
+/// has a mirroring glyph.
+///
+/// ```
+/// # use ucd_parser::mirror_of;
+/// assert_eq!(mirror_of(0x28), Some(0x29));
+/// assert_eq!(mirror_of(0x29), Some(0x28));
+/// assert_eq!(mirror_of(0x41), None);
+/// ```
+///
+/// [`Mirrored::Yes`]: crate::Mirrored::Yes
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MirrorPair {
+	/// Codepoint for the mirrored character.
+	pub code: u32,
+
+	/// Codepoint for the mirroring glyph.
+	pub mirror: u32,
+}
+
+impl MirrorPair {
+	/// List of mirroring pairs from the UCD data in file order. Lazy-loaded
+	/// from `BidiMirroring.txt`.
+	pub fn list() -> &'static [MirrorPair] {
+		static LIST: Lazy<Box<[MirrorPair]>> = Lazy::new(|| {
+			let input = Input::get(InputFile::BidiMirroring);
+			let lines = input.lines();
+			let list = lines.map(|x| MirrorPair::parse(x).unwrap());
+			let list = list.collect::<Vec<_>>();
+			list.into_boxed_slice()
+		});
+		&LIST
+	}
+
+	/// Parse a `CODE; MIRROR` line. A trailing comment, which in the UCD data
+	/// has the character name and a `[BEST FIT]` mark, is ignored.
+	pub fn parse<T: AsRef<str>>(input: T) -> Result<Self, String> {
+		let input = input.as_ref();
+		let error = |err: String| format!("bidi mirroring: {} -- in `{}`", err, input);
+		let line = input.split('#').next().unwrap_or_default();
+		let [code, mirror] = parse_fields::<2>(line).map_err(error)?;
+		let code = parse_code(code).map_err(error)?;
+		let mirror = parse_code(mirror).map_err(error)?;
+		Ok(MirrorPair { code, mirror })
+	}
+}
+
+impl std::fmt::Display for MirrorPair {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{:04X}; {:04X}", self.code, self.mirror)
+	}
+}
+
+/// Returns the `Bidi_Mirroring_Glyph` for a codepoint, or [`None`] if it is
+/// not listed in `BidiMirroring.txt`.
+pub fn mirror_of(code: u32) -> Option<u32> {
+	static MAP: Lazy<HashMap<u32, u32>> = Lazy::new(|| {
+		let list = MirrorPair::list().iter();
+		list.map(|x| (x.code, x.mirror)).collect()
+	});
+	MAP.get(&code).copied()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_from_string() {
+		let pair = MirrorPair::parse("0028; 0029 # LEFT PARENTHESIS").unwrap();
+		assert_eq!(
+			pair,
+			MirrorPair {
+				code: 0x28,
+				mirror: 0x29
+			}
+		);
+		assert_eq!(pair.to_string(), "0028; 0029");
+
+		let pair = MirrorPair::parse("2209; 220C # [BEST FIT] NOT AN ELEMENT OF").unwrap();
+		assert_eq!(
+			pair,
+			MirrorPair {
+				code: 0x2209,
+				mirror: 0x220C
+			}
+		);
+
+		let err = MirrorPair::parse("0028 # LEFT PARENTHESIS").unwrap_err();
+		assert!(err.contains("invalid row format"));
+		assert!(err.contains("-- in `0028 # LEFT PARENTHESIS`"));
+
+		assert!(MirrorPair::parse("0028; xx").is_err());
+	}
+
+	#[test]
+	fn can_load_from_ucd() {
+		let list = MirrorPair::list();
+		assert_eq!(list.len(), 428);
+		assert_eq!(
+			list[0],
+			MirrorPair {
+				code: 0x28,
+				mirror: 0x29
+			}
+		);
+		assert_eq!(
+			list[1],
+			MirrorPair {
+				code: 0x29,
+				mirror: 0x28
+			}
+		);
+	}
+
+	#[test]
+	fn returns_mirror_for_codepoint() {
+		assert_eq!(mirror_of(0x28), Some(0x29));
+		assert_eq!(mirror_of(0x29), Some(0x28));
+		assert_eq!(mirror_of(0x2264), Some(0x2265));
+		assert_eq!(mirror_of(0x2265), Some(0x2264));
+		assert_eq!(mirror_of(0x41), None);
+	}
+
+	#[test]
+	fn mirror_lookup_is_symmetric() {
+		for pair in MirrorPair::list() {
+			if mirror_of(pair.mirror).is_some() {
+				assert_eq!(mirror_of(pair.mirror), Some(pair.code), "for {}", pair);
+			}
+		}
+	}
+}
@@ -42,6 +42,78 @@ impl<'a> Block<'a> {
 		&BLOCKS
 	}
 
+	/// Return the block coverage for a plane, sorted by codepoint. Gaps
+	/// between blocks are filled with `No_Block` so that the entire plane is
+	/// covered, and block ranges are clipped to the plane.
+	pub fn planar_map(plane: u8) -> Vec<(RangeInclusive<u32>, &'static str)> {
+		let plane_sta = (plane as u32) << 16;
+		let plane_end = plane_sta + 0xFFFF;
+
+		let mut blocks = Self::list()
+			.iter()
+			.filter(|x| *x.range.start() <= plane_end && *x.range.end() >= plane_sta)
+			.collect::<Vec<_>>();
+		blocks.sort_by_key(|x| *x.range.start());
+
+		let mut output = Vec::new();
+		let mut next = plane_sta;
+		for block in blocks {
+			let sta = std::cmp::max(*block.range.start(), plane_sta);
+			let end = std::cmp::min(*block.range.end(), plane_end);
+			if sta < next {
+				continue;
+			}
+			if sta > next {
+				output.push((next..=sta - 1, "No_Block"));
+			}
+			output.push((sta..=end, block.name));
+			next = end + 1;
+		}
+		if next <= plane_end {
+			output.push((next..=plane_end, "No_Block"));
+		}
+		output
+	}
+
+	/// Find a block by name using the loose matching rules described in
+	/// [`Block::name`].
+	pub fn find(name: &str) -> Option<&'static Block<'static>> {
+		let name = normalize_block_name(name);
+		Self::list()
+			.iter()
+			.find(|x| normalize_block_name(x.name) == name)
+	}
+
+	/// Find the block containing a codepoint, or [`None`] if the codepoint is
+	/// not part of any block.
+	pub fn find_by_code(code: u32) -> Option<&'static Block<'static>> {
+		let blocks = Self::list();
+		let index = blocks.partition_point(|x| *x.range.end() < code);
+		blocks.get(index).filter(|x| x.contains(code))
+	}
+
+	/// Returns true if the block name matches the query, ignoring casing,
+	/// whitespace, hyphens, and underbars.
+	pub fn name_matches(&self, query: &str) -> bool {
+		normalize_block_name(self.name) == normalize_block_name(query)
+	}
+
+	/// Returns true if the codepoint is in the block range.
+	pub fn contains(&self, code: u32) -> bool {
+		self.range.contains(&code)
+	}
+
+	/// Number of codepoints in the block range.
+	#[allow(clippy::len_without_is_empty)]
+	pub fn len(&self) -> u32 {
+		let (sta, end) = (*self.range.start(), *self.range.end());
+		if sta > end {
+			return 0;
+		}
+		debug_assert!(end - sta < u32::MAX, "block length overflows u32");
+		end - sta + 1
+	}
+
 	pub fn new(range: RangeInclusive<u32>, name: &'a str) -> Self {
 		Block { range, name }
 	}
@@ -57,6 +129,20 @@ impl<'a> Block<'a> {
 	}
 }
 
+/// Normalize a block name for comparison by lowercasing it and removing
+/// whitespace, hyphens, and underbars.
+///
+/// ```
+/// # use ucd_parser::normalize_block_name;
+/// assert_eq!(normalize_block_name("Latin Extended-A"), "latinextendeda");
+/// ```
+pub fn normalize_block_name(name: &str) -> String {
+	name.chars()
+		.filter(|x| !x.is_whitespace() && *x != '-' && *x != '_')
+		.flat_map(|x| x.to_lowercase())
+		.collect()
+}
+
 impl<'a> std::fmt::Display for Block<'a> {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		write!(
@@ -107,11 +193,95 @@ mod tests {
 		assert!(error.contains("-- in `xx..00FF; some name`"))
 	}
 
+	#[test]
+	fn block_names_match_loosely() {
+		let block = Block::new(0x0100..=0x017F, "Latin Extended-A");
+		assert!(block.name_matches("Latin Extended-A"));
+		assert!(block.name_matches("latin extended a"));
+		assert!(block.name_matches("LATINEXTENDEDA"));
+		assert!(block.name_matches("Latin_Extended_A"));
+		assert!(!block.name_matches("Latin Extended-B"));
+	}
+
+	#[test]
+	fn can_find_block_by_name() {
+		let a = Block::find("Latin Extended-A").unwrap();
+		let b = Block::find("latin extended a").unwrap();
+		let c = Block::find("LATINEXTENDEDA").unwrap();
+		assert_eq!(a.range, 0x0100..=0x017F);
+		assert_eq!(a.name, "Latin Extended-A");
+		assert!(std::ptr::eq(a, b));
+		assert!(std::ptr::eq(a, c));
+
+		assert!(Block::find("No Such Block").is_none());
+	}
+
+	#[test]
+	fn contains_codepoints_in_range() {
+		let block = Block::find("Latin Extended-A").unwrap();
+		assert!(!block.contains(0x00FF));
+		assert!(block.contains(0x0100));
+		assert!(block.contains(0x0150));
+		assert!(block.contains(0x017F));
+		assert!(!block.contains(0x0180));
+	}
+
+	#[test]
+	fn returns_block_length() {
+		assert_eq!(Block::find("Basic Latin").unwrap().len(), 128);
+		assert_eq!(Block::find("Latin Extended-A").unwrap().len(), 128);
+		assert_eq!(Block::find("CJK Unified Ideographs").unwrap().len(), 0x5200);
+		assert_eq!(Block::new(0x41..=0x41, "single").len(), 1);
+		assert_eq!(Block::new(0..=0x10FFFF, "all").len(), 0x110000);
+	}
+
+	#[test]
+	fn can_find_block_by_code() {
+		let name = |code: u32| Block::find_by_code(code).map(|x| x.name);
+		assert_eq!(name(0x0000), Some("Basic Latin"));
+		assert_eq!(name(0x007F), Some("Basic Latin"));
+		assert_eq!(name(0x0080), Some("Latin-1 Supplement"));
+		assert_eq!(name(0x0100), Some("Latin Extended-A"));
+
+		// gap between blocks
+		assert_eq!(name(0x2FE0), None);
+		assert_eq!(name(0x40000), None);
+
+		let last = Block::list().last().unwrap();
+		assert_eq!(*last.range.end(), 0x10FFFF);
+		assert_eq!(name(0x10FFFF), Some(last.name));
+		assert_eq!(name(0x110000), None);
+	}
+
+	#[test]
+	fn planar_map_covers_entire_plane() {
+		fn check_coverage(plane: u8) -> Vec<(RangeInclusive<u32>, &'static str)> {
+			let map = Block::planar_map(plane);
+			let mut next = (plane as u32) << 16;
+			for (range, _) in map.iter() {
+				assert_eq!(*range.start(), next);
+				next = range.end() + 1;
+			}
+			assert_eq!(next, ((plane as u32) + 1) << 16);
+			map
+		}
+
+		let map = check_coverage(0);
+		assert_eq!(map[0], (0x0000..=0x007F, "Basic Latin"));
+		assert_eq!(map.last().unwrap().1, "Specials");
+
+		let map = check_coverage(3);
+		assert!(map.iter().any(|x| x.1 == "No_Block"));
+
+		let map = check_coverage(4);
+		assert_eq!(map, vec![(0x40000..=0x4FFFF, "No_Block")]);
+	}
+
 	#[test]
 	fn can_load_from_ucd() {
 		let source = include_ucd!("Blocks.txt");
 		let source = source.lines().collect::<Vec<_>>();
-		assert!(source.len() > 0);
+		assert!(!source.is_empty());
 
 		let blocks = Block::list();
 		let blocks = blocks.iter().map(|x| x.to_string()).collect::<Vec<_>>();
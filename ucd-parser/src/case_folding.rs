@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+use crate::{
+	input::{Input, InputFile},
+	parse::{parse_code, parse_code_sequence, parse_fields},
+};
+
+/// Case folding mapping for a codepoint from `CaseFolding.txt`.
+///
+/// Case folding maps characters to a common form so that strings can be
+/// compared case-insensitively. A single codepoint can have both a simple
+/// and a full mapping, so it can appear in more than one entry.
+///
+/// ```
+/// # use ucd_parser::{CaseFolding, FoldStatus};
+/// let entry = CaseFolding::parse("00DF; F; 0073 0073;").unwrap();
+/// assert_eq!(entry.code, 0xDF);
+/// assert_eq!(entry.status, FoldStatus::Full);
+/// assert_eq!(entry.mapping, vec![0x73, 0x73]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CaseFolding {
+	/// Codepoint being mapped.
+	pub code: u32,
+
+	/// Status of the mapping, which determines the folding it applies to.
+	pub status: FoldStatus,
+
+	/// Sequence of codepoints the code folds to.
+	pub mapping: Vec<u32>,
+}
+
+impl CaseFolding {
+	/// List of mappings from the UCD data in file order. Lazy-loaded from
+	/// `CaseFolding.txt`.
+	pub fn list() -> &'static [CaseFolding] {
+		static LIST: Lazy<Box<[CaseFolding]>> = Lazy::new(|| {
+			let input = Input::get(InputFile::CaseFolding);
+			let lines = input.lines();
+			let list = lines.map(|x| CaseFolding::parse(x).unwrap());
+			let list = list.collect::<Vec<_>>();
+			list.into_boxed_slice()
+		});
+		&LIST
+	}
+
+	pub fn parse(input: &str) -> Result<Self, String> {
+		let error = |err: String| format!("case folding: {} -- in `{}`", err, input);
+
+		// lines end with a `;` so there is an empty trailing field
+		let [code, status, mapping, _] = parse_fields::<4>(input).map_err(error)?;
+		let code = parse_code(code).map_err(error)?;
+		let status = FoldStatus::parse(status)
+			.ok_or_else(|| error(format!("`{}` is not a valid status", status)))?;
+		let mapping = parse_code_sequence(mapping).map_err(error)?;
+		if mapping.is_empty() {
+			return Err(error("empty mapping".to_string()));
+		}
+
+		Ok(CaseFolding {
+			code,
+			status,
+			mapping,
+		})
+	}
+}
+
+impl std::fmt::Display for CaseFolding {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{:04X}; {}; ", self.code, self.status)?;
+		for (index, code) in self.mapping.iter().enumerate() {
+			if index > 0 {
+				write!(f, " ")?;
+			}
+			write!(f, "{:04X}", code)?;
+		}
+		write!(f, ";")
+	}
+}
+
+/// Returns the simple case folding for a codepoint, which always maps to a
+/// single codepoint. Codepoints without a folding map to themselves.
+///
+/// This uses the [`FoldStatus::Common`] and [`FoldStatus::Simple`] mappings.
+/// If `turkic` is true, the [`FoldStatus::Turkic`] mappings take precedence
+/// for the dotted and dotless I.
+///
+/// ```
+/// # use ucd_parser::simple_fold;
+/// assert_eq!(simple_fold(0x41, false), 0x61);
+/// assert_eq!(simple_fold(0x49, true), 0x131);
+/// ```
+pub fn simple_fold(code: u32, turkic: bool) -> u32 {
+	static SIMPLE: Lazy<HashMap<u32, u32>> = Lazy::new(|| fold_map(FoldStatus::Simple));
+	static TURKIC: Lazy<HashMap<u32, u32>> = Lazy::new(|| fold_map(FoldStatus::Turkic));
+
+	fn fold_map(status: FoldStatus) -> HashMap<u32, u32> {
+		let list = CaseFolding::list().iter();
+		let list = list.filter(|x| x.status == FoldStatus::Common || x.status == status);
+		list.map(|x| (x.code, x.mapping[0])).collect()
+	}
+
+	let map = if turkic { &TURKIC } else { &SIMPLE };
+	match map.get(&code) {
+		Some(folded) => *folded,
+		None if turkic => SIMPLE.get(&code).copied().unwrap_or(code),
+		None => code,
+	}
+}
+
+/// Status for a [`CaseFolding`] mapping.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FoldStatus {
+	/// `C` common case folding, shared by both simple and full foldings.
+	Common,
+	/// `F` full case folding, mapping to multiple codepoints.
+	Full,
+	/// `S` simple case folding, for codepoints that also have a full mapping.
+	Simple,
+	/// `T` special case for uppercase I and dotted uppercase I, used only
+	/// for Turkic languages.
+	Turkic,
+}
+
+impl FoldStatus {
+	pub fn parse<T: AsRef<str>>(input: T) -> Option<Self> {
+		let status = match input.as_ref() {
+			"C" => FoldStatus::Common,
+			"F" => FoldStatus::Full,
+			"S" => FoldStatus::Simple,
+			"T" => FoldStatus::Turkic,
+			_ => return None,
+		};
+		Some(status)
+	}
+}
+
+impl std::fmt::Display for FoldStatus {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let output = match self {
+			FoldStatus::Common => "C",
+			FoldStatus::Full => "F",
+			FoldStatus::Simple => "S",
+			FoldStatus::Turkic => "T",
+		};
+		write!(f, "{}", output)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn status_supports_to_string() {
+		fn check(input: FoldStatus, expected: &'static str) {
+			assert_eq!(input.to_string(), expected);
+			assert_eq!(FoldStatus::parse(input.to_string()).expect(expected), input);
+		}
+
+		check(FoldStatus::Common, "C");
+		check(FoldStatus::Full, "F");
+		check(FoldStatus::Simple, "S");
+		check(FoldStatus::Turkic, "T");
+	}
+
+	#[test]
+	fn status_parse_from_invalid_string_is_none() {
+		assert_eq!(FoldStatus::parse("X"), None);
+		assert_eq!(FoldStatus::parse(""), None);
+	}
+
+	#[test]
+	fn parses_from_string() {
+		let entry = CaseFolding::parse("0041; C; 0061;").unwrap();
+		assert_eq!(entry.code, 0x41);
+		assert_eq!(entry.status, FoldStatus::Common);
+		assert_eq!(entry.mapping, vec![0x61]);
+
+		let entry = CaseFolding::parse("1F88; F; 1F00 03B9;").unwrap();
+		assert_eq!(entry.code, 0x1F88);
+		assert_eq!(entry.status, FoldStatus::Full);
+		assert_eq!(entry.mapping, vec![0x1F00, 0x3B9]);
+	}
+
+	#[test]
+	fn parse_invalid_input_returns_error() {
+		let err = CaseFolding::parse("0041; X; 0061;").unwrap_err();
+		assert!(err.contains("`X` is not a valid status"));
+		assert!(err.contains("-- in `0041; X; 0061;`"));
+
+		let err = CaseFolding::parse("0041; C; 00xx;").unwrap_err();
+		assert!(err.contains("`00xx` is not a valid code"));
+
+		let err = CaseFolding::parse("0041; C;").unwrap_err();
+		assert!(err.contains("invalid row format"));
+
+		let err = CaseFolding::parse("0041; C; ;").unwrap_err();
+		assert!(err.contains("empty mapping"));
+	}
+
+	#[test]
+	fn returns_simple_fold() {
+		assert_eq!(simple_fold(0x0041, false), 0x0061);
+		assert_eq!(simple_fold(0x0061, false), 0x0061);
+		assert_eq!(simple_fold(0x03A3, false), 0x03C3);
+
+		// has both a full and a simple folding
+		assert_eq!(simple_fold(0x1E9E, false), 0x00DF);
+		assert_eq!(simple_fold(0x1E9E, true), 0x00DF);
+	}
+
+	#[test]
+	fn simple_fold_supports_turkic_mappings() {
+		assert_eq!(simple_fold(0x0049, false), 0x0069);
+		assert_eq!(simple_fold(0x0049, true), 0x0131);
+		assert_eq!(simple_fold(0x0130, true), 0x0069);
+
+		// U+0130 has only a full and a turkic folding
+		assert_eq!(simple_fold(0x0130, false), 0x0130);
+
+		assert_eq!(simple_fold(0x0041, true), 0x0061);
+	}
+
+	#[test]
+	fn simple_fold_of_uncased_codepoint_is_itself() {
+		assert_eq!(simple_fold(0x0030, false), 0x0030);
+		assert_eq!(simple_fold(0x4E00, true), 0x4E00);
+		assert_eq!(simple_fold(0x10FFFF, false), 0x10FFFF);
+	}
+
+	#[test]
+	fn can_load_from_ucd() {
+		let source = include_ucd!("CaseFolding.txt");
+		let source = source.lines().collect::<Vec<_>>();
+		assert!(!source.is_empty());
+
+		let list = CaseFolding::list();
+		let list = list.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+		assert_eq!(list, source);
+	}
+}
@@ -0,0 +1,186 @@
+use ucd_property_ranges::RangeTable;
+
+use crate::{
+	data::{Bidi, Category},
+	input::{Input, InputFile},
+	Age, AgeProperty, DerivedAge, Script, ScriptProperty, UnicodeDataMap,
+};
+
+/// Properties for a single codepoint aggregated from multiple UCD files by
+/// a [`CharacterDb`].
+///
+/// Fields are [`None`] when the source file was not loaded in the database
+/// or the codepoint is not listed in it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CharacterInfo {
+	pub code: u32,
+
+	/// Character name from `UnicodeData.txt`. For codepoints inside a
+	/// `First>`/`Last>` range this is the range label (e.g. `<CJK Ideograph>`).
+	pub name: Option<String>,
+
+	/// General category from `UnicodeData.txt`.
+	pub category: Option<Category>,
+
+	/// Bidi class from `UnicodeData.txt`.
+	pub bidi: Option<Bidi>,
+
+	/// Script from `Scripts.txt`. Codepoints not listed in the file have the
+	/// [`Script::Unknown`] script.
+	pub script: Option<Script>,
+
+	/// Age from `DerivedAge.txt`.
+	pub age: Option<Age>,
+}
+
+/// Database of codepoint properties merged from multiple UCD files.
+///
+/// Use [`CharacterDb::builder`] to select the source files to load:
+///
+/// ```
+/// # use ucd_parser::{CharacterDb, Script};
+/// let db = CharacterDb::builder().unicode_data().scripts().build();
+/// let info = db.lookup(0x41);
+/// assert_eq!(info.name.as_deref(), Some("LATIN CAPITAL LETTER A"));
+/// assert_eq!(info.script, Some(Script::Latin));
+/// assert_eq!(info.age, None);
+/// ```
+pub struct CharacterDb {
+	unicode_data: Option<UnicodeDataMap<'static>>,
+	scripts: Option<RangeTable>,
+	age: Option<RangeTable>,
+}
+
+impl CharacterDb {
+	/// Return a builder for a database without any sources.
+	pub fn builder() -> CharacterDbBuilder {
+		CharacterDbBuilder::default()
+	}
+
+	/// Load a database with all supported sources.
+	pub fn load() -> Self {
+		Self::builder().all().build()
+	}
+
+	/// Return the aggregated properties for a codepoint.
+	pub fn lookup(&self, code: u32) -> CharacterInfo {
+		let row = self.unicode_data.as_ref().and_then(|x| x.get(code));
+		let name = row.as_ref().map(|x| x.display_name().into_owned());
+		CharacterInfo {
+			code,
+			name,
+			category: row.as_ref().map(|x| x.category),
+			bidi: row.as_ref().map(|x| x.bidi),
+			script: self
+				.scripts
+				.as_ref()
+				.and_then(|x| x.lookup(code, ScriptProperty)),
+			age: self.age.as_ref().and_then(|x| x.lookup(code, AgeProperty)),
+		}
+	}
+}
+
+/// Builder for a [`CharacterDb`] selecting which UCD files to load.
+#[derive(Default)]
+pub struct CharacterDbBuilder {
+	unicode_data: bool,
+	scripts: bool,
+	age: bool,
+}
+
+impl CharacterDbBuilder {
+	/// Load all supported sources.
+	pub fn all(self) -> Self {
+		self.unicode_data().scripts().age()
+	}
+
+	/// Load the name, category, and bidi class from `UnicodeData.txt`.
+	pub fn unicode_data(mut self) -> Self {
+		self.unicode_data = true;
+		self
+	}
+
+	/// Load the script from `Scripts.txt`.
+	pub fn scripts(mut self) -> Self {
+		self.scripts = true;
+		self
+	}
+
+	/// Load the age from `DerivedAge.txt`.
+	pub fn age(mut self) -> Self {
+		self.age = true;
+		self
+	}
+
+	/// Load the selected sources and build the database.
+	pub fn build(self) -> CharacterDb {
+		let unicode_data = if self.unicode_data {
+			let input = Input::get(InputFile::UnicodeData);
+			Some(UnicodeDataMap::from_str(input.text()).unwrap())
+		} else {
+			None
+		};
+
+		CharacterDb {
+			unicode_data,
+			scripts: self.scripts.then(Script::load),
+			age: self.age.then(DerivedAge::load),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn looks_up_latin_letter() {
+		let db = CharacterDb::load();
+		let info = db.lookup(0xE9);
+		assert_eq!(
+			info,
+			CharacterInfo {
+				code: 0xE9,
+				name: Some("LATIN SMALL LETTER E WITH ACUTE".to_string()),
+				category: Some(Category::LetterLowercase),
+				bidi: Some(Bidi::L),
+				script: Some(Script::Latin),
+				age: Some(Age::new(1, 1)),
+			}
+		);
+	}
+
+	#[test]
+	fn looks_up_ranges_and_unassigned() {
+		let db = CharacterDb::load();
+
+		let info = db.lookup(0x4E01);
+		assert_eq!(info.name.as_deref(), Some("<CJK Ideograph>"));
+		assert_eq!(info.category, Some(Category::LetterOther));
+		assert_eq!(info.script, Some(Script::Han));
+
+		let info = db.lookup(0x0378);
+		assert_eq!(info.name, None);
+		assert_eq!(info.category, None);
+		assert_eq!(info.bidi, None);
+		assert_eq!(info.script, Some(Script::Unknown));
+		assert_eq!(info.age, None);
+	}
+
+	#[test]
+	fn missing_sources_are_none() {
+		let db = CharacterDb::builder().build();
+		let info = db.lookup(0x41);
+		assert_eq!(info.name, None);
+		assert_eq!(info.category, None);
+		assert_eq!(info.bidi, None);
+		assert_eq!(info.script, None);
+		assert_eq!(info.age, None);
+
+		let db = CharacterDb::builder().age().build();
+		let info = db.lookup(0x41);
+		assert_eq!(info.name, None);
+		assert_eq!(info.script, None);
+		assert_eq!(info.age, Some(Age::new(1, 1)));
+	}
+}
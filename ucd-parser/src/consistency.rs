@@ -0,0 +1,130 @@
+use crate::{
+	check_sorted_unique,
+	input::{Input, InputFile},
+	parse::parse_range_line,
+	Category, MirrorPair, Mirrored, UnicodeData,
+};
+
+/// Run cross-file consistency checks over the bundled UCD data, returning a
+/// list of problems found (empty if the data is consistent).
+///
+/// This is meant to vet a new drop of the UCD data. Unlike the loaders, it
+/// doesn't panic on the first error, so all problems are reported at once.
+///
+/// The following checks are performed:
+///
+/// - codepoints in `UnicodeData.txt` are sorted and unique;
+/// - codepoints listed in `BidiMirroring.txt` are [`Mirrored::Yes`];
+/// - categories match `extracted/DerivedGeneralCategory.txt`;
+/// - decomposition mappings only contain assigned codepoints;
+/// - decomposition mappings are in canonical order.
+pub fn consistency_check() -> Vec<String> {
+	let mut problems = Vec::new();
+	if let Err(err) = check_sorted_unique(UnicodeData::list()) {
+		problems.push(err);
+	}
+	check_bidi_mirroring(&mut problems);
+	check_general_category(&mut problems);
+	check_decompositions(&mut problems);
+	problems
+}
+
+fn check_bidi_mirroring(problems: &mut Vec<String>) {
+	let input = Input::get(InputFile::BidiMirroring);
+	for line in input.lines() {
+		let code = match MirrorPair::parse(line) {
+			Ok(pair) => pair.code,
+			Err(err) => {
+				problems.push(err);
+				continue;
+			}
+		};
+		match UnicodeData::find(code) {
+			Some(row) if row.mirrored == Mirrored::Yes => {}
+			Some(_) => problems.push(format!(
+				"BidiMirroring: U+{:04X} has a mirroring glyph but is not mirrored",
+				code
+			)),
+			None => problems.push(format!(
+				"BidiMirroring: U+{:04X} has a mirroring glyph but is unassigned",
+				code
+			)),
+		}
+	}
+}
+
+fn check_general_category(problems: &mut Vec<String>) {
+	let input = Input::get(InputFile::DerivedGeneralCategory);
+	for line in input.lines() {
+		let (sta, end, value) = match parse_range_line(line) {
+			Ok(row) => row,
+			Err(err) => {
+				problems.push(format!("DerivedGeneralCategory: {}", err));
+				continue;
+			}
+		};
+		let expected = match Category::parse(value) {
+			Some(category) => category,
+			None => {
+				problems.push(format!(
+					"DerivedGeneralCategory: invalid category `{}` -- in `{}`",
+					value, line
+				));
+				continue;
+			}
+		};
+		for code in sta..=end {
+			let actual = UnicodeData::find(code)
+				.map(|x| x.category)
+				.unwrap_or(Category::OtherNotAssigned);
+			if actual != expected {
+				problems.push(format!(
+					"DerivedGeneralCategory: U+{:04X} is {} but UnicodeData has {}",
+					code, expected, actual
+				));
+			}
+		}
+	}
+}
+
+fn check_decompositions(problems: &mut Vec<String>) {
+	let combining_class = |code: u32| UnicodeData::find(code).map(|x| x.combining_class);
+	for row in UnicodeData::list() {
+		let decomposition = match &row.decomposition {
+			Some(decomposition) => decomposition,
+			None => continue,
+		};
+
+		for code in decomposition.codes.iter() {
+			if combining_class(*code).is_none() {
+				problems.push(format!(
+					"UnicodeData: U+{:04X} decomposes to unassigned U+{:04X}",
+					row.code, code
+				));
+			}
+		}
+
+		for pair in decomposition.codes.windows(2) {
+			let a = combining_class(pair[0]).unwrap_or(0);
+			let b = combining_class(pair[1]).unwrap_or(0);
+			if b != 0 && a > b {
+				problems.push(format!(
+					"UnicodeData: U+{:04X} decomposition is not in canonical order (U+{:04X} ccc={} before U+{:04X} ccc={})",
+					row.code, pair[0], a, pair[1], b
+				));
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	#[ignore = "slow: checks every codepoint in the UCD data"]
+	fn bundled_data_is_consistent() {
+		let problems = consistency_check();
+		assert!(problems.is_empty(), "{}", problems.join("\n"));
+	}
+}
@@ -2,7 +2,7 @@
 /// in the Unicode Standard.
 ///
 /// See https://www.unicode.org/reports/tr9/#Bidirectional_Character_Types
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Bidi {
 	/// Left-to-Right: most alphabetic, syllabic, Han ideographs, non-European
 	/// or non-Arabic digits, ...
@@ -60,6 +60,69 @@ pub enum Bidi {
 }
 
 impl Bidi {
+	/// List of all bidi types, in declaration order.
+	pub fn all() -> &'static [Bidi] {
+		&[
+			Bidi::L,
+			Bidi::R,
+			Bidi::AL,
+			Bidi::EN,
+			Bidi::ES,
+			Bidi::ET,
+			Bidi::AN,
+			Bidi::CS,
+			Bidi::NSM,
+			Bidi::BN,
+			Bidi::B,
+			Bidi::S,
+			Bidi::WS,
+			Bidi::ON,
+			Bidi::LRE,
+			Bidi::LRO,
+			Bidi::RLE,
+			Bidi::RLO,
+			Bidi::PDF,
+			Bidi::LRI,
+			Bidi::RLI,
+			Bidi::FSI,
+			Bidi::PDI,
+		]
+	}
+
+	/// Returns the human-readable name for the bidi type (e.g. `Arabic Letter`
+	/// for `AL`).
+	///
+	/// This is the long name from `PropertyValueAliases.txt` with spaces
+	/// instead of underscores. Use [`Display`](std::fmt::Display) for the
+	/// abbreviation.
+	pub fn long_name(&self) -> &'static str {
+		match self {
+			Bidi::L => "Left To Right",
+			Bidi::R => "Right To Left",
+			Bidi::AL => "Arabic Letter",
+			Bidi::EN => "European Number",
+			Bidi::ES => "European Separator",
+			Bidi::ET => "European Terminator",
+			Bidi::AN => "Arabic Number",
+			Bidi::CS => "Common Separator",
+			Bidi::NSM => "Nonspacing Mark",
+			Bidi::BN => "Boundary Neutral",
+			Bidi::B => "Paragraph Separator",
+			Bidi::S => "Segment Separator",
+			Bidi::WS => "White Space",
+			Bidi::ON => "Other Neutral",
+			Bidi::LRE => "Left To Right Embedding",
+			Bidi::LRO => "Left To Right Override",
+			Bidi::RLE => "Right To Left Embedding",
+			Bidi::RLO => "Right To Left Override",
+			Bidi::PDF => "Pop Directional Format",
+			Bidi::LRI => "Left To Right Isolate",
+			Bidi::RLI => "Right To Left Isolate",
+			Bidi::FSI => "First Strong Isolate",
+			Bidi::PDI => "Pop Directional Isolate",
+		}
+	}
+
 	pub fn parse<T: AsRef<str>>(input: T) -> Option<Bidi> {
 		let bidi = match input.as_ref() {
 			"L" => Bidi::L,
@@ -89,6 +152,27 @@ impl Bidi {
 		};
 		Some(bidi)
 	}
+
+	/// Returns the [`BidiClass`] grouping for this bidi type, as listed in
+	/// the table of bidirectional character types in TR9.
+	pub fn class(&self) -> BidiClass {
+		match self {
+			Bidi::L | Bidi::R | Bidi::AL => BidiClass::Strong,
+			Bidi::EN | Bidi::ES | Bidi::ET | Bidi::AN | Bidi::CS | Bidi::NSM | Bidi::BN => {
+				BidiClass::Weak
+			}
+			Bidi::B | Bidi::S | Bidi::WS | Bidi::ON => BidiClass::Neutral,
+			Bidi::LRE
+			| Bidi::LRO
+			| Bidi::RLE
+			| Bidi::RLO
+			| Bidi::PDF
+			| Bidi::LRI
+			| Bidi::RLI
+			| Bidi::FSI
+			| Bidi::PDI => BidiClass::Explicit,
+		}
+	}
 }
 
 impl std::fmt::Display for Bidi {
@@ -122,6 +206,22 @@ impl std::fmt::Display for Bidi {
 	}
 }
 
+/// Higher-level grouping for the [`Bidi`] types.
+///
+/// See https://www.unicode.org/reports/tr9/#Table_Bidirectional_Character_Types
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BidiClass {
+	/// Strong types: `L`, `R`, and `AL`.
+	Strong,
+	/// Weak types: `EN`, `ES`, `ET`, `AN`, `CS`, `NSM`, and `BN`.
+	Weak,
+	/// Neutral types: `B`, `S`, `WS`, and `ON`.
+	Neutral,
+	/// Explicit formatting types: `LRE`, `LRO`, `RLE`, `RLO`, `PDF`, `LRI`,
+	/// `RLI`, `FSI`, and `PDI`.
+	Explicit,
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -193,4 +293,38 @@ mod tests {
 		check(Bidi::FSI, "FSI");
 		check(Bidi::PDI, "PDI");
 	}
+
+	#[test]
+	fn returns_class() {
+		assert_eq!(Bidi::L.class(), BidiClass::Strong);
+		assert_eq!(Bidi::AL.class(), BidiClass::Strong);
+		assert_eq!(Bidi::EN.class(), BidiClass::Weak);
+		assert_eq!(Bidi::NSM.class(), BidiClass::Weak);
+		assert_eq!(Bidi::BN.class(), BidiClass::Weak);
+		assert_eq!(Bidi::B.class(), BidiClass::Neutral);
+		assert_eq!(Bidi::ON.class(), BidiClass::Neutral);
+		assert_eq!(Bidi::LRE.class(), BidiClass::Explicit);
+		assert_eq!(Bidi::PDI.class(), BidiClass::Explicit);
+	}
+
+	#[test]
+	fn lists_all_with_long_names() {
+		let all = Bidi::all();
+		assert_eq!(all.len(), 23);
+		for (index, bidi) in all.iter().enumerate() {
+			assert!(!bidi.long_name().is_empty());
+			assert!(!all[..index].contains(bidi));
+		}
+
+		assert_eq!(Bidi::AL.long_name(), "Arabic Letter");
+		assert_eq!(Bidi::AL.to_string(), "AL");
+	}
+
+	#[test]
+	fn long_names_match_property_value_aliases() {
+		for bidi in Bidi::all() {
+			let long_name = crate::PropertyValueAlias::long_name("bc", &bidi.to_string()).unwrap();
+			assert_eq!(bidi.long_name(), long_name.replace('_', " "));
+		}
+	}
 }
@@ -1,7 +1,7 @@
 /// General category for character. These are a useful breakdown into
 /// various "character types" which can be used as a default categorization
 /// in implementations.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Category {
 	/// `Cn` Unicode category (no characters in the file have this property).
 	OtherNotAssigned,
@@ -66,6 +66,42 @@ pub enum Category {
 }
 
 impl Category {
+	/// List of all categories, in declaration order.
+	pub fn all() -> &'static [Category] {
+		&[
+			Category::OtherNotAssigned,
+			Category::LetterUppercase,
+			Category::LetterLowercase,
+			Category::LetterTitlecase,
+			Category::MarkNonSpacing,
+			Category::MarkSpacingCombining,
+			Category::MarkEnclosing,
+			Category::NumberDecimalDigit,
+			Category::NumberLetter,
+			Category::NumberOther,
+			Category::SeparatorSpace,
+			Category::SeparatorLine,
+			Category::SeparatorParagraph,
+			Category::OtherControl,
+			Category::OtherFormat,
+			Category::OtherSurrogate,
+			Category::OtherPrivateUse,
+			Category::LetterModifier,
+			Category::LetterOther,
+			Category::PunctuationConnector,
+			Category::PunctuationDash,
+			Category::PunctuationOpen,
+			Category::PunctuationClose,
+			Category::PunctuationInitialQuote,
+			Category::PunctuationFinalQuote,
+			Category::PunctuationOther,
+			Category::SymbolMath,
+			Category::SymbolCurrency,
+			Category::SymbolModifier,
+			Category::SymbolOther,
+		]
+	}
+
 	pub fn parse<T: AsRef<str>>(input: T) -> Option<Self> {
 		let category = match input.as_ref() {
 			"Cn" => Category::OtherNotAssigned,
@@ -102,6 +138,142 @@ impl Category {
 		};
 		Some(category)
 	}
+
+	/// Parse a category from its long property value name, as used in
+	/// `PropertyValueAliases.txt` (e.g. `Uppercase_Letter` for `Lu`).
+	pub fn parse_long<T: AsRef<str>>(input: T) -> Option<Self> {
+		let category = match input.as_ref() {
+			"Unassigned" => Category::OtherNotAssigned,
+			"Uppercase_Letter" => Category::LetterUppercase,
+			"Lowercase_Letter" => Category::LetterLowercase,
+			"Titlecase_Letter" => Category::LetterTitlecase,
+			"Nonspacing_Mark" => Category::MarkNonSpacing,
+			"Spacing_Mark" => Category::MarkSpacingCombining,
+			"Enclosing_Mark" => Category::MarkEnclosing,
+			"Decimal_Number" => Category::NumberDecimalDigit,
+			"Letter_Number" => Category::NumberLetter,
+			"Other_Number" => Category::NumberOther,
+			"Space_Separator" => Category::SeparatorSpace,
+			"Line_Separator" => Category::SeparatorLine,
+			"Paragraph_Separator" => Category::SeparatorParagraph,
+			"Control" => Category::OtherControl,
+			"Format" => Category::OtherFormat,
+			"Surrogate" => Category::OtherSurrogate,
+			"Private_Use" => Category::OtherPrivateUse,
+			"Modifier_Letter" => Category::LetterModifier,
+			"Other_Letter" => Category::LetterOther,
+			"Connector_Punctuation" => Category::PunctuationConnector,
+			"Dash_Punctuation" => Category::PunctuationDash,
+			"Open_Punctuation" => Category::PunctuationOpen,
+			"Close_Punctuation" => Category::PunctuationClose,
+			"Initial_Punctuation" => Category::PunctuationInitialQuote,
+			"Final_Punctuation" => Category::PunctuationFinalQuote,
+			"Other_Punctuation" => Category::PunctuationOther,
+			"Math_Symbol" => Category::SymbolMath,
+			"Currency_Symbol" => Category::SymbolCurrency,
+			"Modifier_Symbol" => Category::SymbolModifier,
+			"Other_Symbol" => Category::SymbolOther,
+			_ => return None,
+		};
+		Some(category)
+	}
+
+	/// Returns the major category (e.g. `L` for `Lu`) for this category.
+	pub fn major(&self) -> MajorCategory {
+		match self {
+			Category::LetterUppercase
+			| Category::LetterLowercase
+			| Category::LetterTitlecase
+			| Category::LetterModifier
+			| Category::LetterOther => MajorCategory::Letter,
+			Category::MarkNonSpacing | Category::MarkSpacingCombining | Category::MarkEnclosing => {
+				MajorCategory::Mark
+			}
+			Category::NumberDecimalDigit | Category::NumberLetter | Category::NumberOther => {
+				MajorCategory::Number
+			}
+			Category::PunctuationConnector
+			| Category::PunctuationDash
+			| Category::PunctuationOpen
+			| Category::PunctuationClose
+			| Category::PunctuationInitialQuote
+			| Category::PunctuationFinalQuote
+			| Category::PunctuationOther => MajorCategory::Punctuation,
+			Category::SymbolMath
+			| Category::SymbolCurrency
+			| Category::SymbolModifier
+			| Category::SymbolOther => MajorCategory::Symbol,
+			Category::SeparatorSpace | Category::SeparatorLine | Category::SeparatorParagraph => {
+				MajorCategory::Separator
+			}
+			Category::OtherNotAssigned
+			| Category::OtherControl
+			| Category::OtherFormat
+			| Category::OtherSurrogate
+			| Category::OtherPrivateUse => MajorCategory::Other,
+		}
+	}
+
+	/// Returns true if the major category is [`MajorCategory::Letter`].
+	pub fn is_letter(&self) -> bool {
+		self.major() == MajorCategory::Letter
+	}
+
+	/// Returns true if the major category is [`MajorCategory::Mark`].
+	pub fn is_mark(&self) -> bool {
+		self.major() == MajorCategory::Mark
+	}
+
+	/// Returns true if the major category is [`MajorCategory::Number`].
+	pub fn is_number(&self) -> bool {
+		self.major() == MajorCategory::Number
+	}
+
+	/// Returns true if the major category is [`MajorCategory::Punctuation`].
+	pub fn is_punctuation(&self) -> bool {
+		self.major() == MajorCategory::Punctuation
+	}
+
+	/// Returns true if the major category is [`MajorCategory::Symbol`].
+	pub fn is_symbol(&self) -> bool {
+		self.major() == MajorCategory::Symbol
+	}
+
+	/// Returns true if the major category is [`MajorCategory::Separator`].
+	pub fn is_separator(&self) -> bool {
+		self.major() == MajorCategory::Separator
+	}
+
+	/// Returns true if the major category is [`MajorCategory::Other`].
+	pub fn is_other(&self) -> bool {
+		self.major() == MajorCategory::Other
+	}
+
+	/// Returns the index of the category in [`Category::all`], which can be
+	/// used as a stable numeric encoding.
+	/// Use `Category::try_from` for the reverse conversion.
+	pub fn to_index(&self) -> u32 {
+		*self as u32
+	}
+}
+
+/// Convert an index in [`Category::all`] back to the category.
+///
+/// ```
+/// # use ucd_parser::Category;
+/// let index = Category::LetterLowercase.to_index();
+/// assert_eq!(Category::try_from(index), Ok(Category::LetterLowercase));
+/// assert!(Category::try_from(100).is_err());
+/// ```
+impl TryFrom<u32> for Category {
+	type Error = String;
+
+	fn try_from(value: u32) -> Result<Self, Self::Error> {
+		let all = Category::all();
+		all.get(value as usize)
+			.copied()
+			.ok_or_else(|| format!("`{}` is not a valid category index", value))
+	}
 }
 
 impl std::fmt::Display for Category {
@@ -142,10 +314,74 @@ impl std::fmt::Display for Category {
 	}
 }
 
+/// Major class for a [`Category`], corresponding to the first letter of the
+/// category abbreviation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MajorCategory {
+	/// `L` Unicode major category.
+	Letter,
+	/// `M` Unicode major category.
+	Mark,
+	/// `N` Unicode major category.
+	Number,
+	/// `P` Unicode major category.
+	Punctuation,
+	/// `S` Unicode major category.
+	Symbol,
+	/// `Z` Unicode major category.
+	Separator,
+	/// `C` Unicode major category.
+	Other,
+}
+
+impl MajorCategory {
+	pub fn parse<T: AsRef<str>>(input: T) -> Option<Self> {
+		let category = match input.as_ref() {
+			"L" => MajorCategory::Letter,
+			"M" => MajorCategory::Mark,
+			"N" => MajorCategory::Number,
+			"P" => MajorCategory::Punctuation,
+			"S" => MajorCategory::Symbol,
+			"Z" => MajorCategory::Separator,
+			"C" => MajorCategory::Other,
+			_ => return None,
+		};
+		Some(category)
+	}
+}
+
+impl std::fmt::Display for MajorCategory {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let output = match self {
+			MajorCategory::Letter => "L",
+			MajorCategory::Mark => "M",
+			MajorCategory::Number => "N",
+			MajorCategory::Punctuation => "P",
+			MajorCategory::Symbol => "S",
+			MajorCategory::Separator => "Z",
+			MajorCategory::Other => "C",
+		};
+		write!(f, "{}", output)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 
+	#[test]
+	fn converts_to_and_from_index() {
+		for (index, category) in Category::all().iter().enumerate() {
+			assert_eq!(category.to_index(), index as u32);
+			assert_eq!(Category::try_from(category.to_index()), Ok(*category));
+		}
+
+		let count = Category::all().len() as u32;
+		let err = Category::try_from(count).unwrap_err();
+		assert_eq!(err, format!("`{}` is not a valid category index", count));
+		assert!(Category::try_from(u32::MAX).is_err());
+	}
+
 	#[test]
 	fn parses_from_string() {
 		fn parse(input: &'static str) -> Category {
@@ -189,6 +425,33 @@ mod tests {
 		assert_eq!(Category::parse("xx"), None);
 	}
 
+	#[test]
+	fn parses_from_long_name() {
+		assert_eq!(
+			Category::parse_long("Uppercase_Letter"),
+			Some(Category::LetterUppercase)
+		);
+		assert_eq!(
+			Category::parse_long("Decimal_Number"),
+			Some(Category::NumberDecimalDigit)
+		);
+		assert_eq!(
+			Category::parse_long("Space_Separator"),
+			Some(Category::SeparatorSpace)
+		);
+		assert_eq!(Category::parse_long("Uppercase_Letters"), None);
+		assert_eq!(Category::parse_long("Lu"), None);
+	}
+
+	#[test]
+	fn long_names_match_property_value_aliases() {
+		for category in Category::all() {
+			let short = category.to_string();
+			let long = crate::PropertyValueAlias::long_name("gc", &short).unwrap();
+			assert_eq!(Category::parse_long(long), Some(*category));
+		}
+	}
+
 	#[test]
 	fn supports_to_string() {
 		fn check(input: Category, expected: &'static str) {
@@ -227,4 +490,61 @@ mod tests {
 		check(Category::SymbolModifier, "Sk");
 		check(Category::SymbolOther, "So");
 	}
+
+	#[test]
+	fn lists_all_categories() {
+		let all = Category::all();
+		assert_eq!(all.len(), 30);
+		for (n, it) in all.iter().enumerate() {
+			assert_eq!(Category::parse(it.to_string()), Some(*it));
+			assert!(!all[..n].contains(it), "{} is duplicated", it);
+		}
+	}
+
+	#[test]
+	fn maps_to_major_category() {
+		assert_eq!(Category::LetterUppercase.major(), MajorCategory::Letter);
+		assert_eq!(Category::LetterModifier.major(), MajorCategory::Letter);
+		assert_eq!(Category::MarkNonSpacing.major(), MajorCategory::Mark);
+		assert_eq!(Category::NumberDecimalDigit.major(), MajorCategory::Number);
+		assert_eq!(
+			Category::PunctuationDash.major(),
+			MajorCategory::Punctuation
+		);
+		assert_eq!(Category::SymbolCurrency.major(), MajorCategory::Symbol);
+		assert_eq!(Category::SeparatorSpace.major(), MajorCategory::Separator);
+		assert_eq!(Category::OtherControl.major(), MajorCategory::Other);
+		assert_eq!(Category::OtherNotAssigned.major(), MajorCategory::Other);
+	}
+
+	#[test]
+	fn major_category_matches_abbreviation() {
+		fn check(input: Category) {
+			let major = input.major().to_string();
+			assert!(input.to_string().starts_with(&major));
+			assert_eq!(MajorCategory::parse(&major), Some(input.major()));
+		}
+
+		check(Category::LetterTitlecase);
+		check(Category::MarkEnclosing);
+		check(Category::NumberLetter);
+		check(Category::PunctuationOther);
+		check(Category::SymbolMath);
+		check(Category::SeparatorParagraph);
+		check(Category::OtherPrivateUse);
+	}
+
+	#[test]
+	fn supports_major_category_predicates() {
+		assert!(Category::LetterLowercase.is_letter());
+		assert!(Category::MarkEnclosing.is_mark());
+		assert!(Category::NumberOther.is_number());
+		assert!(Category::PunctuationOpen.is_punctuation());
+		assert!(Category::SymbolOther.is_symbol());
+		assert!(Category::SeparatorLine.is_separator());
+		assert!(Category::OtherFormat.is_other());
+
+		assert!(!Category::LetterLowercase.is_mark());
+		assert!(!Category::SymbolOther.is_punctuation());
+	}
 }
@@ -0,0 +1,147 @@
+/// Canonical combining class for a character, used in the canonical
+/// ordering of combining marks for normalization.
+///
+/// This is a number from 0 to 254. The well-known values have named constants
+/// (see the `ccc` values in `PropertyValueAliases.txt`), while the classes
+/// from 10 to 199 are fixed position classes specific to a few scripts.
+///
+/// ```
+/// # use ucd_parser::CombiningClass;
+/// assert_eq!(CombiningClass(230), CombiningClass::ABOVE);
+/// assert_eq!(CombiningClass::ABOVE.name(), Some("Above"));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CombiningClass(pub u32);
+
+impl CombiningClass {
+	/// `Not_Reordered` (0).
+	pub const NOT_REORDERED: CombiningClass = CombiningClass(0);
+
+	/// `Overlay` (1).
+	pub const OVERLAY: CombiningClass = CombiningClass(1);
+
+	/// `Han_Reading` (6).
+	pub const HAN_READING: CombiningClass = CombiningClass(6);
+
+	/// `Nukta` (7).
+	pub const NUKTA: CombiningClass = CombiningClass(7);
+
+	/// `Kana_Voicing` (8).
+	pub const KANA_VOICING: CombiningClass = CombiningClass(8);
+
+	/// `Virama` (9).
+	pub const VIRAMA: CombiningClass = CombiningClass(9);
+
+	/// `Attached_Below_Left` (200).
+	pub const ATTACHED_BELOW_LEFT: CombiningClass = CombiningClass(200);
+
+	/// `Attached_Below` (202).
+	pub const ATTACHED_BELOW: CombiningClass = CombiningClass(202);
+
+	/// `Attached_Above` (214).
+	pub const ATTACHED_ABOVE: CombiningClass = CombiningClass(214);
+
+	/// `Attached_Above_Right` (216).
+	pub const ATTACHED_ABOVE_RIGHT: CombiningClass = CombiningClass(216);
+
+	/// `Below_Left` (218).
+	pub const BELOW_LEFT: CombiningClass = CombiningClass(218);
+
+	/// `Below` (220).
+	pub const BELOW: CombiningClass = CombiningClass(220);
+
+	/// `Below_Right` (222).
+	pub const BELOW_RIGHT: CombiningClass = CombiningClass(222);
+
+	/// `Left` (224).
+	pub const LEFT: CombiningClass = CombiningClass(224);
+
+	/// `Right` (226).
+	pub const RIGHT: CombiningClass = CombiningClass(226);
+
+	/// `Above_Left` (228).
+	pub const ABOVE_LEFT: CombiningClass = CombiningClass(228);
+
+	/// `Above` (230).
+	pub const ABOVE: CombiningClass = CombiningClass(230);
+
+	/// `Above_Right` (232).
+	pub const ABOVE_RIGHT: CombiningClass = CombiningClass(232);
+
+	/// `Double_Below` (233).
+	pub const DOUBLE_BELOW: CombiningClass = CombiningClass(233);
+
+	/// `Double_Above` (234).
+	pub const DOUBLE_ABOVE: CombiningClass = CombiningClass(234);
+
+	/// `Iota_Subscript` (240).
+	pub const IOTA_SUBSCRIPT: CombiningClass = CombiningClass(240);
+
+	/// Returns the long name for a well-known combining class, or [`None`]
+	/// for the fixed position classes that don't have a name.
+	pub fn name(&self) -> Option<&'static str> {
+		let name = match self.0 {
+			0 => "Not_Reordered",
+			1 => "Overlay",
+			6 => "Han_Reading",
+			7 => "Nukta",
+			8 => "Kana_Voicing",
+			9 => "Virama",
+			200 => "Attached_Below_Left",
+			202 => "Attached_Below",
+			214 => "Attached_Above",
+			216 => "Attached_Above_Right",
+			218 => "Below_Left",
+			220 => "Below",
+			222 => "Below_Right",
+			224 => "Left",
+			226 => "Right",
+			228 => "Above_Left",
+			230 => "Above",
+			232 => "Above_Right",
+			233 => "Double_Below",
+			234 => "Double_Above",
+			240 => "Iota_Subscript",
+			_ => return None,
+		};
+		Some(name)
+	}
+}
+
+impl std::fmt::Display for CombiningClass {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn returns_name() {
+		assert_eq!(CombiningClass(0), CombiningClass::NOT_REORDERED);
+		assert_eq!(CombiningClass(0).name(), Some("Not_Reordered"));
+		assert_eq!(CombiningClass(230).name(), Some("Above"));
+		assert_eq!(CombiningClass(7).name(), Some("Nukta"));
+
+		assert_eq!(CombiningClass(10).name(), None);
+		assert_eq!(CombiningClass(50).name(), None);
+	}
+
+	#[test]
+	fn names_match_property_value_aliases() {
+		for code in 0..=254 {
+			let ccc = CombiningClass(code);
+			if let Some(name) = ccc.name() {
+				let short = crate::PropertyValueAlias::short_name("ccc", name).unwrap();
+				let long = crate::PropertyValueAlias::long_name("ccc", short).unwrap();
+				assert_eq!(long, name);
+				assert_eq!(
+					crate::PropertyValueAlias::short_name("ccc", &code.to_string()),
+					Some(short)
+				);
+			}
+		}
+	}
+}
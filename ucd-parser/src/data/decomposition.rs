@@ -1,5 +1,5 @@
 /// Decomposition mapping for the character.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Decomposition {
 	pub tag: Option<DecompositionTag>,
 	pub codes: Vec<u32>,
@@ -28,6 +28,17 @@ impl Decomposition {
 		}
 		Ok(output)
 	}
+
+	/// Returns the `Decomposition_Type` property value name for the mapping,
+	/// which is `Canonical` for mappings without a tag.
+	///
+	/// See [`DecompositionTag::type_name`].
+	pub fn type_name(&self) -> &'static str {
+		match self.tag {
+			Some(tag) => tag.type_name(),
+			None => "Canonical",
+		}
+	}
 }
 
 impl std::fmt::Display for Decomposition {
@@ -56,7 +67,7 @@ impl std::fmt::Display for Decomposition {
 ///
 /// In the absence of other formatting information in a compatibility mapping,
 /// the tag is used to distinguish it from canonical mappings.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum DecompositionTag {
 	/// A font variant (e.g. a blackletter form).
 	Font,
@@ -93,6 +104,95 @@ pub enum DecompositionTag {
 }
 
 impl DecompositionTag {
+	/// Returns all tags, in declaration order.
+	pub fn all() -> &'static [DecompositionTag] {
+		&[
+			DecompositionTag::Font,
+			DecompositionTag::NoBreak,
+			DecompositionTag::Initial,
+			DecompositionTag::Medial,
+			DecompositionTag::Final,
+			DecompositionTag::Isolated,
+			DecompositionTag::Circle,
+			DecompositionTag::Super,
+			DecompositionTag::Sub,
+			DecompositionTag::Vertical,
+			DecompositionTag::Wide,
+			DecompositionTag::Narrow,
+			DecompositionTag::Small,
+			DecompositionTag::Square,
+			DecompositionTag::Fraction,
+			DecompositionTag::Compat,
+		]
+	}
+
+	/// Returns true for the Arabic contextual presentation form tags:
+	/// `<initial>`, `<medial>`, `<final>`, and `<isolated>`.
+	pub fn is_presentation_form(&self) -> bool {
+		matches!(
+			self,
+			DecompositionTag::Initial
+				| DecompositionTag::Medial
+				| DecompositionTag::Final
+				| DecompositionTag::Isolated
+		)
+	}
+
+	/// Returns true for the width variant tags: `<wide>` and `<narrow>`.
+	pub fn is_width_variant(&self) -> bool {
+		matches!(self, DecompositionTag::Wide | DecompositionTag::Narrow)
+	}
+
+	/// Returns the `Decomposition_Type` property value name for the tag
+	/// (e.g. `Nobreak` for `<noBreak>`).
+	pub fn type_name(&self) -> &'static str {
+		match self {
+			DecompositionTag::Font => "Font",
+			DecompositionTag::NoBreak => "Nobreak",
+			DecompositionTag::Initial => "Initial",
+			DecompositionTag::Medial => "Medial",
+			DecompositionTag::Final => "Final",
+			DecompositionTag::Isolated => "Isolated",
+			DecompositionTag::Circle => "Circle",
+			DecompositionTag::Super => "Super",
+			DecompositionTag::Sub => "Sub",
+			DecompositionTag::Vertical => "Vertical",
+			DecompositionTag::Wide => "Wide",
+			DecompositionTag::Narrow => "Narrow",
+			DecompositionTag::Small => "Small",
+			DecompositionTag::Square => "Square",
+			DecompositionTag::Fraction => "Fraction",
+			DecompositionTag::Compat => "Compat",
+		}
+	}
+
+	/// Parse a tag from its `Decomposition_Type` property value name.
+	///
+	/// Note that this returns [`None`] for `Canonical`, since canonical
+	/// mappings don't have a tag.
+	pub fn parse_type_name<T: AsRef<str>>(input: T) -> Option<Self> {
+		let tag = match input.as_ref() {
+			"Font" => DecompositionTag::Font,
+			"Nobreak" => DecompositionTag::NoBreak,
+			"Initial" => DecompositionTag::Initial,
+			"Medial" => DecompositionTag::Medial,
+			"Final" => DecompositionTag::Final,
+			"Isolated" => DecompositionTag::Isolated,
+			"Circle" => DecompositionTag::Circle,
+			"Super" => DecompositionTag::Super,
+			"Sub" => DecompositionTag::Sub,
+			"Vertical" => DecompositionTag::Vertical,
+			"Wide" => DecompositionTag::Wide,
+			"Narrow" => DecompositionTag::Narrow,
+			"Small" => DecompositionTag::Small,
+			"Square" => DecompositionTag::Square,
+			"Fraction" => DecompositionTag::Fraction,
+			"Compat" => DecompositionTag::Compat,
+			_ => return None,
+		};
+		Some(tag)
+	}
+
 	pub fn parse<T: AsRef<str>>(input: T) -> Option<Self> {
 		let tag = match input.as_ref() {
 			"<font>" => DecompositionTag::Font,
@@ -178,6 +278,15 @@ mod test_decomposition {
 		);
 	}
 
+	#[test]
+	fn returns_type_name() {
+		let canonical = Decomposition::parse("0041 0300").unwrap();
+		assert_eq!(canonical.type_name(), "Canonical");
+
+		let compat = Decomposition::parse("<noBreak> 0020").unwrap();
+		assert_eq!(compat.type_name(), "Nobreak");
+	}
+
 	#[test]
 	fn returns_error_on_invalid_tag() {
 		let input = "<xx> FFFF";
@@ -198,7 +307,7 @@ mod test_decomposition {
 	fn supports_to_string() {
 		fn check(input: Decomposition, expected: &'static str) {
 			assert_eq!(input.to_string(), expected);
-			assert_eq!(Decomposition::parse(&input.to_string()).unwrap(), input);
+			assert_eq!(Decomposition::parse(input.to_string()).unwrap(), input);
 		}
 
 		let input = Decomposition {
@@ -225,6 +334,33 @@ mod test_decomposition {
 mod test_tags {
 	use super::*;
 
+	#[test]
+	fn classifies_presentation_forms() {
+		let tags = DecompositionTag::all().iter();
+		let tags = tags
+			.filter(|x| x.is_presentation_form())
+			.collect::<Vec<_>>();
+		assert_eq!(
+			tags,
+			vec![
+				&DecompositionTag::Initial,
+				&DecompositionTag::Medial,
+				&DecompositionTag::Final,
+				&DecompositionTag::Isolated,
+			]
+		);
+	}
+
+	#[test]
+	fn classifies_width_variants() {
+		let tags = DecompositionTag::all().iter();
+		let tags = tags.filter(|x| x.is_width_variant()).collect::<Vec<_>>();
+		assert_eq!(
+			tags,
+			vec![&DecompositionTag::Wide, &DecompositionTag::Narrow]
+		);
+	}
+
 	#[test]
 	fn parses_tag_from_string() {
 		fn parse(input: &'static str) -> DecompositionTag {
@@ -281,4 +417,57 @@ mod test_tags {
 		check(DecompositionTag::Fraction, "<fraction>");
 		check(DecompositionTag::Compat, "<compat>");
 	}
+
+	#[test]
+	fn lists_all_tags() {
+		let all = DecompositionTag::all();
+		assert_eq!(all.len(), 16);
+		for (n, tag) in all.iter().enumerate() {
+			assert!(!all[n + 1..].contains(tag));
+		}
+	}
+
+	#[test]
+	fn supports_type_name() {
+		fn check(input: DecompositionTag, expected: &'static str) {
+			assert_eq!(input.type_name(), expected);
+			assert_eq!(
+				DecompositionTag::parse_type_name(input.type_name()).expect(expected),
+				input
+			);
+		}
+
+		check(DecompositionTag::Font, "Font");
+		check(DecompositionTag::NoBreak, "Nobreak");
+		check(DecompositionTag::Initial, "Initial");
+		check(DecompositionTag::Medial, "Medial");
+		check(DecompositionTag::Final, "Final");
+		check(DecompositionTag::Isolated, "Isolated");
+		check(DecompositionTag::Circle, "Circle");
+		check(DecompositionTag::Super, "Super");
+		check(DecompositionTag::Sub, "Sub");
+		check(DecompositionTag::Vertical, "Vertical");
+		check(DecompositionTag::Wide, "Wide");
+		check(DecompositionTag::Narrow, "Narrow");
+		check(DecompositionTag::Small, "Small");
+		check(DecompositionTag::Square, "Square");
+		check(DecompositionTag::Fraction, "Fraction");
+		check(DecompositionTag::Compat, "Compat");
+
+		assert_eq!(DecompositionTag::parse_type_name("Canonical"), None);
+		assert_eq!(DecompositionTag::parse_type_name("noBreak"), None);
+	}
+
+	#[test]
+	fn type_names_match_property_value_aliases() {
+		for tag in DecompositionTag::all() {
+			let name = tag.type_name();
+			assert!(
+				crate::PropertyValueAlias::short_name("dt", name).is_some(),
+				"`{}` is not a Decomposition_Type value",
+				name
+			);
+		}
+		assert!(crate::PropertyValueAlias::short_name("dt", "Canonical").is_some());
+	}
 }
@@ -4,6 +4,9 @@ pub use bidi::*;
 mod category;
 pub use category::*;
 
+mod combining_class;
+pub use combining_class::*;
+
 mod decomposition;
 pub use decomposition::*;
 
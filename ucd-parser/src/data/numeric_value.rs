@@ -1,7 +1,7 @@
 /// Numeric value property for a character. Includes fractions such as the
 /// `U+2155 VULGAR FRACTION ONE FIFTH` and numeric values for compatibility
 /// characters such as circled numbers.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum NumericValue {
 	None,
 	Integer(i64),
@@ -11,7 +11,7 @@ pub enum NumericValue {
 impl NumericValue {
 	pub fn parse<T: AsRef<str>>(input: T) -> Result<NumericValue, String> {
 		let input = input.as_ref();
-		if input.len() == 0 {
+		if input.is_empty() {
 			return Ok(NumericValue::None);
 		}
 
@@ -28,6 +28,47 @@ impl NumericValue {
 			Ok(NumericValue::Integer(value))
 		}
 	}
+
+	/// Returns the numeric value as a floating point number, or [`None`] if
+	/// there is no numeric value.
+	///
+	/// A rational with a zero denominator also returns [`None`].
+	pub fn as_f64(&self) -> Option<f64> {
+		match *self {
+			NumericValue::None => None,
+			NumericValue::Integer(value) => Some(value as f64),
+			NumericValue::Rational(_, 0) => None,
+			NumericValue::Rational(a, b) => Some(a as f64 / b as f64),
+		}
+	}
+
+	/// Returns the value with a rational reduced to its lowest terms and the
+	/// sign normalized so that the denominator is positive.
+	///
+	/// Note that [`NumericValue::parse`] keeps the raw values from the input,
+	/// so this must be called explicitly before comparing rational values.
+	///
+	/// A reduced rational that doesn't fit in an `i32` (e.g. `1/-2147483648`)
+	/// is returned unchanged.
+	pub fn reduced(&self) -> NumericValue {
+		match *self {
+			NumericValue::Rational(a, b) => {
+				let (mut x, mut y) = (a.unsigned_abs(), b.unsigned_abs());
+				while y != 0 {
+					(x, y) = (y, x % y);
+				}
+				let gcd = std::cmp::max(x, 1) as i64;
+				let sign = if b < 0 { -1 } else { 1 };
+				let num = i32::try_from(sign * a as i64 / gcd);
+				let den = i32::try_from(sign * b as i64 / gcd);
+				match (num, den) {
+					(Ok(num), Ok(den)) => NumericValue::Rational(num, den),
+					_ => *self,
+				}
+			}
+			other => other,
+		}
+	}
 }
 
 impl std::fmt::Display for NumericValue {
@@ -115,4 +156,71 @@ mod tests {
 		check(NumericValue::Rational(2, 4), "2/4");
 		check(NumericValue::Rational(-3, 6), "-3/6");
 	}
+
+	#[test]
+	fn converts_to_f64() {
+		assert_eq!(NumericValue::None.as_f64(), None);
+		assert_eq!(NumericValue::Integer(123).as_f64(), Some(123.0));
+		assert_eq!(NumericValue::Integer(-5).as_f64(), Some(-5.0));
+		assert_eq!(NumericValue::Rational(1, 4).as_f64(), Some(0.25));
+		assert_eq!(NumericValue::Rational(-1, 2).as_f64(), Some(-0.5));
+	}
+
+	#[test]
+	fn converts_to_f64_with_zero_denominator_as_none() {
+		assert_eq!(NumericValue::Rational(1, 0).as_f64(), None);
+	}
+
+	#[test]
+	fn reduces_rational_to_lowest_terms() {
+		assert_eq!(
+			NumericValue::Rational(2, 4).reduced(),
+			NumericValue::Rational(1, 2)
+		);
+		assert_eq!(
+			NumericValue::Rational(-3, -6).reduced(),
+			NumericValue::Rational(1, 2)
+		);
+		assert_eq!(
+			NumericValue::Rational(3, -6).reduced(),
+			NumericValue::Rational(-1, 2)
+		);
+		assert_eq!(
+			NumericValue::Rational(4, 2).reduced(),
+			NumericValue::Rational(2, 1)
+		);
+		assert_eq!(
+			NumericValue::Rational(1, 5).reduced(),
+			NumericValue::Rational(1, 5)
+		);
+	}
+
+	#[test]
+	fn reduced_handles_i32_min() {
+		assert_eq!(
+			NumericValue::parse("1/-2147483648").unwrap().reduced(),
+			NumericValue::Rational(1, i32::MIN)
+		);
+		assert_eq!(
+			NumericValue::Rational(i32::MIN, i32::MIN).reduced(),
+			NumericValue::Rational(1, 1)
+		);
+		assert_eq!(
+			NumericValue::Rational(i32::MIN, 2).reduced(),
+			NumericValue::Rational(-1073741824, 1)
+		);
+		assert_eq!(
+			NumericValue::Rational(i32::MIN, -1).reduced(),
+			NumericValue::Rational(i32::MIN, -1)
+		);
+	}
+
+	#[test]
+	fn reduced_keeps_other_values() {
+		assert_eq!(NumericValue::None.reduced(), NumericValue::None);
+		assert_eq!(
+			NumericValue::Integer(-12).reduced(),
+			NumericValue::Integer(-12)
+		);
+	}
 }
@@ -0,0 +1,170 @@
+use std::ops::RangeInclusive;
+
+use once_cell::sync::Lazy;
+use ucd_property_ranges::{PropertyKey, RangeTable};
+
+use crate::{
+	input::{Input, InputFile},
+	parse::parse_range_line,
+};
+
+/// Version of the Unicode Standard in which a codepoint was first assigned.
+///
+/// Ages are ordered by version, so they can be compared to filter codepoints
+/// newer than a given version.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Age {
+	pub major: u32,
+	pub minor: u32,
+}
+
+impl Age {
+	pub fn new(major: u32, minor: u32) -> Self {
+		Age { major, minor }
+	}
+
+	/// Parse a two-part `MAJOR.MINOR` version (e.g. `1.1`).
+	pub fn parse<T: AsRef<str>>(input: T) -> Result<Self, String> {
+		let input = input.as_ref();
+		let error = || format!("`{}` is not a valid age", input);
+		let (major, minor) = input.split_once('.').ok_or_else(error)?;
+		let major = major.parse::<u32>().map_err(|_| error())?;
+		let minor = minor.parse::<u32>().map_err(|_| error())?;
+		Ok(Age::new(major, minor))
+	}
+}
+
+impl std::fmt::Display for Age {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}.{}", self.major, self.minor)
+	}
+}
+
+/// [`PropertyKey`] for [`Age`] values in a [`RangeTable`].
+#[derive(Clone, PartialEq)]
+pub struct AgeProperty;
+
+impl PropertyKey for AgeProperty {
+	type Value = Age;
+}
+
+/// Range of codepoints from `DerivedAge.txt` with the [`Age`] in which they
+/// were first assigned.
+///
+/// ```
+/// # use ucd_parser::{age_for, Age};
+/// assert_eq!(age_for(0x41), Some(Age::new(1, 1)));
+/// assert!(age_for(0x1F600).unwrap() > Age::new(6, 0));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DerivedAge {
+	/// Inclusive range of codepoints.
+	pub range: RangeInclusive<u32>,
+
+	/// Version in which the codepoints were assigned.
+	pub age: Age,
+}
+
+impl DerivedAge {
+	/// List of ranges from the UCD data in file order. Lazy-loaded from
+	/// `DerivedAge.txt`.
+	pub fn list() -> &'static [DerivedAge] {
+		static LIST: Lazy<Box<[DerivedAge]>> = Lazy::new(|| {
+			let input = Input::get(InputFile::DerivedAge);
+			let lines = input.lines();
+			let list = lines.map(|x| DerivedAge::parse(x).unwrap());
+			let list = list.collect::<Vec<_>>();
+			list.into_boxed_slice()
+		});
+		&LIST
+	}
+
+	/// Build a [`RangeTable`] with the [`AgeProperty`] for all codepoints
+	/// listed in `DerivedAge.txt`. Unassigned codepoints are not mapped.
+	pub fn load() -> RangeTable {
+		let mut table = RangeTable::new();
+		for it in Self::list() {
+			table.set_range(it.range.clone(), AgeProperty, it.age);
+		}
+		table
+	}
+
+	pub fn parse(input: &str) -> Result<Self, String> {
+		let (sta, end, age) =
+			parse_range_line(input).map_err(|err| format!("derived age: {}", err))?;
+		let age =
+			Age::parse(age).map_err(|err| format!("derived age: {} -- in `{}`", err, input))?;
+		Ok(DerivedAge {
+			range: sta..=end,
+			age,
+		})
+	}
+}
+
+/// Returns the [`Age`] for a codepoint, or [`None`] if it is unassigned.
+pub fn age_for(code: u32) -> Option<Age> {
+	static SORTED: Lazy<Vec<&'static DerivedAge>> = Lazy::new(|| {
+		let mut sorted = DerivedAge::list().iter().collect::<Vec<_>>();
+		sorted.sort_by_key(|x| *x.range.start());
+		sorted
+	});
+
+	let index = SORTED.partition_point(|x| *x.range.end() < code);
+	SORTED
+		.get(index)
+		.filter(|x| x.range.contains(&code))
+		.map(|x| x.age)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_age() {
+		assert_eq!(Age::parse("1.1").unwrap(), Age::new(1, 1));
+		assert_eq!(Age::parse("14.0").unwrap(), Age::new(14, 0));
+		assert!(Age::parse("14")
+			.unwrap_err()
+			.contains("`14` is not a valid age"));
+		assert!(Age::parse("x.1").is_err());
+	}
+
+	#[test]
+	fn ages_are_ordered_by_version() {
+		assert!(Age::new(1, 1) < Age::new(2, 0));
+		assert!(Age::new(2, 0) < Age::new(2, 1));
+		assert!(Age::new(9, 0) < Age::new(10, 0));
+	}
+
+	#[test]
+	fn parses_from_string() {
+		let entry = DerivedAge::parse("0000..001F    ; 1.1").unwrap();
+		assert_eq!(entry.range, 0..=0x1F);
+		assert_eq!(entry.age, Age::new(1, 1));
+
+		let entry = DerivedAge::parse("00AD          ; 3.2").unwrap();
+		assert_eq!(entry.range, 0xAD..=0xAD);
+		assert_eq!(entry.age, Age::new(3, 2));
+
+		let err = DerivedAge::parse("0000; x").unwrap_err();
+		assert!(err.contains("`x` is not a valid age"));
+	}
+
+	#[test]
+	fn can_load_from_ucd() {
+		let table = DerivedAge::load();
+		assert_eq!(table.lookup(0x41, AgeProperty), Some(Age::new(1, 1)));
+		assert_eq!(table.lookup(0x20AC, AgeProperty), Some(Age::new(2, 1)));
+		assert_eq!(table.lookup(0x378, AgeProperty), None);
+	}
+
+	#[test]
+	fn returns_age_for_codepoint() {
+		assert_eq!(age_for(0x41), Some(Age::new(1, 1)));
+		assert_eq!(age_for(0x20AC), Some(Age::new(2, 1)));
+		assert_eq!(age_for(0x1F600), Some(Age::new(6, 1)));
+		assert_eq!(age_for(0x378), None);
+		assert_eq!(age_for(0x10FFFF), Some(Age::new(2, 0)));
+	}
+}
@@ -0,0 +1,214 @@
+use once_cell::sync::Lazy;
+use ucd_property_ranges::{PropertyKey, RangeTable};
+
+use crate::{
+	input::{Input, InputFile},
+	parse::parse_range_line,
+};
+
+/// [`PropertyKey`] for the `Case_Ignorable` derived property in a
+/// [`RangeTable`].
+#[derive(Clone, PartialEq)]
+pub struct CaseIgnorableProperty;
+
+impl PropertyKey for CaseIgnorableProperty {
+	type Value = bool;
+}
+
+/// Build a [`RangeTable`] with the [`CaseIgnorableProperty`] for the entire
+/// codespace from `DerivedCoreProperties.txt`.
+///
+/// This is used by the conditional case mappings in `SpecialCasing.txt`
+/// (e.g. `Final_Sigma`). Codepoints not listed for the property are `false`.
+pub fn build_case_ignorable_table() -> RangeTable {
+	let mut table = RangeTable::new();
+	table.set_range(0..=0x10FFFF, CaseIgnorableProperty, false);
+	for (sta, end) in derived_core_ranges("Case_Ignorable") {
+		table.set_range(sta..=end, CaseIgnorableProperty, true);
+	}
+	table
+}
+
+/// Boolean properties from `DerivedCoreProperties.txt`, used as the
+/// [`PropertyKey`] for the tables built by [`DerivedCoreProperties::load`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DerivedCoreProperty {
+	Math,
+	Alphabetic,
+	Lowercase,
+	Uppercase,
+	IdStart,
+	IdContinue,
+	XidStart,
+	XidContinue,
+	DefaultIgnorableCodePoint,
+	GraphemeBase,
+	GraphemeExtend,
+}
+
+impl DerivedCoreProperty {
+	/// List of all supported properties, in declaration order.
+	pub fn all() -> &'static [DerivedCoreProperty] {
+		&[
+			DerivedCoreProperty::Math,
+			DerivedCoreProperty::Alphabetic,
+			DerivedCoreProperty::Lowercase,
+			DerivedCoreProperty::Uppercase,
+			DerivedCoreProperty::IdStart,
+			DerivedCoreProperty::IdContinue,
+			DerivedCoreProperty::XidStart,
+			DerivedCoreProperty::XidContinue,
+			DerivedCoreProperty::DefaultIgnorableCodePoint,
+			DerivedCoreProperty::GraphemeBase,
+			DerivedCoreProperty::GraphemeExtend,
+		]
+	}
+
+	/// Property name as used in the data file (e.g. `XID_Start`).
+	pub fn name(&self) -> &'static str {
+		match self {
+			DerivedCoreProperty::Math => "Math",
+			DerivedCoreProperty::Alphabetic => "Alphabetic",
+			DerivedCoreProperty::Lowercase => "Lowercase",
+			DerivedCoreProperty::Uppercase => "Uppercase",
+			DerivedCoreProperty::IdStart => "ID_Start",
+			DerivedCoreProperty::IdContinue => "ID_Continue",
+			DerivedCoreProperty::XidStart => "XID_Start",
+			DerivedCoreProperty::XidContinue => "XID_Continue",
+			DerivedCoreProperty::DefaultIgnorableCodePoint => "Default_Ignorable_Code_Point",
+			DerivedCoreProperty::GraphemeBase => "Grapheme_Base",
+			DerivedCoreProperty::GraphemeExtend => "Grapheme_Extend",
+		}
+	}
+}
+
+impl PropertyKey for DerivedCoreProperty {
+	type Value = bool;
+}
+
+/// Loader for the derived core properties in `DerivedCoreProperties.txt`.
+pub struct DerivedCoreProperties;
+
+impl DerivedCoreProperties {
+	/// Build a [`RangeTable`] with all the [`DerivedCoreProperty`] keys set
+	/// for the entire codespace. Codepoints not listed for a property are
+	/// `false`.
+	///
+	/// ```
+	/// # use ucd_parser::*;
+	/// let table = DerivedCoreProperties::load();
+	/// assert_eq!(table.lookup(0x41, DerivedCoreProperty::Alphabetic), Some(true));
+	/// assert_eq!(table.lookup(0x30, DerivedCoreProperty::Alphabetic), Some(false));
+	/// ```
+	pub fn load() -> RangeTable {
+		let mut table = RangeTable::new();
+		for &property in DerivedCoreProperty::all() {
+			table.set_range(0..=0x10FFFF, property, false);
+			for (sta, end) in derived_core_ranges(property.name()) {
+				table.set_range(sta..=end, property, true);
+			}
+		}
+		table.coalesce();
+		table
+	}
+}
+
+/// Returns true if the codepoint has the `XID_Start` property, i.e. it can
+/// start an identifier.
+pub fn is_xid_start(code: u32) -> bool {
+	static RANGES: Lazy<Vec<(u32, u32)>> =
+		Lazy::new(|| sorted_ranges(DerivedCoreProperty::XidStart));
+	ranges_contain(&RANGES, code)
+}
+
+/// Returns true if the codepoint has the `Default_Ignorable_Code_Point`
+/// property, i.e. it should be invisible when not supported.
+pub fn is_default_ignorable(code: u32) -> bool {
+	static RANGES: Lazy<Vec<(u32, u32)>> =
+		Lazy::new(|| sorted_ranges(DerivedCoreProperty::DefaultIgnorableCodePoint));
+	ranges_contain(&RANGES, code)
+}
+
+fn sorted_ranges(property: DerivedCoreProperty) -> Vec<(u32, u32)> {
+	let mut ranges = derived_core_ranges(property.name());
+	ranges.sort();
+	ranges
+}
+
+fn ranges_contain(ranges: &[(u32, u32)], code: u32) -> bool {
+	let index = ranges.partition_point(|x| x.1 < code);
+	ranges.get(index).map(|x| x.0 <= code).unwrap_or(false)
+}
+
+/// Return the ranges listed for a property in `DerivedCoreProperties.txt`.
+fn derived_core_ranges(property: &str) -> Vec<(u32, u32)> {
+	let input = Input::get(InputFile::DerivedCoreProperties);
+	let lines = input.lines().map(|line| parse_range_line(line).unwrap());
+	let lines = lines.filter(|(_, _, value)| *value == property);
+	lines.map(|(sta, end, _)| (sta, end)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn builds_case_ignorable_table() {
+		let table = build_case_ignorable_table();
+		let value = |code: u32| table.lookup(code, CaseIgnorableProperty);
+
+		// combining mark
+		assert_eq!(value(0x0301), Some(true));
+		// format character
+		assert_eq!(value(0x00AD), Some(true));
+		// apostrophe
+		assert_eq!(value(0x0027), Some(true));
+
+		assert_eq!(value(0x0041), Some(false));
+		assert_eq!(value(0x0061), Some(false));
+		assert_eq!(value(0x10FFFF), Some(false));
+	}
+
+	#[test]
+	fn loads_derived_core_properties() {
+		let table = DerivedCoreProperties::load();
+		let value = |code: u32, key: DerivedCoreProperty| table.lookup(code, key).unwrap();
+
+		assert!(value(0x41, DerivedCoreProperty::XidStart));
+		assert!(value(0x41, DerivedCoreProperty::Uppercase));
+		assert!(!value(0x41, DerivedCoreProperty::Lowercase));
+		assert!(!value(0x30, DerivedCoreProperty::XidStart));
+		assert!(value(0x30, DerivedCoreProperty::XidContinue));
+		assert!(value(0x2B, DerivedCoreProperty::Math));
+		assert!(value(0x0301, DerivedCoreProperty::GraphemeExtend));
+		assert!(!value(0x0301, DerivedCoreProperty::GraphemeBase));
+		assert!(value(
+			0x00AD,
+			DerivedCoreProperty::DefaultIgnorableCodePoint
+		));
+		assert!(!value(0x10FFFF, DerivedCoreProperty::Alphabetic));
+
+		for &property in DerivedCoreProperty::all() {
+			assert!(!derived_core_ranges(property.name()).is_empty());
+		}
+	}
+
+	#[test]
+	fn checks_default_ignorable() {
+		assert!(is_default_ignorable(0x00AD));
+		assert!(is_default_ignorable(0x200B));
+		assert!(is_default_ignorable(0xE0FFF));
+		assert!(!is_default_ignorable(0x0041));
+		assert!(!is_default_ignorable(0x0020));
+	}
+
+	#[test]
+	fn checks_xid_start() {
+		assert!(is_xid_start('a' as u32));
+		assert!(is_xid_start('Z' as u32));
+		assert!(is_xid_start(0x4E00));
+		assert!(!is_xid_start('0' as u32));
+		assert!(!is_xid_start('_' as u32));
+		assert!(!is_xid_start(0x10FFFF));
+	}
+}
@@ -0,0 +1,179 @@
+use std::ops::RangeInclusive;
+
+use once_cell::sync::Lazy;
+use ucd_property_ranges::{PropertyKey, RangeTable};
+
+use crate::{
+	input::{Input, InputFile},
+	parse::{parse_fields, parse_range_line},
+	NumericValue,
+};
+
+/// [`PropertyKey`] for [`NumericValue`]s in a [`RangeTable`].
+#[derive(Clone, PartialEq)]
+pub struct NumericValueProperty;
+
+impl PropertyKey for NumericValueProperty {
+	type Value = NumericValue;
+}
+
+/// Range of codepoints from `extracted/DerivedNumericValues.txt` with their
+/// `Numeric_Value`.
+///
+/// This is derived from both `UnicodeData.txt` and the numeric values for
+/// CJK ideographs in the Unihan database, so it includes some codepoints
+/// that don't have a numeric value in [`UnicodeData`](crate::UnicodeData).
+///
+/// ```
+/// # use ucd_parser::{numeric_value_for, NumericValue};
+/// assert_eq!(numeric_value_for(0x0035), NumericValue::Integer(5));
+/// assert_eq!(numeric_value_for(0x0F33), NumericValue::Rational(-1, 2));
+/// assert_eq!(numeric_value_for(0x0041), NumericValue::None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DerivedNumericValue {
+	/// Inclusive range of codepoints.
+	pub range: RangeInclusive<u32>,
+
+	/// Numeric value for the codepoints.
+	pub value: NumericValue,
+}
+
+impl DerivedNumericValue {
+	/// List of ranges from the UCD data in file order. Lazy-loaded from
+	/// `extracted/DerivedNumericValues.txt`.
+	pub fn list() -> &'static [DerivedNumericValue] {
+		static LIST: Lazy<Box<[DerivedNumericValue]>> = Lazy::new(|| {
+			let input = Input::get(InputFile::DerivedNumericValues);
+			let lines = input.lines();
+			let list = lines.map(|x| DerivedNumericValue::parse(x).unwrap());
+			let list = list.collect::<Vec<_>>();
+			list.into_boxed_slice()
+		});
+		&LIST
+	}
+
+	/// Build a [`RangeTable`] with the [`NumericValueProperty`] for all
+	/// codepoints listed in `extracted/DerivedNumericValues.txt`. Codepoints
+	/// without a numeric value are not mapped.
+	pub fn load() -> RangeTable {
+		let mut table = RangeTable::new();
+		for it in Self::list() {
+			table.set_range(it.range.clone(), NumericValueProperty, it.value);
+		}
+		table
+	}
+
+	/// Parse a `CODE ; DECIMAL ; ; RATIONAL` line (e.g. `0F33 ; -0.5 ; ; -1/2`).
+	///
+	/// The value is taken from the exact rational field. The decimal field is
+	/// only an approximation and is ignored.
+	pub fn parse(input: &str) -> Result<Self, String> {
+		let error = |err: String| format!("derived numeric value: {} -- in `{}`", err, input);
+		let (sta, end, fields) =
+			parse_range_line(input).map_err(|err| format!("derived numeric value: {}", err))?;
+		let [_, _, rational] = parse_fields::<3>(fields).map_err(error)?;
+		let value = NumericValue::parse(rational).map_err(error)?;
+		if value == NumericValue::None {
+			return Err(error("missing numeric value".to_string()));
+		}
+		Ok(DerivedNumericValue {
+			range: sta..=end,
+			value,
+		})
+	}
+}
+
+/// Returns the `Numeric_Value` for a codepoint from
+/// `extracted/DerivedNumericValues.txt`, or [`NumericValue::None`] if it
+/// has no numeric value.
+pub fn numeric_value_for(code: u32) -> NumericValue {
+	static SORTED: Lazy<Vec<&'static DerivedNumericValue>> = Lazy::new(|| {
+		let mut sorted = DerivedNumericValue::list().iter().collect::<Vec<_>>();
+		sorted.sort_by_key(|x| *x.range.start());
+		sorted
+	});
+
+	let index = SORTED.partition_point(|x| *x.range.end() < code);
+	SORTED
+		.get(index)
+		.filter(|x| x.range.contains(&code))
+		.map(|x| x.value)
+		.unwrap_or(NumericValue::None)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_from_string() {
+		let entry = DerivedNumericValue::parse("0035          ; 5.0 ; ; 5").unwrap();
+		assert_eq!(entry.range, 0x35..=0x35);
+		assert_eq!(entry.value, NumericValue::Integer(5));
+
+		let entry = DerivedNumericValue::parse("0F33          ; -0.5 ; ; -1/2").unwrap();
+		assert_eq!(entry.range, 0xF33..=0xF33);
+		assert_eq!(entry.value, NumericValue::Rational(-1, 2));
+
+		let entry = DerivedNumericValue::parse("1F100..1F101  ; 0.0 ; ; 0").unwrap();
+		assert_eq!(entry.range, 0x1F100..=0x1F101);
+		assert_eq!(entry.value, NumericValue::Integer(0));
+	}
+
+	#[test]
+	fn parse_invalid_line_returns_error() {
+		let err = DerivedNumericValue::parse("0035 ; 5.0").unwrap_err();
+		assert!(err.contains("invalid row format"));
+		assert!(err.contains("-- in `0035 ; 5.0`"));
+
+		let err = DerivedNumericValue::parse("0035 ; 5.0 ; ; x").unwrap_err();
+		assert!(err.contains("not a valid numeric value: `x`"));
+
+		let err = DerivedNumericValue::parse("0035 ; 5.0 ; ; ").unwrap_err();
+		assert!(err.contains("missing numeric value"));
+
+		assert!(DerivedNumericValue::parse("xx ; 5.0 ; ; 5").is_err());
+	}
+
+	#[test]
+	fn can_load_from_ucd() {
+		let list = DerivedNumericValue::list();
+		assert_eq!(
+			list[0],
+			DerivedNumericValue::parse("0F33 ; -0.5 ; ; -1/2").unwrap()
+		);
+
+		let table = DerivedNumericValue::load();
+		let lookup = |code| table.lookup(code, NumericValueProperty);
+		assert_eq!(lookup(0x0030), Some(NumericValue::Integer(0)));
+		assert_eq!(lookup(0x00BD), Some(NumericValue::Rational(1, 2)));
+		assert_eq!(lookup(0x0041), None);
+	}
+
+	#[test]
+	fn returns_numeric_value_for_codepoint() {
+		assert_eq!(numeric_value_for(0x0039), NumericValue::Integer(9));
+		assert_eq!(numeric_value_for(0x0F33), NumericValue::Rational(-1, 2));
+		assert_eq!(numeric_value_for(0x2189), NumericValue::Integer(0));
+		assert_eq!(numeric_value_for(0x0041), NumericValue::None);
+
+		// CJK ideographs with a numeric value from Unihan are not in UnicodeData
+		assert_eq!(numeric_value_for(0x3405), NumericValue::Integer(5));
+	}
+
+	#[test]
+	fn matches_unicode_data_numeric_values() {
+		for row in crate::UnicodeData::list() {
+			if row.numeric_value != NumericValue::None {
+				let expected = row.numeric_value.reduced();
+				assert_eq!(
+					numeric_value_for(row.code).reduced(),
+					expected,
+					"for U+{:04X}",
+					row.code
+				);
+			}
+		}
+	}
+}
@@ -0,0 +1,223 @@
+use once_cell::sync::Lazy;
+use ucd_property_ranges::{PropertyKey, RangeTable};
+
+use crate::{
+	input::{Input, InputFile},
+	parse::parse_range_line,
+};
+
+/// Ranges with a default other than [`EastAsianWidth::Neutral`] for
+/// codepoints not listed in `EastAsianWidth.txt`.
+const DEFAULT_RANGES: [(u32, u32, EastAsianWidth); 5] = [
+	(0x3400, 0x4DBF, EastAsianWidth::Wide),
+	(0x4E00, 0x9FFF, EastAsianWidth::Wide),
+	(0xF900, 0xFAFF, EastAsianWidth::Wide),
+	(0x20000, 0x2FFFD, EastAsianWidth::Wide),
+	(0x30000, 0x3FFFD, EastAsianWidth::Wide),
+];
+
+/// Values for the `East_Asian_Width` property, used to determine the width
+/// of characters in East Asian typography and fixed-width terminals.
+///
+/// See https://www.unicode.org/reports/tr11/
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EastAsianWidth {
+	/// `A` characters that can be wide or narrow depending on the context.
+	Ambiguous,
+	/// `F` fullwidth compatibility characters.
+	Fullwidth,
+	/// `H` halfwidth compatibility characters.
+	Halfwidth,
+	/// `N` characters that don't occur in East Asian typography.
+	Neutral,
+	/// `Na` narrow characters with a wide counterpart.
+	Narrow,
+	/// `W` wide characters.
+	Wide,
+}
+
+impl EastAsianWidth {
+	pub fn parse<T: AsRef<str>>(input: T) -> Option<Self> {
+		let value = match input.as_ref() {
+			"A" => EastAsianWidth::Ambiguous,
+			"F" => EastAsianWidth::Fullwidth,
+			"H" => EastAsianWidth::Halfwidth,
+			"N" => EastAsianWidth::Neutral,
+			"Na" => EastAsianWidth::Narrow,
+			"W" => EastAsianWidth::Wide,
+			_ => return None,
+		};
+		Some(value)
+	}
+
+	/// Returns the default width for codepoints not listed in the file.
+	///
+	/// This is [`EastAsianWidth::Wide`] for the CJK ideograph blocks and the
+	/// planes 2 and 3, and [`EastAsianWidth::Neutral`] otherwise, as
+	/// documented in `EastAsianWidth.txt`.
+	pub fn default_for(code: u32) -> Self {
+		DEFAULT_RANGES
+			.iter()
+			.find(|&&(sta, end, _)| sta <= code && code <= end)
+			.map(|&(_, _, value)| value)
+			.unwrap_or(EastAsianWidth::Neutral)
+	}
+
+	/// Build a [`RangeTable`] with the [`EastAsianWidthProperty`] for the
+	/// entire codespace from `EastAsianWidth.txt`.
+	///
+	/// Codepoints not listed in the file are set to their default value as
+	/// returned by [`EastAsianWidth::default_for`].
+	pub fn load() -> RangeTable {
+		let mut table = RangeTable::new();
+		table.set_range(
+			0..=0x10FFFF,
+			EastAsianWidthProperty,
+			EastAsianWidth::Neutral,
+		);
+		for &(sta, end, value) in DEFAULT_RANGES.iter() {
+			table.set_range(sta..=end, EastAsianWidthProperty, value);
+		}
+
+		for &(sta, end, value) in east_asian_width_ranges() {
+			table.set_range(sta..=end, EastAsianWidthProperty, value);
+		}
+		table
+	}
+
+	/// Parse a line from `EastAsianWidth.txt`.
+	pub fn parse_line(input: &str) -> Result<(u32, u32, Self), String> {
+		let (sta, end, value) =
+			parse_range_line(input).map_err(|err| format!("east asian width: {}", err))?;
+		let value = EastAsianWidth::parse(value).ok_or_else(|| {
+			format!(
+				"east asian width: `{}` is not a valid width -- in `{}`",
+				value, input
+			)
+		})?;
+		Ok((sta, end, value))
+	}
+}
+
+impl std::fmt::Display for EastAsianWidth {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let output = match self {
+			EastAsianWidth::Ambiguous => "A",
+			EastAsianWidth::Fullwidth => "F",
+			EastAsianWidth::Halfwidth => "H",
+			EastAsianWidth::Neutral => "N",
+			EastAsianWidth::Narrow => "Na",
+			EastAsianWidth::Wide => "W",
+		};
+		write!(f, "{}", output)
+	}
+}
+
+/// [`PropertyKey`] for [`EastAsianWidth`] values in a [`RangeTable`].
+#[derive(Clone, PartialEq)]
+pub struct EastAsianWidthProperty;
+
+impl PropertyKey for EastAsianWidthProperty {
+	type Value = EastAsianWidth;
+}
+
+/// Returns the [`EastAsianWidth`] for a codepoint.
+///
+/// Codepoints not listed in the file use [`EastAsianWidth::default_for`].
+///
+/// ```
+/// # use ucd_parser::{width_for, EastAsianWidth};
+/// assert_eq!(width_for(0x4E00), EastAsianWidth::Wide);
+/// assert_eq!(width_for(0x0041), EastAsianWidth::Narrow);
+/// ```
+pub fn width_for(code: u32) -> EastAsianWidth {
+	let ranges = east_asian_width_ranges();
+	let index = ranges.partition_point(|x| x.1 < code);
+	match ranges.get(index) {
+		Some(&(sta, _, value)) if sta <= code => value,
+		_ => EastAsianWidth::default_for(code),
+	}
+}
+
+/// Ranges from `EastAsianWidth.txt` sorted by codepoint.
+fn east_asian_width_ranges() -> &'static [(u32, u32, EastAsianWidth)] {
+	static RANGES: Lazy<Vec<(u32, u32, EastAsianWidth)>> = Lazy::new(|| {
+		let input = Input::get(InputFile::EastAsianWidth);
+		let lines = input.lines();
+		let mut ranges = lines
+			.map(|x| EastAsianWidth::parse_line(x).unwrap())
+			.collect::<Vec<_>>();
+		ranges.sort_by_key(|x| x.0);
+		ranges
+	});
+	&RANGES
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn supports_to_string() {
+		fn check(input: EastAsianWidth, expected: &'static str) {
+			assert_eq!(input.to_string(), expected);
+			assert_eq!(
+				EastAsianWidth::parse(input.to_string()).expect(expected),
+				input
+			);
+		}
+
+		check(EastAsianWidth::Ambiguous, "A");
+		check(EastAsianWidth::Fullwidth, "F");
+		check(EastAsianWidth::Halfwidth, "H");
+		check(EastAsianWidth::Neutral, "N");
+		check(EastAsianWidth::Narrow, "Na");
+		check(EastAsianWidth::Wide, "W");
+	}
+
+	#[test]
+	fn parse_from_invalid_string_is_none() {
+		assert_eq!(EastAsianWidth::parse("xx"), None);
+		assert_eq!(EastAsianWidth::parse("Wide"), None);
+	}
+
+	#[test]
+	fn parses_line() {
+		let line = EastAsianWidth::parse_line("3000;F").unwrap();
+		assert_eq!(line, (0x3000, 0x3000, EastAsianWidth::Fullwidth));
+
+		let line = EastAsianWidth::parse_line("0021..0023;Na").unwrap();
+		assert_eq!(line, (0x21, 0x23, EastAsianWidth::Narrow));
+
+		let err = EastAsianWidth::parse_line("0021;X").unwrap_err();
+		assert!(err.contains("`X` is not a valid width"));
+	}
+
+	#[test]
+	fn returns_width_for_codepoint() {
+		assert_eq!(width_for(0x4E00), EastAsianWidth::Wide);
+		assert_eq!(width_for(0xFF21), EastAsianWidth::Fullwidth);
+		assert_eq!(width_for(0xFF61), EastAsianWidth::Halfwidth);
+		assert_eq!(width_for(0x00A1), EastAsianWidth::Ambiguous);
+		assert_eq!(width_for(0x0041), EastAsianWidth::Narrow);
+		assert_eq!(width_for(0x0000), EastAsianWidth::Neutral);
+
+		// unlisted codepoints use the defaults
+		assert_eq!(width_for(0x0378), EastAsianWidth::Neutral);
+		assert_eq!(width_for(0x2FFFD), EastAsianWidth::Wide);
+		assert_eq!(width_for(0x10FFFF), EastAsianWidth::Neutral);
+	}
+
+	#[test]
+	fn loads_table_from_ucd() {
+		let table = EastAsianWidth::load();
+		for code in [
+			0x0, 0x41, 0xA1, 0x378, 0x3000, 0x4E00, 0xFF21, 0x2FFFD, 0x10FFFF,
+		] {
+			assert_eq!(
+				table.lookup(code, EastAsianWidthProperty),
+				Some(width_for(code))
+			);
+		}
+	}
+}
@@ -0,0 +1,56 @@
+use std::ops::RangeInclusive;
+
+/// Range of the `Regional_Indicator` symbols, which map to the letters `A`
+/// to `Z` and are used in pairs to form flag emoji.
+pub const REGIONAL_INDICATORS: RangeInclusive<u32> = 0x1F1E6..=0x1F1FF;
+
+/// Returns true if the codepoint is a `Regional_Indicator` symbol.
+pub fn is_regional_indicator(code: u32) -> bool {
+	REGIONAL_INDICATORS.contains(&code)
+}
+
+/// Returns the two-letter region code for a pair of regional indicators
+/// forming a flag emoji, or [`None`] if either is not a regional indicator.
+///
+/// Note that this does not check that the region code is valid.
+///
+/// ```
+/// # use ucd_parser::flag_sequence;
+/// assert_eq!(flag_sequence(0x1F1E7, 0x1F1F7), Some("BR".to_string()));
+/// ```
+pub fn flag_sequence(a: u32, b: u32) -> Option<String> {
+	let letter = |code: u32| {
+		if is_regional_indicator(code) {
+			let offset = code - REGIONAL_INDICATORS.start();
+			Some((b'A' + offset as u8) as char)
+		} else {
+			None
+		}
+	};
+	Some([letter(a)?, letter(b)?].iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn detects_regional_indicators() {
+		assert!(is_regional_indicator(0x1F1E6));
+		assert!(is_regional_indicator(0x1F1FA));
+		assert!(is_regional_indicator(0x1F1FF));
+
+		assert!(!is_regional_indicator(0x1F1E5));
+		assert!(!is_regional_indicator(0x1F200));
+		assert!(!is_regional_indicator('U' as u32));
+	}
+
+	#[test]
+	fn returns_flag_sequence() {
+		assert_eq!(flag_sequence(0x1F1FA, 0x1F1F8), Some("US".to_string()));
+		assert_eq!(flag_sequence(0x1F1E6, 0x1F1FF), Some("AZ".to_string()));
+
+		assert_eq!(flag_sequence(0x1F1FA, 'S' as u32), None);
+		assert_eq!(flag_sequence('U' as u32, 0x1F1F8), None);
+	}
+}
@@ -0,0 +1,235 @@
+use once_cell::sync::Lazy;
+use ucd_property_ranges::{PropertyKey, RangeTable};
+
+use crate::{
+	input::{Input, InputFile},
+	parse::{parse_range_line, OrUnknown},
+};
+
+/// Values for the `Grapheme_Cluster_Break` property used by the grapheme
+/// cluster boundary rules in Unicode Text Segmentation.
+///
+/// See https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GraphemeBreak {
+	/// Default for codepoints not listed in the data file.
+	Other,
+	/// `CR` carriage return.
+	CR,
+	/// `LF` line feed.
+	LF,
+	/// `Control`: control, format, and line/paragraph separators.
+	Control,
+	/// `Extend`: grapheme extenders such as nonspacing marks.
+	Extend,
+	/// `ZWJ` zero width joiner.
+	ZWJ,
+	/// `Regional_Indicator` symbols used for flag sequences.
+	RegionalIndicator,
+	/// `Prepend` characters such as prefixed number signs.
+	Prepend,
+	/// `SpacingMark`: spacing combining marks that extend a cluster.
+	SpacingMark,
+	/// `L` Hangul leading jamo.
+	L,
+	/// `V` Hangul vowel jamo.
+	V,
+	/// `T` Hangul trailing jamo.
+	T,
+	/// `LV` Hangul syllables without a trailing consonant.
+	LV,
+	/// `LVT` Hangul syllables with a trailing consonant.
+	LVT,
+}
+
+impl GraphemeBreak {
+	pub fn parse<T: AsRef<str>>(input: T) -> Option<Self> {
+		let value = match input.as_ref() {
+			"Other" => GraphemeBreak::Other,
+			"CR" => GraphemeBreak::CR,
+			"LF" => GraphemeBreak::LF,
+			"Control" => GraphemeBreak::Control,
+			"Extend" => GraphemeBreak::Extend,
+			"ZWJ" => GraphemeBreak::ZWJ,
+			"Regional_Indicator" => GraphemeBreak::RegionalIndicator,
+			"Prepend" => GraphemeBreak::Prepend,
+			"SpacingMark" => GraphemeBreak::SpacingMark,
+			"L" => GraphemeBreak::L,
+			"V" => GraphemeBreak::V,
+			"T" => GraphemeBreak::T,
+			"LV" => GraphemeBreak::LV,
+			"LVT" => GraphemeBreak::LVT,
+			_ => return None,
+		};
+		Some(value)
+	}
+
+	/// Same as [`GraphemeBreak::parse`], but returns [`OrUnknown::Unknown`] with the
+	/// input text for unrecognized values instead of [`None`].
+	pub fn parse_or_unknown<T: AsRef<str>>(input: T) -> OrUnknown<Self> {
+		OrUnknown::parse_with(input.as_ref(), |x| Self::parse(x))
+	}
+
+	/// Build a [`RangeTable`] with the [`GraphemeBreakProperty`] for the
+	/// entire codespace from `GraphemeBreakProperty.txt`.
+	///
+	/// Codepoints not listed in the file are set to [`GraphemeBreak::Other`],
+	/// which is the `@missing` default declared by the file.
+	pub fn load() -> RangeTable {
+		let mut table = RangeTable::new();
+		Self::set_ranges(&mut table);
+		table
+	}
+
+	/// Same as [`GraphemeBreak::load`].
+	#[deprecated(note = "use `GraphemeBreak::load` instead")]
+	pub fn build_range_table() -> RangeTable {
+		Self::load()
+	}
+
+	/// Set the [`GraphemeBreakProperty`] for the entire codespace in an
+	/// existing table. See [`GraphemeBreak::load`].
+	pub fn set_ranges(table: &mut RangeTable) {
+		table.set_range(0..=0x10FFFF, GraphemeBreakProperty, GraphemeBreak::Other);
+		for &(sta, end, value) in grapheme_break_ranges() {
+			table.set_range(sta..=end, GraphemeBreakProperty, value);
+		}
+	}
+}
+
+impl std::fmt::Display for GraphemeBreak {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let output = match self {
+			GraphemeBreak::Other => "Other",
+			GraphemeBreak::CR => "CR",
+			GraphemeBreak::LF => "LF",
+			GraphemeBreak::Control => "Control",
+			GraphemeBreak::Extend => "Extend",
+			GraphemeBreak::ZWJ => "ZWJ",
+			GraphemeBreak::RegionalIndicator => "Regional_Indicator",
+			GraphemeBreak::Prepend => "Prepend",
+			GraphemeBreak::SpacingMark => "SpacingMark",
+			GraphemeBreak::L => "L",
+			GraphemeBreak::V => "V",
+			GraphemeBreak::T => "T",
+			GraphemeBreak::LV => "LV",
+			GraphemeBreak::LVT => "LVT",
+		};
+		write!(f, "{}", output)
+	}
+}
+
+/// [`PropertyKey`] for [`GraphemeBreak`] values in a [`RangeTable`].
+#[derive(Clone, PartialEq)]
+pub struct GraphemeBreakProperty;
+
+impl PropertyKey for GraphemeBreakProperty {
+	type Value = GraphemeBreak;
+}
+
+/// Returns the [`GraphemeBreak`] value for a codepoint.
+///
+/// Codepoints not listed in the file are [`GraphemeBreak::Other`].
+///
+/// ```
+/// # use ucd_parser::{grapheme_break_for, GraphemeBreak};
+/// assert_eq!(grapheme_break_for(0x000D), GraphemeBreak::CR);
+/// assert_eq!(grapheme_break_for(0x0041), GraphemeBreak::Other);
+/// ```
+pub fn grapheme_break_for(code: u32) -> GraphemeBreak {
+	let ranges = grapheme_break_ranges();
+	let index = ranges.partition_point(|x| x.1 < code);
+	match ranges.get(index) {
+		Some(&(sta, _, value)) if sta <= code => value,
+		_ => GraphemeBreak::Other,
+	}
+}
+
+/// Ranges from `GraphemeBreakProperty.txt` sorted by codepoint.
+fn grapheme_break_ranges() -> &'static [(u32, u32, GraphemeBreak)] {
+	static RANGES: Lazy<Vec<(u32, u32, GraphemeBreak)>> = Lazy::new(|| {
+		let input = Input::get(InputFile::GraphemeBreakProperty);
+		let lines = input.lines().map(|line| {
+			let (sta, end, value) = parse_range_line(line).unwrap();
+			let value = GraphemeBreak::parse(value)
+				.unwrap_or_else(|| panic!("invalid grapheme break `{}` -- in `{}`", value, line));
+			(sta, end, value)
+		});
+		let mut ranges = lines.collect::<Vec<_>>();
+		ranges.sort_by_key(|x| x.0);
+		ranges
+	});
+	&RANGES
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn supports_to_string() {
+		fn check(input: GraphemeBreak, expected: &'static str) {
+			assert_eq!(input.to_string(), expected);
+			assert_eq!(
+				GraphemeBreak::parse(input.to_string()).expect(expected),
+				input
+			);
+		}
+
+		check(GraphemeBreak::Other, "Other");
+		check(GraphemeBreak::CR, "CR");
+		check(GraphemeBreak::LF, "LF");
+		check(GraphemeBreak::Control, "Control");
+		check(GraphemeBreak::Extend, "Extend");
+		check(GraphemeBreak::ZWJ, "ZWJ");
+		check(GraphemeBreak::RegionalIndicator, "Regional_Indicator");
+		check(GraphemeBreak::Prepend, "Prepend");
+		check(GraphemeBreak::SpacingMark, "SpacingMark");
+		check(GraphemeBreak::L, "L");
+		check(GraphemeBreak::V, "V");
+		check(GraphemeBreak::T, "T");
+		check(GraphemeBreak::LV, "LV");
+		check(GraphemeBreak::LVT, "LVT");
+	}
+
+	#[test]
+	fn parse_from_invalid_string_is_none() {
+		assert_eq!(GraphemeBreak::parse("xx"), None);
+	}
+
+	#[test]
+	fn builds_table_from_ucd() {
+		let table = GraphemeBreak::load();
+		let value = |code: u32| table.lookup(code, GraphemeBreakProperty);
+
+		// unlisted codepoints use the default
+		assert_eq!(value(0x0041), Some(GraphemeBreak::Other));
+		assert_eq!(value(0x10FFFF), Some(GraphemeBreak::Other));
+
+		assert_eq!(value(0x000D), Some(GraphemeBreak::CR));
+		assert_eq!(value(0x0301), Some(GraphemeBreak::Extend));
+		assert_eq!(value(0x200D), Some(GraphemeBreak::ZWJ));
+		assert_eq!(value(0x1F1E6), Some(GraphemeBreak::RegionalIndicator));
+		assert_eq!(value(0xAC00), Some(GraphemeBreak::LV));
+		assert_eq!(value(0xAC01), Some(GraphemeBreak::LVT));
+	}
+
+	#[test]
+	fn returns_grapheme_break_for_codepoint() {
+		assert_eq!(grapheme_break_for('\r' as u32), GraphemeBreak::CR);
+		assert_eq!(grapheme_break_for('\n' as u32), GraphemeBreak::LF);
+		assert_eq!(grapheme_break_for(0x0301), GraphemeBreak::Extend);
+		assert_eq!(
+			grapheme_break_for(0x1F1FA),
+			GraphemeBreak::RegionalIndicator
+		);
+		assert_eq!(grapheme_break_for(0x0041), GraphemeBreak::Other);
+		assert_eq!(grapheme_break_for(0x10FFFF), GraphemeBreak::Other);
+
+		let table = GraphemeBreak::load();
+		for code in [0x0, 0xD, 0x41, 0x301, 0x200D, 0xAC00, 0x1F1E6, 0x10FFFF] {
+			let expected = grapheme_break_for(code);
+			assert_eq!(table.lookup(code, GraphemeBreakProperty), Some(expected));
+		}
+	}
+}
@@ -12,12 +12,44 @@ macro_rules! include_ucd {
 	};
 }
 
+use std::{
+	collections::HashMap,
+	io,
+	path::{Path, PathBuf},
+	sync::Mutex,
+	time::SystemTime,
+};
+
+use once_cell::sync::Lazy;
+
+/// Project root directory used as base for [`Input::read`].
+const PROJECT_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
 /// Enum of supported input files from the UCD data.
 #[derive(Clone, Copy)]
 pub enum InputFile {
+	BidiMirroring,
 	Blocks,
+	CaseFolding,
+	CompositionExclusions,
+	DerivedAge,
+	DerivedCoreProperties,
+	DerivedGeneralCategory,
+	DerivedNumericValues,
+	EastAsianWidth,
+	GraphemeBreakProperty,
+	Jamo,
+	LineBreak,
+	NameAliases,
+	NamedSequences,
+	PropertyValueAliases,
 	ReadMe,
+	ScriptExtensions,
+	Scripts,
+	SentenceBreakProperty,
+	SpecialCasing,
 	UnicodeData,
+	WordBreakProperty,
 }
 
 /// Input wrapper providing support for reading data files from the UCD data.
@@ -27,25 +59,185 @@ impl Input {
 	/// Get one of the supported [`InputFile`]s from the UCD data.
 	pub fn get(file: InputFile) -> Self {
 		match file {
+			InputFile::BidiMirroring => include_ucd!("BidiMirroring.txt"),
 			InputFile::Blocks => include_ucd!("Blocks.txt"),
+			InputFile::CaseFolding => include_ucd!("CaseFolding.txt"),
+			InputFile::CompositionExclusions => include_ucd!("CompositionExclusions.txt"),
+			InputFile::DerivedAge => include_ucd!("DerivedAge.txt"),
+			InputFile::DerivedCoreProperties => include_ucd!("DerivedCoreProperties.txt"),
+			InputFile::DerivedGeneralCategory => {
+				include_ucd!("extracted/DerivedGeneralCategory.txt")
+			}
+			InputFile::DerivedNumericValues => {
+				include_ucd!("extracted/DerivedNumericValues.txt")
+			}
+			InputFile::EastAsianWidth => include_ucd!("EastAsianWidth.txt"),
+			InputFile::GraphemeBreakProperty => {
+				include_ucd!("auxiliary/GraphemeBreakProperty.txt")
+			}
+			InputFile::Jamo => include_ucd!("Jamo.txt"),
+			InputFile::LineBreak => include_ucd!("LineBreak.txt"),
+			InputFile::NameAliases => include_ucd!("NameAliases.txt"),
+			InputFile::NamedSequences => include_ucd!("NamedSequences.txt"),
+			InputFile::PropertyValueAliases => include_ucd!("PropertyValueAliases.txt"),
 			InputFile::ReadMe => include_ucd!("ReadMe.txt"),
+			InputFile::ScriptExtensions => include_ucd!("ScriptExtensions.txt"),
+			InputFile::Scripts => include_ucd!("Scripts.txt"),
+			InputFile::SentenceBreakProperty => {
+				include_ucd!("auxiliary/SentenceBreakProperty.txt")
+			}
+			InputFile::SpecialCasing => include_ucd!("SpecialCasing.txt"),
 			InputFile::UnicodeData => include_ucd!("UnicodeData.txt"),
+			InputFile::WordBreakProperty => include_ucd!("auxiliary/WordBreakProperty.txt"),
 		}
 	}
 
+	/// Read an input file at runtime, relative to the project root directory.
+	///
+	/// See [`Input::read_from`].
+	pub fn read(filename: impl AsRef<Path>) -> Self {
+		Self::read_from(PROJECT_ROOT, filename)
+	}
+
+	/// Read an input file at runtime, relative to the given base directory.
+	///
+	/// Files are cached by their resolved absolute path, so reading the same
+	/// file again (even through a different base) returns the cached text.
+	///
+	/// This will panic if the file cannot be read. See [`Input::try_read_from`]
+	/// for a non-panicking version.
+	pub fn read_from(base: impl AsRef<Path>, filename: impl AsRef<Path>) -> Self {
+		Self::try_read_from(base, filename).unwrap_or_else(|err| panic!("{}", err))
+	}
+
+	/// Same as [`Input::read`], but returns an error if the file cannot be
+	/// read instead of panicking.
+	pub fn try_read(filename: impl AsRef<Path>) -> io::Result<Self> {
+		Self::try_read_from(PROJECT_ROOT, filename)
+	}
+
+	/// Same as [`Input::read_from`], but returns an error if the file cannot
+	/// be read instead of panicking.
+	///
+	/// The error message includes the path for the file.
+	pub fn try_read_from(base: impl AsRef<Path>, filename: impl AsRef<Path>) -> io::Result<Self> {
+		static CACHE: Lazy<Mutex<HashMap<PathBuf, &'static str>>> = Lazy::new(Default::default);
+
+		let (path, error) = Self::resolve(base.as_ref(), filename.as_ref())?;
+		let mut cache = CACHE.lock().unwrap();
+		let text = match cache.get(&path) {
+			Some(text) => *text,
+			None => {
+				let text = Self::load(&path).map_err(error)?;
+				cache.insert(path, text);
+				text
+			}
+		};
+		Ok(Input(text))
+	}
+
+	/// Same as [`Input::read`], but reloads the file if it was modified
+	/// since it was last read with this method.
+	///
+	/// This is useful for files that may be regenerated during a build. The
+	/// file is considered modified if its modification time or length has
+	/// changed.
+	///
+	/// Note that the text for previous versions of the file is not released.
+	pub fn read_fresh(filename: impl AsRef<Path>) -> Self {
+		Self::try_read_fresh(filename).unwrap_or_else(|err| panic!("{}", err))
+	}
+
+	/// Same as [`Input::read_fresh`], but returns an error if the file cannot
+	/// be read instead of panicking.
+	pub fn try_read_fresh(filename: impl AsRef<Path>) -> io::Result<Self> {
+		type Entry = (Option<SystemTime>, u64, &'static str);
+		static CACHE: Lazy<Mutex<HashMap<PathBuf, Entry>>> = Lazy::new(Default::default);
+
+		let (path, error) = Self::resolve(PROJECT_ROOT.as_ref(), filename.as_ref())?;
+		let metadata = std::fs::metadata(&path).map_err(&error)?;
+		let (modified, len) = (metadata.modified().ok(), metadata.len());
+
+		let mut cache = CACHE.lock().unwrap();
+		let text = match cache.get(&path) {
+			Some(&(cached_modified, cached_len, text))
+				if modified.is_some() && cached_modified == modified && cached_len == len =>
+			{
+				text
+			}
+			_ => {
+				let text = Self::load(&path).map_err(error)?;
+				cache.insert(path, (modified, len, text));
+				text
+			}
+		};
+		Ok(Input(text))
+	}
+
+	/// Read an input file at runtime, relative to the project root, without
+	/// using or updating the cache.
+	///
+	/// Note that the returned text is never released.
+	pub fn read_uncached(filename: impl AsRef<Path>) -> Self {
+		let result = Self::resolve(PROJECT_ROOT.as_ref(), filename.as_ref())
+			.and_then(|(path, error)| Self::load(&path).map_err(error));
+		Input(result.unwrap_or_else(|err| panic!("{}", err)))
+	}
+
+	/// Resolve the absolute path for an input file, returning the path and a
+	/// function to add the path to read errors.
+	fn resolve(
+		base: &Path,
+		filename: &Path,
+	) -> io::Result<(PathBuf, impl Fn(io::Error) -> io::Error)> {
+		let path = base.join(filename);
+		let error = move |err: io::Error| {
+			let message = format!("reading input `{}`: {}", path.display(), err);
+			io::Error::new(err.kind(), message)
+		};
+		let resolved = base.join(filename).canonicalize().map_err(&error)?;
+		Ok((resolved, error))
+	}
+
+	fn load(path: &Path) -> io::Result<&'static str> {
+		let text = std::fs::read_to_string(path)?;
+		Ok(Box::leak(text.into_boxed_str()))
+	}
+
 	/// Iterator over the input lines filtering comments and blank lines.
 	pub fn lines(&self) -> impl Iterator<Item = &'static str> {
+		self.lines_with_prefix("#")
+	}
+
+	/// Same as [`Input::lines`], but using a custom comment prefix (e.g.
+	/// `//`). An empty prefix disables comment stripping, in which case only
+	/// blank lines are skipped.
+	pub fn lines_with_prefix<'a>(
+		&self,
+		comment: &'a str,
+	) -> impl Iterator<Item = &'static str> + 'a {
+		let lines = self.0.lines().map(move |x| match x.find(comment) {
+			Some(index) if !comment.is_empty() => x[..index].trim_end(),
+			_ => x.trim_end(),
+		});
+		lines.filter(|x| !x.is_empty())
+	}
+
+	/// Iterator over the input lines returning the data and the comment for
+	/// each line separately, skipping blank lines.
+	///
+	/// The data is empty for lines with only a comment. The comment does not
+	/// include the leading `#` and is trimmed.
+	pub fn lines_with_comments(
+		&self,
+	) -> impl Iterator<Item = (&'static str, Option<&'static str>)> {
 		let lines = self.0.lines();
 		let lines = lines
-			.map(|x| {
-				if let Some(index) = x.find('#') {
-					&x[0..index]
-				} else {
-					x
-				}
-			})
-			.map(|x| x.trim_end())
-			.filter(|x| x.len() > 0);
+			.filter(|x| !x.trim().is_empty())
+			.map(|x| match x.split_once('#') {
+				Some((line, comment)) => (line.trim_end(), Some(comment.trim())),
+				None => (x.trim_end(), None),
+			});
 		lines
 	}
 
@@ -53,10 +245,22 @@ impl Input {
 	pub fn text(&self) -> &'static str {
 		self.0.trim()
 	}
+
+	/// Returns the full text for the input as-is.
+	pub fn raw(&self) -> &'static str {
+		self.0
+	}
+
+	/// Returns the length of the input text in bytes.
+	pub fn byte_len(&self) -> usize {
+		self.0.len()
+	}
 }
 
 #[cfg(test)]
 mod tests {
+	use super::*;
+
 	macro_rules! read_test_input {
 		($filename:expr) => {
 			include_input!(concat!("ucd-parser/testdata/input/", $filename))
@@ -88,10 +292,169 @@ mod tests {
 		assert_eq!(input, vec!["nc 1", "nc 2", "nc 3", "nc 4"]);
 	}
 
+	#[test]
+	fn input_lines_with_prefix_filter_custom_comments() {
+		let input = read_test_input!("slash-comments.in");
+		let lines = input.lines_with_prefix("//").collect::<Vec<_>>();
+		assert_eq!(
+			lines,
+			vec![
+				"@directive 1",
+				"nc 1",
+				"nc 2 # not a comment",
+				"@directive 2"
+			]
+		);
+
+		let lines = input.lines().collect::<Vec<_>>();
+		assert_eq!(
+			lines,
+			vec![
+				"// comment",
+				"@directive 1",
+				"nc 1 // in-line comment",
+				"\t// indented comment",
+				"nc 2",
+				"@directive 2",
+			]
+		);
+
+		let lines = input.lines_with_prefix("").collect::<Vec<_>>();
+		assert_eq!(lines.len(), 6);
+		assert_eq!(lines[4], "nc 2 # not a comment");
+
+		let input = read_test_input!("comments.in");
+		let lines = input.lines_with_prefix("#").collect::<Vec<_>>();
+		assert_eq!(lines, input.lines().collect::<Vec<_>>());
+	}
+
+	#[test]
+	fn input_lines_with_comments_return_both() {
+		let input = read_test_input!("comments.in");
+		let input = input.lines_with_comments().collect::<Vec<_>>();
+		assert_eq!(
+			input,
+			vec![
+				("", Some("comment")),
+				("nc 1", None),
+				("", Some("comment")),
+				("nc 2", None),
+				("", Some("indented comment")),
+				("", Some("indented comment")),
+				("nc 3", None),
+				("nc 4", Some("in-line comment")),
+			]
+		);
+	}
+
+	#[test]
+	fn input_lines_with_comments_skip_empty() {
+		let input = read_test_input!("empty-lines.in");
+		let input = input.lines_with_comments().collect::<Vec<_>>();
+		assert_eq!(
+			input,
+			vec![
+				("non-empty 1", None),
+				("non-empty 2", None),
+				("non-empty 3", None),
+			]
+		);
+	}
+
 	#[test]
 	fn can_read_entire_file() {
 		let input = read_test_input!("basic-123.in");
 		let input = input.text();
 		assert_eq!(input, "line 1\nline 2\nline 3");
 	}
+
+	#[test]
+	fn returns_raw_text_and_length() {
+		let input = read_test_input!("basic-123.in");
+		let expected = include_bytes!("../testdata/input/basic-123.in");
+		assert_eq!(input.byte_len(), expected.len());
+		assert_eq!(input.raw().as_bytes(), expected);
+	}
+
+	#[test]
+	fn can_read_from_base_directory() {
+		let base = concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/input");
+		let input = Input::read_from(base, "basic-123.in");
+		let expected = read_test_input!("basic-123.in");
+		assert_eq!(input.raw(), expected.raw());
+
+		// both paths resolve to the same cached file
+		let default = Input::read("ucd-parser/testdata/input/basic-123.in");
+		assert!(std::ptr::eq(input.raw(), default.raw()));
+	}
+
+	#[test]
+	fn read_cache_does_not_mix_bases() {
+		let base = std::env::temp_dir().join(format!("ucd-parser-input-{}", std::process::id()));
+		std::fs::create_dir_all(&base).unwrap();
+		std::fs::write(base.join("basic-123.in"), "other").unwrap();
+
+		let other = Input::read_from(&base, "basic-123.in");
+		let input = Input::read("ucd-parser/testdata/input/basic-123.in");
+		std::fs::remove_dir_all(&base).unwrap();
+
+		assert_eq!(other.raw(), "other");
+		assert_eq!(input.text(), "line 1\nline 2\nline 3");
+	}
+
+	#[test]
+	fn try_read_returns_error_with_path() {
+		let err = Input::try_read("ucd-parser/testdata/input/missing.in")
+			.err()
+			.unwrap();
+		assert_eq!(err.kind(), io::ErrorKind::NotFound);
+		assert!(err.to_string().contains("testdata/input/missing.in"));
+
+		let input = Input::try_read("ucd-parser/testdata/input/basic-123.in").unwrap();
+		assert_eq!(input.text(), "line 1\nline 2\nline 3");
+	}
+
+	#[test]
+	fn read_fresh_reloads_modified_files() {
+		let base = std::env::temp_dir().join(format!("ucd-parser-fresh-{}", std::process::id()));
+		std::fs::create_dir_all(&base).unwrap();
+		let path = base.join("fresh.in");
+		std::fs::write(&path, "first").unwrap();
+
+		let first = Input::read_fresh(&path);
+		let again = Input::read_fresh(&path);
+		assert_eq!(first.raw(), "first");
+		assert!(std::ptr::eq(first.raw(), again.raw()));
+
+		// set the time explicitly in case of a coarse filesystem timestamp
+		std::fs::write(&path, "second").unwrap();
+		let file = std::fs::File::options().write(true).open(&path).unwrap();
+		let modified = std::time::SystemTime::now() + std::time::Duration::from_secs(10);
+		file.set_modified(modified).unwrap();
+		drop(file);
+
+		let fresh = Input::read_fresh(&path);
+		let cached = Input::read(&path);
+		let uncached = Input::read_uncached(&path);
+		std::fs::remove_dir_all(&base).unwrap();
+
+		assert_eq!(fresh.raw(), "second");
+		assert_eq!(cached.raw(), "second");
+		assert_eq!(uncached.raw(), "second");
+		assert!(!std::ptr::eq(cached.raw(), uncached.raw()));
+	}
+
+	#[test]
+	fn read_uncached_bypasses_cache() {
+		let cached = Input::read("ucd-parser/testdata/input/basic-123.in");
+		let uncached = Input::read_uncached("ucd-parser/testdata/input/basic-123.in");
+		assert_eq!(cached.raw(), uncached.raw());
+		assert!(!std::ptr::eq(cached.raw(), uncached.raw()));
+	}
+
+	#[test]
+	#[should_panic(expected = "testdata/input/missing.in")]
+	fn read_panics_with_path() {
+		Input::read("ucd-parser/testdata/input/missing.in");
+	}
 }
@@ -0,0 +1,204 @@
+use once_cell::sync::Lazy;
+
+use crate::{
+	input::{Input, InputFile},
+	parse::{parse_code, parse_fields},
+};
+
+/// Entry from `Jamo.txt` with the `Jamo_Short_Name` for a conjoining jamo,
+/// used to generate the names for Hangul syllables.
+///
+/// ```
+/// # use ucd_parser::short_name_for;
+/// assert_eq!(short_name_for(0x1100), Some("G"));
+/// assert_eq!(short_name_for(0x1161), Some("A"));
+/// assert_eq!(short_name_for(0x0041), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Jamo<'a> {
+	/// Codepoint for the jamo.
+	pub code: u32,
+
+	/// Short name for the jamo. This is empty for `U+110B` (IEUNG), which
+	/// is silent as a leading consonant.
+	pub short_name: &'a str,
+}
+
+impl<'a> Jamo<'a> {
+	/// List of jamo from the UCD data in file order. Lazy-loaded from
+	/// `Jamo.txt`.
+	pub fn list() -> &'static [Jamo<'static>] {
+		static LIST: Lazy<Box<[Jamo]>> = Lazy::new(|| {
+			let input = Input::get(InputFile::Jamo);
+			let lines = input.lines();
+			let list = lines.map(|x| Jamo::parse(x).unwrap());
+			let list = list.collect::<Vec<_>>();
+			list.into_boxed_slice()
+		});
+		&LIST
+	}
+
+	pub fn parse(input: &'a str) -> Result<Self, String> {
+		let error = |err: String| format!("jamo: {} -- in `{}`", err, input);
+		let [code, short_name] = parse_fields::<2>(input).map_err(error)?;
+		let code = parse_code(code).map_err(error)?;
+		Ok(Jamo { code, short_name })
+	}
+}
+
+impl<'a> std::fmt::Display for Jamo<'a> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{:04X}; {}", self.code, self.short_name)
+	}
+}
+
+/// Returns the `Jamo_Short_Name` for a codepoint, or [`None`] if it is not
+/// a conjoining jamo listed in `Jamo.txt`.
+pub fn short_name_for(code: u32) -> Option<&'static str> {
+	let list = Jamo::list().iter();
+	list.filter(|x| x.code == code).map(|x| x.short_name).next()
+}
+
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// Decompose a precomposed Hangul syllable in the `AC00..D7A3` block into
+/// its leading consonant, vowel and optional trailing consonant jamo.
+///
+/// Hangul syllables are not decomposed in `UnicodeData.txt`, since the
+/// mapping is defined algorithmically (see Section 3.12 of the Unicode
+/// Standard). Returns [`None`] if the codepoint is not a Hangul syllable.
+///
+/// ```
+/// # use ucd_parser::hangul_decompose;
+/// assert_eq!(hangul_decompose(0xAC00), Some(vec![0x1100, 0x1161]));
+/// ```
+pub fn hangul_decompose(code: u32) -> Option<Vec<u32>> {
+	let index = code.checked_sub(S_BASE).filter(|&x| x < S_COUNT)?;
+	let l = L_BASE + index / N_COUNT;
+	let v = V_BASE + (index % N_COUNT) / T_COUNT;
+	let t = T_BASE + index % T_COUNT;
+	if t == T_BASE {
+		Some(vec![l, v])
+	} else {
+		Some(vec![l, v, t])
+	}
+}
+
+/// Compose a Hangul syllable from its leading consonant, vowel and optional
+/// trailing consonant jamo. This is the inverse of [`hangul_decompose`].
+///
+/// Returns [`None`] if any of the jamo is not in the range of conjoining
+/// jamo for its position.
+///
+/// ```
+/// # use ucd_parser::hangul_compose;
+/// assert_eq!(hangul_compose(0x1100, 0x1161, None), Some(0xAC00));
+/// ```
+pub fn hangul_compose(l: u32, v: u32, t: Option<u32>) -> Option<u32> {
+	let l_index = l.checked_sub(L_BASE).filter(|&x| x < L_COUNT)?;
+	let v_index = v.checked_sub(V_BASE).filter(|&x| x < V_COUNT)?;
+	let t_index = match t {
+		Some(t) => t.checked_sub(T_BASE).filter(|&x| x > 0 && x < T_COUNT)?,
+		None => 0,
+	};
+	Some(S_BASE + (l_index * V_COUNT + v_index) * T_COUNT + t_index)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_from_string() {
+		let jamo = Jamo::parse("1101; GG").unwrap();
+		assert_eq!(jamo.code, 0x1101);
+		assert_eq!(jamo.short_name, "GG");
+		assert_eq!(jamo.to_string(), "1101; GG");
+
+		let jamo = Jamo::parse("110B;").unwrap();
+		assert_eq!(jamo.code, 0x110B);
+		assert_eq!(jamo.short_name, "");
+
+		let err = Jamo::parse("1101").unwrap_err();
+		assert!(err.contains("invalid row format"));
+		assert!(err.contains("-- in `1101`"));
+
+		assert!(Jamo::parse("xx; G").is_err());
+	}
+
+	#[test]
+	fn can_load_from_ucd() {
+		let list = Jamo::list();
+		assert_eq!(list.len(), 67);
+		assert_eq!(list[0], Jamo::parse("1100; G").unwrap());
+		assert_eq!(list[66], Jamo::parse("11C2; H").unwrap());
+	}
+
+	#[test]
+	fn returns_short_name_for_codepoint() {
+		// leading consonants
+		assert_eq!(short_name_for(0x1100), Some("G"));
+		assert_eq!(short_name_for(0x1101), Some("GG"));
+		assert_eq!(short_name_for(0x110B), Some(""));
+		assert_eq!(short_name_for(0x1112), Some("H"));
+
+		// vowels
+		assert_eq!(short_name_for(0x1161), Some("A"));
+		assert_eq!(short_name_for(0x116A), Some("WA"));
+		assert_eq!(short_name_for(0x1175), Some("I"));
+
+		// trailing consonants
+		assert_eq!(short_name_for(0x11A8), Some("G"));
+		assert_eq!(short_name_for(0x11AA), Some("GS"));
+		assert_eq!(short_name_for(0x11C2), Some("H"));
+
+		assert_eq!(short_name_for(0x11A7), None);
+		assert_eq!(short_name_for(0xAC00), None);
+	}
+
+	#[test]
+	fn decomposes_hangul_syllables() {
+		// 가 = ᄀ + ᅡ
+		assert_eq!(hangul_decompose(0xAC00), Some(vec![0x1100, 0x1161]));
+
+		// 한 = ᄒ + ᅡ + ᆫ
+		assert_eq!(hangul_decompose(0xD55C), Some(vec![0x1112, 0x1161, 0x11AB]));
+
+		// last syllable
+		assert_eq!(hangul_decompose(0xD7A3), Some(vec![0x1112, 0x1175, 0x11C2]));
+
+		assert_eq!(hangul_decompose(0xABFF), None);
+		assert_eq!(hangul_decompose(0xD7A4), None);
+		assert_eq!(hangul_decompose(0x41), None);
+	}
+
+	#[test]
+	fn composes_hangul_syllables() {
+		assert_eq!(hangul_compose(0x1100, 0x1161, None), Some(0xAC00));
+		assert_eq!(hangul_compose(0x1112, 0x1161, Some(0x11AB)), Some(0xD55C));
+		assert_eq!(hangul_compose(0x1112, 0x1175, Some(0x11C2)), Some(0xD7A3));
+
+		assert_eq!(hangul_compose(0x1113, 0x1161, None), None);
+		assert_eq!(hangul_compose(0x1100, 0x1176, None), None);
+		assert_eq!(hangul_compose(0x1100, 0x1161, Some(0x11A7)), None);
+		assert_eq!(hangul_compose(0x1100, 0x1161, Some(0x11C3)), None);
+		assert_eq!(hangul_compose(0x1161, 0x1100, None), None);
+	}
+
+	#[test]
+	fn hangul_composition_round_trips() {
+		for code in 0xAC00..=0xD7A3 {
+			let jamo = hangul_decompose(code).unwrap();
+			let composed = hangul_compose(jamo[0], jamo[1], jamo.get(2).copied());
+			assert_eq!(composed, Some(code));
+		}
+	}
+}
@@ -23,5 +23,68 @@ pub use unicode_data::*;
 mod data;
 pub use data::*;
 
+mod derived_age;
+pub use derived_age::*;
+
 mod version;
 pub use version::*;
+
+mod word_break;
+pub use word_break::*;
+
+mod sentence_break;
+pub use sentence_break::*;
+
+mod case_folding;
+pub use case_folding::*;
+
+mod property_aliases;
+pub use property_aliases::*;
+
+mod scripts;
+pub use scripts::*;
+
+mod consistency;
+pub use consistency::*;
+
+mod derived_core;
+pub use derived_core::*;
+
+mod name_aliases;
+pub use name_aliases::*;
+
+mod jamo;
+pub use jamo::*;
+
+mod grapheme_break;
+pub use grapheme_break::*;
+
+mod segmentation;
+pub use segmentation::*;
+
+mod emoji;
+pub use emoji::*;
+
+mod line_break;
+pub use line_break::*;
+
+mod east_asian_width;
+pub use east_asian_width::*;
+
+mod unihan;
+pub use unihan::*;
+
+mod special_casing;
+pub use special_casing::*;
+
+mod character_db;
+pub use character_db::*;
+
+mod bidi_mirroring;
+pub use bidi_mirroring::*;
+
+mod derived_numeric;
+pub use derived_numeric::*;
+
+mod named_sequences;
+pub use named_sequences::*;
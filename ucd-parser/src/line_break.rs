@@ -0,0 +1,450 @@
+use once_cell::sync::Lazy;
+use ucd_property_ranges::{PropertyKey, RangeTable};
+
+use crate::{
+	input::{Input, InputFile},
+	parse::{parse_range_line, OrUnknown},
+};
+
+/// Ranges with a default other than [`LineBreak::XX`] for codepoints not
+/// listed in `LineBreak.txt`.
+const DEFAULT_RANGES: [(u32, u32, LineBreak); 8] = [
+	(0x3400, 0x4DBF, LineBreak::ID),
+	(0x4E00, 0x9FFF, LineBreak::ID),
+	(0xF900, 0xFAFF, LineBreak::ID),
+	(0x20000, 0x2FFFD, LineBreak::ID),
+	(0x30000, 0x3FFFD, LineBreak::ID),
+	(0x1F000, 0x1FAFF, LineBreak::ID),
+	(0x1FC00, 0x1FFFD, LineBreak::ID),
+	(0x20A0, 0x20CF, LineBreak::PR),
+];
+
+/// Values for the `Line_Break` property used by the Unicode Line Breaking
+/// Algorithm.
+///
+/// See https://www.unicode.org/reports/tr14/#Properties
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LineBreak {
+	/// `Ambiguous`
+	AI,
+	/// `Alphabetic`
+	AL,
+	/// `Break_Both`
+	B2,
+	/// `Break_After`
+	BA,
+	/// `Break_Before`
+	BB,
+	/// `Mandatory_Break`
+	BK,
+	/// `Contingent_Break`
+	CB,
+	/// `Conditional_Japanese_Starter`
+	CJ,
+	/// `Close_Punctuation`
+	CL,
+	/// `Combining_Mark`
+	CM,
+	/// `Close_Parenthesis`
+	CP,
+	/// `Carriage_Return`
+	CR,
+	/// `E_Base`
+	EB,
+	/// `E_Modifier`
+	EM,
+	/// `Exclamation`
+	EX,
+	/// `Glue`
+	GL,
+	/// `H2`
+	H2,
+	/// `H3`
+	H3,
+	/// `Hebrew_Letter`
+	HL,
+	/// `Hyphen`
+	HY,
+	/// `Ideographic`
+	ID,
+	/// `Inseparable`
+	IN,
+	/// `Infix_Numeric`
+	IS,
+	/// `JL`
+	JL,
+	/// `JT`
+	JT,
+	/// `JV`
+	JV,
+	/// `Line_Feed`
+	LF,
+	/// `Next_Line`
+	NL,
+	/// `Nonstarter`
+	NS,
+	/// `Numeric`
+	NU,
+	/// `Open_Punctuation`
+	OP,
+	/// `Postfix_Numeric`
+	PO,
+	/// `Prefix_Numeric`
+	PR,
+	/// `Quotation`
+	QU,
+	/// `Regional_Indicator`
+	RI,
+	/// `Complex_Context`
+	SA,
+	/// `Surrogate`
+	SG,
+	/// `Space`
+	SP,
+	/// `Break_Symbols`
+	SY,
+	/// `Word_Joiner`
+	WJ,
+	/// `Unknown`
+	XX,
+	/// `ZWSpace`
+	ZW,
+	/// `ZWJ`
+	ZWJ,
+}
+
+impl LineBreak {
+	pub fn parse<T: AsRef<str>>(input: T) -> Option<Self> {
+		let value = match input.as_ref() {
+			"AI" => LineBreak::AI,
+			"AL" => LineBreak::AL,
+			"B2" => LineBreak::B2,
+			"BA" => LineBreak::BA,
+			"BB" => LineBreak::BB,
+			"BK" => LineBreak::BK,
+			"CB" => LineBreak::CB,
+			"CJ" => LineBreak::CJ,
+			"CL" => LineBreak::CL,
+			"CM" => LineBreak::CM,
+			"CP" => LineBreak::CP,
+			"CR" => LineBreak::CR,
+			"EB" => LineBreak::EB,
+			"EM" => LineBreak::EM,
+			"EX" => LineBreak::EX,
+			"GL" => LineBreak::GL,
+			"H2" => LineBreak::H2,
+			"H3" => LineBreak::H3,
+			"HL" => LineBreak::HL,
+			"HY" => LineBreak::HY,
+			"ID" => LineBreak::ID,
+			"IN" => LineBreak::IN,
+			"IS" => LineBreak::IS,
+			"JL" => LineBreak::JL,
+			"JT" => LineBreak::JT,
+			"JV" => LineBreak::JV,
+			"LF" => LineBreak::LF,
+			"NL" => LineBreak::NL,
+			"NS" => LineBreak::NS,
+			"NU" => LineBreak::NU,
+			"OP" => LineBreak::OP,
+			"PO" => LineBreak::PO,
+			"PR" => LineBreak::PR,
+			"QU" => LineBreak::QU,
+			"RI" => LineBreak::RI,
+			"SA" => LineBreak::SA,
+			"SG" => LineBreak::SG,
+			"SP" => LineBreak::SP,
+			"SY" => LineBreak::SY,
+			"WJ" => LineBreak::WJ,
+			"XX" => LineBreak::XX,
+			"ZW" => LineBreak::ZW,
+			"ZWJ" => LineBreak::ZWJ,
+			_ => return None,
+		};
+		Some(value)
+	}
+
+	/// Same as [`LineBreak::parse`], but returns [`OrUnknown::Unknown`] with the
+	/// input text for unrecognized values instead of [`None`].
+	pub fn parse_or_unknown<T: AsRef<str>>(input: T) -> OrUnknown<Self> {
+		OrUnknown::parse_with(input.as_ref(), |x| Self::parse(x))
+	}
+
+	/// Resolve the `CJ` (Conditional Japanese Starter) class according to
+	/// the default tailoring in UAX #14.
+	///
+	/// `CJ` resolves to `NS` for strict line breaking and to `ID` otherwise.
+	/// All other classes resolve to themselves.
+	///
+	/// ```
+	/// # use ucd_parser::LineBreak;
+	/// assert_eq!(LineBreak::CJ.resolve(true), LineBreak::NS);
+	/// assert_eq!(LineBreak::CJ.resolve(false), LineBreak::ID);
+	/// ```
+	pub fn resolve(&self, strict: bool) -> LineBreak {
+		match self {
+			LineBreak::CJ if strict => LineBreak::NS,
+			LineBreak::CJ => LineBreak::ID,
+			other => *other,
+		}
+	}
+
+	/// Returns the default class for codepoints not listed in the file.
+	///
+	/// This is [`LineBreak::ID`] for the CJK ideograph blocks, the planes 2
+	/// and 3, and some pictographic ranges in plane 1, [`LineBreak::PR`] for
+	/// the currency symbols block, and [`LineBreak::XX`] otherwise, as
+	/// documented in `LineBreak.txt`.
+	pub fn default_for(code: u32) -> Self {
+		DEFAULT_RANGES
+			.iter()
+			.find(|&&(sta, end, _)| sta <= code && code <= end)
+			.map(|&(_, _, value)| value)
+			.unwrap_or(LineBreak::XX)
+	}
+
+	/// Build a [`RangeTable`] with the [`LineBreakProperty`] for the entire
+	/// codespace from `LineBreak.txt`.
+	///
+	/// Codepoints not listed in the file are set to their default value as
+	/// returned by [`LineBreak::default_for`].
+	pub fn load() -> RangeTable {
+		let mut table = RangeTable::new();
+		table.set_range(0..=0x10FFFF, LineBreakProperty, LineBreak::XX);
+		for &(sta, end, value) in DEFAULT_RANGES.iter() {
+			table.set_range(sta..=end, LineBreakProperty, value);
+		}
+
+		for &(sta, end, value) in line_break_ranges() {
+			table.set_range(sta..=end, LineBreakProperty, value);
+		}
+		table
+	}
+
+	/// Parse a line from `LineBreak.txt`.
+	pub fn parse_line(input: &str) -> Result<(u32, u32, Self), String> {
+		let (sta, end, value) =
+			parse_range_line(input).map_err(|err| format!("line break: {}", err))?;
+		let value = LineBreak::parse(value).ok_or_else(|| {
+			format!(
+				"line break: `{}` is not a valid class -- in `{}`",
+				value, input
+			)
+		})?;
+		Ok((sta, end, value))
+	}
+}
+
+impl std::fmt::Display for LineBreak {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let output = match self {
+			LineBreak::AI => "AI",
+			LineBreak::AL => "AL",
+			LineBreak::B2 => "B2",
+			LineBreak::BA => "BA",
+			LineBreak::BB => "BB",
+			LineBreak::BK => "BK",
+			LineBreak::CB => "CB",
+			LineBreak::CJ => "CJ",
+			LineBreak::CL => "CL",
+			LineBreak::CM => "CM",
+			LineBreak::CP => "CP",
+			LineBreak::CR => "CR",
+			LineBreak::EB => "EB",
+			LineBreak::EM => "EM",
+			LineBreak::EX => "EX",
+			LineBreak::GL => "GL",
+			LineBreak::H2 => "H2",
+			LineBreak::H3 => "H3",
+			LineBreak::HL => "HL",
+			LineBreak::HY => "HY",
+			LineBreak::ID => "ID",
+			LineBreak::IN => "IN",
+			LineBreak::IS => "IS",
+			LineBreak::JL => "JL",
+			LineBreak::JT => "JT",
+			LineBreak::JV => "JV",
+			LineBreak::LF => "LF",
+			LineBreak::NL => "NL",
+			LineBreak::NS => "NS",
+			LineBreak::NU => "NU",
+			LineBreak::OP => "OP",
+			LineBreak::PO => "PO",
+			LineBreak::PR => "PR",
+			LineBreak::QU => "QU",
+			LineBreak::RI => "RI",
+			LineBreak::SA => "SA",
+			LineBreak::SG => "SG",
+			LineBreak::SP => "SP",
+			LineBreak::SY => "SY",
+			LineBreak::WJ => "WJ",
+			LineBreak::XX => "XX",
+			LineBreak::ZW => "ZW",
+			LineBreak::ZWJ => "ZWJ",
+		};
+		write!(f, "{}", output)
+	}
+}
+
+/// [`PropertyKey`] for [`LineBreak`] values in a [`RangeTable`].
+#[derive(Clone, PartialEq)]
+pub struct LineBreakProperty;
+
+impl PropertyKey for LineBreakProperty {
+	type Value = LineBreak;
+}
+
+/// Returns the [`LineBreak`] class for a codepoint.
+///
+/// Codepoints not listed in the file use [`LineBreak::default_for`].
+///
+/// ```
+/// # use ucd_parser::{line_break_for, LineBreak};
+/// assert_eq!(line_break_for(0x4E00), LineBreak::ID);
+/// assert_eq!(line_break_for(0x0020), LineBreak::SP);
+/// ```
+pub fn line_break_for(code: u32) -> LineBreak {
+	let ranges = line_break_ranges();
+	let index = ranges.partition_point(|x| x.1 < code);
+	match ranges.get(index) {
+		Some(&(sta, _, value)) if sta <= code => value,
+		_ => LineBreak::default_for(code),
+	}
+}
+
+/// Ranges from `LineBreak.txt` sorted by codepoint.
+fn line_break_ranges() -> &'static [(u32, u32, LineBreak)] {
+	static RANGES: Lazy<Vec<(u32, u32, LineBreak)>> = Lazy::new(|| {
+		let input = Input::get(InputFile::LineBreak);
+		let lines = input.lines();
+		let mut ranges = lines
+			.map(|x| LineBreak::parse_line(x).unwrap())
+			.collect::<Vec<_>>();
+		ranges.sort_by_key(|x| x.0);
+		ranges
+	});
+	&RANGES
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn supports_to_string() {
+		fn check(input: LineBreak, expected: &'static str) {
+			assert_eq!(input.to_string(), expected);
+			assert_eq!(LineBreak::parse(input.to_string()).expect(expected), input);
+		}
+
+		check(LineBreak::AI, "AI");
+		check(LineBreak::AL, "AL");
+		check(LineBreak::B2, "B2");
+		check(LineBreak::BA, "BA");
+		check(LineBreak::BB, "BB");
+		check(LineBreak::BK, "BK");
+		check(LineBreak::CB, "CB");
+		check(LineBreak::CJ, "CJ");
+		check(LineBreak::CL, "CL");
+		check(LineBreak::CM, "CM");
+		check(LineBreak::CP, "CP");
+		check(LineBreak::CR, "CR");
+		check(LineBreak::EB, "EB");
+		check(LineBreak::EM, "EM");
+		check(LineBreak::EX, "EX");
+		check(LineBreak::GL, "GL");
+		check(LineBreak::H2, "H2");
+		check(LineBreak::H3, "H3");
+		check(LineBreak::HL, "HL");
+		check(LineBreak::HY, "HY");
+		check(LineBreak::ID, "ID");
+		check(LineBreak::IN, "IN");
+		check(LineBreak::IS, "IS");
+		check(LineBreak::JL, "JL");
+		check(LineBreak::JT, "JT");
+		check(LineBreak::JV, "JV");
+		check(LineBreak::LF, "LF");
+		check(LineBreak::NL, "NL");
+		check(LineBreak::NS, "NS");
+		check(LineBreak::NU, "NU");
+		check(LineBreak::OP, "OP");
+		check(LineBreak::PO, "PO");
+		check(LineBreak::PR, "PR");
+		check(LineBreak::QU, "QU");
+		check(LineBreak::RI, "RI");
+		check(LineBreak::SA, "SA");
+		check(LineBreak::SG, "SG");
+		check(LineBreak::SP, "SP");
+		check(LineBreak::SY, "SY");
+		check(LineBreak::WJ, "WJ");
+		check(LineBreak::XX, "XX");
+		check(LineBreak::ZW, "ZW");
+		check(LineBreak::ZWJ, "ZWJ");
+	}
+
+	#[test]
+	fn parse_from_invalid_string_is_none() {
+		assert_eq!(LineBreak::parse("xx"), None);
+		assert_eq!(LineBreak::parse("Alphabetic"), None);
+	}
+
+	#[test]
+	fn parse_or_unknown_preserves_unrecognized_value() {
+		assert_eq!(
+			LineBreak::parse_or_unknown("ID"),
+			OrUnknown::Known(LineBreak::ID)
+		);
+		assert_eq!(LineBreak::parse_or_unknown("xx").to_string(), "xx");
+	}
+
+	#[test]
+	fn resolves_conditional_japanese_starter() {
+		assert_eq!(LineBreak::CJ.resolve(true), LineBreak::NS);
+		assert_eq!(LineBreak::CJ.resolve(false), LineBreak::ID);
+
+		assert_eq!(LineBreak::AL.resolve(true), LineBreak::AL);
+		assert_eq!(LineBreak::AL.resolve(false), LineBreak::AL);
+		assert_eq!(LineBreak::NS.resolve(false), LineBreak::NS);
+	}
+
+	#[test]
+	fn parses_line() {
+		let line = LineBreak::parse_line("0009;BA").unwrap();
+		assert_eq!(line, (0x09, 0x09, LineBreak::BA));
+
+		let line = LineBreak::parse_line("0000..0008;CM").unwrap();
+		assert_eq!(line, (0x00, 0x08, LineBreak::CM));
+
+		let err = LineBreak::parse_line("0021;ZZ").unwrap_err();
+		assert!(err.contains("`ZZ` is not a valid class"));
+	}
+
+	#[test]
+	fn returns_line_break_for_codepoint() {
+		assert_eq!(line_break_for(0x4E00), LineBreak::ID);
+		assert_eq!(line_break_for(0x0020), LineBreak::SP);
+		assert_eq!(line_break_for(0x0009), LineBreak::BA);
+		assert_eq!(line_break_for(0x0041), LineBreak::AL);
+		assert_eq!(line_break_for(0x000A), LineBreak::LF);
+		assert_eq!(line_break_for(0x00A0), LineBreak::GL);
+
+		// unlisted codepoints use the defaults
+		assert_eq!(line_break_for(0x0378), LineBreak::XX);
+		assert_eq!(line_break_for(0x2FFFD), LineBreak::ID);
+		assert_eq!(line_break_for(0x1FFFD), LineBreak::ID);
+		assert_eq!(line_break_for(0x10FFFF), LineBreak::XX);
+	}
+
+	#[test]
+	fn loads_table_from_ucd() {
+		let table = LineBreak::load();
+		for code in [
+			0x0, 0x20, 0x41, 0x378, 0x20CF, 0x4E00, 0x1FFFD, 0x2FFFD, 0x10FFFF,
+		] {
+			assert_eq!(
+				table.lookup(code, LineBreakProperty),
+				Some(line_break_for(code))
+			);
+		}
+	}
+}
@@ -0,0 +1,182 @@
+use once_cell::sync::Lazy;
+
+use crate::{
+	input::{Input, InputFile},
+	parse::{parse_code, parse_fields},
+};
+
+/// Alternate name for a character from `NameAliases.txt`.
+///
+/// ```
+/// # use ucd_parser::{aliases_for, AliasKind};
+/// let aliases = aliases_for(0x0000);
+/// assert_eq!(aliases[0].alias, "NULL");
+/// assert_eq!(aliases[0].kind, AliasKind::Control);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NameAlias<'a> {
+	/// Codepoint for the alias.
+	pub code: u32,
+
+	/// Alternate name for the codepoint.
+	pub alias: &'a str,
+
+	/// Type of the alias.
+	pub kind: AliasKind,
+}
+
+impl<'a> NameAlias<'a> {
+	/// List of aliases from the UCD data in file order. Lazy-loaded from
+	/// `NameAliases.txt`.
+	pub fn list() -> &'static [NameAlias<'static>] {
+		static LIST: Lazy<Box<[NameAlias]>> = Lazy::new(|| {
+			let input = Input::get(InputFile::NameAliases);
+			let lines = input.lines();
+			let list = lines.map(|x| NameAlias::parse(x).unwrap());
+			let list = list.collect::<Vec<_>>();
+			list.into_boxed_slice()
+		});
+		&LIST
+	}
+
+	pub fn parse(input: &'a str) -> Result<Self, String> {
+		let error = |err: String| format!("name alias: {} -- in `{}`", err, input);
+		let [code, alias, kind] = parse_fields::<3>(input).map_err(error)?;
+		let code = parse_code(code).map_err(error)?;
+		if alias.is_empty() {
+			return Err(error("empty alias".to_string()));
+		}
+		let kind = AliasKind::parse(kind)
+			.ok_or_else(|| error(format!("`{}` is not a valid kind", kind)))?;
+		Ok(NameAlias { code, alias, kind })
+	}
+}
+
+impl<'a> std::fmt::Display for NameAlias<'a> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{:04X};{};{}", self.code, self.alias, self.kind)
+	}
+}
+
+/// Returns all aliases for a codepoint, in file order.
+pub fn aliases_for(code: u32) -> Vec<NameAlias<'static>> {
+	let list = NameAlias::list().iter();
+	list.filter(|x| x.code == code).cloned().collect()
+}
+
+/// Type of a [`NameAlias`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AliasKind {
+	/// Corrections for serious problems in the character names.
+	Correction,
+	/// ISO 6429 names for C0 and C1 control functions, and other commonly
+	/// occurring names for control codes.
+	Control,
+	/// A few widely used alternate names for format characters.
+	Alternate,
+	/// Several documented labels for C1 control code points which were
+	/// never actually approved in any standard.
+	Figment,
+	/// Commonly occurring abbreviations (or acronyms) for control codes,
+	/// format characters, spaces, and variation selectors.
+	Abbreviation,
+}
+
+impl AliasKind {
+	pub fn parse<T: AsRef<str>>(input: T) -> Option<Self> {
+		let kind = match input.as_ref() {
+			"correction" => AliasKind::Correction,
+			"control" => AliasKind::Control,
+			"alternate" => AliasKind::Alternate,
+			"figment" => AliasKind::Figment,
+			"abbreviation" => AliasKind::Abbreviation,
+			_ => return None,
+		};
+		Some(kind)
+	}
+}
+
+impl std::fmt::Display for AliasKind {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let output = match self {
+			AliasKind::Correction => "correction",
+			AliasKind::Control => "control",
+			AliasKind::Alternate => "alternate",
+			AliasKind::Figment => "figment",
+			AliasKind::Abbreviation => "abbreviation",
+		};
+		write!(f, "{}", output)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn kind_supports_to_string() {
+		fn check(input: AliasKind, expected: &'static str) {
+			assert_eq!(input.to_string(), expected);
+			assert_eq!(AliasKind::parse(input.to_string()).expect(expected), input);
+		}
+
+		check(AliasKind::Correction, "correction");
+		check(AliasKind::Control, "control");
+		check(AliasKind::Alternate, "alternate");
+		check(AliasKind::Figment, "figment");
+		check(AliasKind::Abbreviation, "abbreviation");
+	}
+
+	#[test]
+	fn kind_parse_from_invalid_string_is_none() {
+		assert_eq!(AliasKind::parse("xx"), None);
+		assert_eq!(AliasKind::parse("Control"), None);
+	}
+
+	#[test]
+	fn parses_from_string() {
+		let alias = NameAlias::parse("0000;NULL;control").unwrap();
+		assert_eq!(alias.code, 0);
+		assert_eq!(alias.alias, "NULL");
+		assert_eq!(alias.kind, AliasKind::Control);
+
+		let err = NameAlias::parse("0000;NULL;xx").unwrap_err();
+		assert!(err.contains("`xx` is not a valid kind"));
+		assert!(err.contains("-- in `0000;NULL;xx`"));
+
+		let err = NameAlias::parse("0000;NULL").unwrap_err();
+		assert!(err.contains("invalid row format"));
+
+		let err = NameAlias::parse("0000;;control").unwrap_err();
+		assert!(err.contains("empty alias"));
+	}
+
+	#[test]
+	fn can_load_from_ucd() {
+		let source = include_ucd!("NameAliases.txt");
+		let source = source.lines().collect::<Vec<_>>();
+		assert!(!source.is_empty());
+
+		let list = NameAlias::list();
+		let list = list.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+		assert_eq!(list, source);
+	}
+
+	#[test]
+	fn returns_aliases_for_codepoint() {
+		let aliases = aliases_for(0x0000);
+		let aliases = aliases
+			.iter()
+			.map(|x| (x.alias, x.kind))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			aliases,
+			vec![
+				("NULL", AliasKind::Control),
+				("NUL", AliasKind::Abbreviation)
+			]
+		);
+
+		assert!(aliases_for(0x0041).is_empty());
+	}
+}
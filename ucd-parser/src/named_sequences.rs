@@ -0,0 +1,130 @@
+use once_cell::sync::Lazy;
+
+use crate::{
+	input::{Input, InputFile},
+	parse::parse_code_sequence,
+};
+
+/// Named character sequence from `NamedSequences.txt`.
+///
+/// Named sequences share the namespace with character names, but identify a
+/// sequence of codepoints instead of a single character.
+///
+/// ```
+/// # use ucd_parser::NamedSequence;
+/// let sequence = NamedSequence::find_by_name("KEYCAP DIGIT ZERO").unwrap();
+/// assert_eq!(sequence.codes, vec![0x0030, 0xFE0F, 0x20E3]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedSequence<'a> {
+	/// Name for the sequence.
+	pub name: &'a str,
+
+	/// Codepoints in the sequence.
+	pub codes: Vec<u32>,
+}
+
+impl<'a> NamedSequence<'a> {
+	/// List of named sequences from the UCD data in file order. Lazy-loaded
+	/// from `NamedSequences.txt`.
+	pub fn list() -> &'static [NamedSequence<'static>] {
+		static LIST: Lazy<Box<[NamedSequence]>> = Lazy::new(|| {
+			let input = Input::get(InputFile::NamedSequences);
+			let lines = input.lines();
+			let list = lines.map(|x| NamedSequence::parse(x).unwrap());
+			let list = list.collect::<Vec<_>>();
+			list.into_boxed_slice()
+		});
+		&LIST
+	}
+
+	/// Find a named sequence by its exact name.
+	pub fn find_by_name(name: &str) -> Option<&'static NamedSequence<'static>> {
+		Self::list().iter().find(|x| x.name == name)
+	}
+
+	pub fn parse(input: &'a str) -> Result<Self, String> {
+		let error = |err: String| format!("named sequence: {} -- in `{}`", err, input);
+		let (name, codes) = input
+			.split_once(';')
+			.ok_or_else(|| error("missing `;`".to_string()))?;
+		let name = name.trim();
+		if name.is_empty() {
+			return Err(error("empty name".to_string()));
+		}
+		let codes = parse_code_sequence(codes).map_err(error)?;
+		if codes.len() < 2 {
+			return Err(error("expected at least two codes".to_string()));
+		}
+		Ok(NamedSequence { name, codes })
+	}
+}
+
+impl<'a> std::fmt::Display for NamedSequence<'a> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let codes = self.codes.iter().map(|x| format!("{:04X}", x));
+		let codes = codes.collect::<Vec<_>>();
+		write!(f, "{};{}", self.name, codes.join(" "))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_from_string() {
+		let sequence = NamedSequence::parse("KEYCAP DIGIT ZERO;0030 FE0F 20E3").unwrap();
+		assert_eq!(sequence.name, "KEYCAP DIGIT ZERO");
+		assert_eq!(sequence.codes, vec![0x0030, 0xFE0F, 0x20E3]);
+		assert_eq!(sequence.to_string(), "KEYCAP DIGIT ZERO;0030 FE0F 20E3");
+
+		let sequence =
+			NamedSequence::parse("LATIN CAPITAL LETTER A WITH MACRON AND GRAVE;0100 0300").unwrap();
+		assert_eq!(
+			sequence.name,
+			"LATIN CAPITAL LETTER A WITH MACRON AND GRAVE"
+		);
+		assert_eq!(sequence.codes, vec![0x0100, 0x0300]);
+	}
+
+	#[test]
+	fn parsing_invalid_sequence_returns_error() {
+		let err = NamedSequence::parse("KEYCAP DIGIT ZERO").unwrap_err();
+		assert!(err.contains("missing `;`"));
+		assert!(err.contains("-- in `KEYCAP DIGIT ZERO`"));
+
+		let err = NamedSequence::parse("KEYCAP DIGIT ZERO;0030 xx").unwrap_err();
+		assert!(err.contains("`xx` is not a valid code"));
+
+		let err = NamedSequence::parse(";0030 20E3").unwrap_err();
+		assert!(err.contains("empty name"));
+
+		let err = NamedSequence::parse("KEYCAP DIGIT ZERO;0030").unwrap_err();
+		assert!(err.contains("expected at least two codes"));
+	}
+
+	#[test]
+	fn can_load_from_ucd() {
+		let list = NamedSequence::list();
+		assert_eq!(list.len(), 461);
+		assert_eq!(
+			list[0],
+			NamedSequence::parse("KEYCAP NUMBER SIGN;0023 FE0F 20E3").unwrap()
+		);
+		assert!(list.iter().all(|x| x.codes.len() >= 2));
+	}
+
+	#[test]
+	fn can_find_by_name() {
+		let sequence = NamedSequence::find_by_name("KEYCAP ASTERISK").unwrap();
+		assert_eq!(sequence.codes, vec![0x002A, 0xFE0F, 0x20E3]);
+
+		let name = "MODIFIER LETTER EXTRA-LOW EXTRA-HIGH CONTOUR TONE BAR";
+		let sequence = NamedSequence::find_by_name(name).unwrap();
+		assert_eq!(sequence.codes, vec![0x02E9, 0x02E5]);
+
+		assert!(NamedSequence::find_by_name("keycap asterisk").is_none());
+		assert!(NamedSequence::find_by_name("LATIN CAPITAL LETTER A").is_none());
+	}
+}
@@ -1,22 +1,240 @@
+use ucd_property_ranges::{PropertyKey, RangeTable};
+
+use crate::input::Input;
+
+/// Parse a codepoint in hexadecimal, with an optional `U+` prefix (e.g.
+/// `1234` or `U+1234`). The prefix is case-insensitive.
 pub fn parse_code<S: AsRef<str>>(input: S) -> Result<u32, String> {
 	let input = input.as_ref();
-	u32::from_str_radix(input.as_ref(), 16)
+	let code = match input.get(..2) {
+		Some(prefix) if prefix.eq_ignore_ascii_case("U+") => &input[2..],
+		_ => input,
+	};
+	if code.starts_with('+') {
+		return Err(format!("`{}` is not a valid code", input));
+	}
+	u32::from_str_radix(code, 16)
 		.map_err(|err| format!("`{}` is not a valid code ({})", input, err))
 }
 
+/// Same as [`parse_code`], but returns an error if the codepoint is outside
+/// the Unicode codespace (i.e. greater than `10FFFF`).
+pub fn parse_code_checked<S: AsRef<str>>(input: S) -> Result<u32, String> {
+	let input = input.as_ref();
+	let code = parse_code(input)?;
+	if code > 0x10FFFF {
+		return Err(format!(
+			"`{}` is outside the Unicode codepoint range",
+			input
+		));
+	}
+	Ok(code)
+}
+
+/// Parse an inclusive `FIRST..LAST` range of codepoints. Whitespace around
+/// the range endpoints is ignored.
+///
+/// A single `CODE` without the `..` separator is parsed as a range with the
+/// same start and end.
 pub fn parse_range<S: AsRef<str>>(input: S) -> Result<(u32, u32), String> {
 	let separator = "..";
 	let input = input.as_ref();
-	let split_at = input
-		.find(separator)
-		.ok_or_else(|| format!("`{}` is not a valid range", input))?;
-	let sta = &input[0..split_at];
-	let end = &input[split_at + separator.len()..];
+	let split_at = match input.find(separator) {
+		Some(split_at) => split_at,
+		None => {
+			let code = parse_code(input.trim())
+				.map_err(|err| format!("`{}` is not a valid range: {}", input, err))?;
+			return Ok((code, code));
+		}
+	};
+	let sta = input[0..split_at].trim();
+	let end = input[split_at + separator.len()..].trim();
 	let sta = parse_code(sta).map_err(|err| format!("range start {}", err))?;
 	let end = parse_code(end).map_err(|err| format!("range end {}", err))?;
 	Ok((sta, end))
 }
 
+/// Parse a sequence of whitespace-separated codepoints (e.g. `0030 20E3`),
+/// each in the format accepted by [`parse_code`]. An empty input returns an
+/// empty sequence.
+///
+/// ```
+/// # use ucd_parser::parse::parse_code_sequence;
+/// assert_eq!(parse_code_sequence("0030 20E3"), Ok(vec![0x30, 0x20E3]));
+/// ```
+pub fn parse_code_sequence<S: AsRef<str>>(input: S) -> Result<Vec<u32>, String> {
+	let input = input.as_ref();
+	let codes = input
+		.split_whitespace()
+		.map(|token| parse_code(token).map_err(|err| format!("{} in sequence `{}`", err, input)));
+	codes.collect()
+}
+
+/// Split a row with `N` fields separated by `;`, returning the trimmed fields.
+///
+/// Returns an error if the row doesn't have exactly `N` fields.
+pub fn parse_fields<const N: usize>(input: &str) -> Result<[&str; N], String> {
+	let mut fields = [""; N];
+	let mut count = 0;
+	for field in input.split(';') {
+		if count < N {
+			fields[count] = field.trim();
+		}
+		count += 1;
+	}
+	if count != N {
+		return Err(format!(
+			"invalid row format (expected {} fields, found {})",
+			N, count
+		));
+	}
+	Ok(fields)
+}
+
+/// Parse a `CODE ; VALUE` or `FIRST..LAST ; VALUE` line in the format used
+/// by most of the UCD property files.
+///
+/// Returns the inclusive range of codepoints and the trimmed value. Single
+/// codepoints are returned as a range with the same start and end.
+pub fn parse_range_line(input: &str) -> Result<(u32, u32, &str), String> {
+	let semicolon = input
+		.find(';')
+		.ok_or_else(|| format!("`{}` is missing `;`", input))?;
+	let (range, value) = (input[..semicolon].trim(), input[semicolon + 1..].trim());
+	let (sta, end) = parse_range(range).map_err(|err| format!("{} -- in `{}`", err, input))?;
+	Ok((sta, end, value))
+}
+
+/// Parse a `# @missing: FIRST..LAST; VALUE` annotation line, which in the
+/// UCD property files declares the default value for codepoints not listed
+/// in the file.
+///
+/// Returns [`None`] if the line is not a valid `@missing` annotation. The
+/// value is everything after the range, trimmed. For files with multiple
+/// properties this includes the property name (e.g. `NFD_QC; Yes`).
+///
+/// ```
+/// # use ucd_parser::parse::parse_missing_annotation;
+/// let line = "# @missing: 0000..10FFFF; Unknown";
+/// assert_eq!(parse_missing_annotation(line), Some((0, 0x10FFFF, "Unknown")));
+/// ```
+pub fn parse_missing_annotation(line: &str) -> Option<(u32, u32, &str)> {
+	let line = line.trim_start();
+	let line = line.strip_prefix('#').unwrap_or(line).trim_start();
+	let line = line.strip_prefix("@missing:")?;
+	parse_range_line(line).ok()
+}
+
+/// Set the defaults from the `@missing` annotations in the input for a
+/// property in a [`RangeTable`], before applying the explicit assignments.
+///
+/// Annotations are applied in file order, so a more specific default that
+/// follows the `0000..10FFFF` one overrides it, as in the UCD files. Returns
+/// an error if an annotation value cannot be parsed.
+pub fn set_missing_defaults<K: PropertyKey>(
+	table: &mut RangeTable,
+	input: &Input,
+	key: K,
+	parse: impl Fn(&'static str) -> Option<K::Value>,
+) -> Result<(), String> {
+	let comments = input.lines_with_comments().filter_map(|(_, x)| x);
+	for (sta, end, value) in comments.filter_map(parse_missing_annotation) {
+		let value =
+			parse(value).ok_or_else(|| format!("`{}` is not a valid @missing value", value))?;
+		table.set_range(sta..=end, key.clone(), value);
+	}
+	Ok(())
+}
+
+/// Parse all data lines in the input, collecting the errors instead of
+/// stopping at the first one.
+///
+/// Comments and blank lines are skipped as in [`Input::lines`]. Returns the
+/// successfully parsed items, and the 1-based line number and message for
+/// each line that failed to parse.
+///
+/// [`Input::lines`]: crate::input::Input::lines
+///
+/// ```
+/// # use ucd_parser::parse::{parse_all, parse_code};
+/// let (codes, errors) = parse_all("0041\n# comment\nxx\n0042", parse_code);
+/// assert_eq!(codes, vec![0x41, 0x42]);
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].0, 3);
+/// ```
+pub fn parse_all<'a, T>(
+	input: &'a str,
+	parse: impl Fn(&'a str) -> Result<T, String>,
+) -> (Vec<T>, Vec<(usize, String)>) {
+	let mut items = Vec::new();
+	let mut errors = Vec::new();
+	for (index, line) in input.lines().enumerate() {
+		let line = match line.split_once('#') {
+			Some((line, _)) => line,
+			None => line,
+		};
+		let line = line.trim_end();
+		if line.trim().is_empty() {
+			continue;
+		}
+		match parse(line) {
+			Ok(item) => items.push(item),
+			Err(err) => errors.push((index + 1, err)),
+		}
+	}
+	(items, errors)
+}
+
+/// Result of parsing a property value that may not be recognized, for
+/// forward compatibility with newer versions of the UCD data.
+///
+/// This is returned by the `parse_or_unknown` method of the enums for
+/// property values that are often extended by new Unicode versions:
+/// [`Script`](crate::Script), [`LineBreak`](crate::LineBreak),
+/// [`GraphemeBreak`](crate::GraphemeBreak), [`WordBreak`](crate::WordBreak),
+/// and [`SentenceBreak`](crate::SentenceBreak).
+///
+/// ```
+/// # use ucd_parser::{parse::OrUnknown, Script};
+/// assert_eq!(Script::parse_or_unknown("Latin"), OrUnknown::Known(Script::Latin));
+/// assert_eq!(Script::parse_or_unknown("Xyz").to_string(), "Xyz");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrUnknown<T> {
+	/// Recognized value.
+	Known(T),
+	/// Unrecognized value with the raw input text.
+	Unknown(String),
+}
+
+impl<T> OrUnknown<T> {
+	/// Parse the input with the given function, falling back to
+	/// [`OrUnknown::Unknown`] if it returns [`None`].
+	pub fn parse_with<F: FnOnce(&str) -> Option<T>>(input: &str, parse: F) -> Self {
+		match parse(input) {
+			Some(value) => OrUnknown::Known(value),
+			None => OrUnknown::Unknown(input.to_string()),
+		}
+	}
+
+	/// Returns the recognized value, or [`None`] if it is unknown.
+	pub fn known(self) -> Option<T> {
+		match self {
+			OrUnknown::Known(value) => Some(value),
+			OrUnknown::Unknown(_) => None,
+		}
+	}
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for OrUnknown<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			OrUnknown::Known(value) => value.fmt(f),
+			OrUnknown::Unknown(input) => write!(f, "{}", input),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -36,6 +254,41 @@ mod tests {
 		assert!(err.contains("`xx` is not a valid code"));
 	}
 
+	#[test]
+	fn can_parse_code_with_prefix() {
+		assert_eq!(parse_code("U+1234"), Ok(0x1234));
+		assert_eq!(parse_code("u+1234"), Ok(0x1234));
+		assert_eq!(parse_code("1234"), Ok(0x1234));
+		assert_eq!(parse_code("u+00ff"), Ok(0xFF));
+		assert_eq!(parse_code("U+10FFFF"), Ok(0x10FFFF));
+
+		assert!(parse_code("U+").is_err());
+		assert!(parse_code("U++12").is_err());
+		assert!(parse_code("+12").is_err());
+		assert!(parse_code("U+123456789").is_err());
+
+		let err = parse_code("U+XYZ").unwrap_err();
+		assert!(err.contains("`U+XYZ` is not a valid code"));
+	}
+
+	#[test]
+	fn parse_code_checked_validates_range() {
+		assert_eq!(parse_code_checked("10FFFF"), Ok(0x10FFFF));
+		assert_eq!(parse_code_checked("U+0041"), Ok(0x41));
+
+		let err = parse_code_checked("110000").unwrap_err();
+		assert_eq!(err, "`110000` is outside the Unicode codepoint range");
+
+		let err = parse_code_checked("FFFFFFFF").unwrap_err();
+		assert_eq!(err, "`FFFFFFFF` is outside the Unicode codepoint range");
+
+		let err = parse_code_checked("xx").unwrap_err();
+		assert!(err.contains("`xx` is not a valid code"));
+
+		assert_eq!(parse_code("110000"), Ok(0x110000));
+		assert_eq!(parse_code("FFFFFFFF"), Ok(0xFFFFFFFF));
+	}
+
 	#[test]
 	fn can_parse_range() {
 		let (a, b) = parse_range("FF..1234").unwrap();
@@ -47,10 +300,28 @@ mod tests {
 		assert_eq!(b, 0xABCD);
 	}
 
+	#[test]
+	fn can_parse_single_code_as_range() {
+		assert_eq!(parse_range("00A0"), Ok((0xA0, 0xA0)));
+		assert_eq!(parse_range(" 00A0 "), Ok((0xA0, 0xA0)));
+		assert_eq!(parse_range("FF..100"), Ok((0xFF, 0x100)));
+	}
+
+	#[test]
+	fn parse_range_ignores_whitespace_around_separator() {
+		let expected = (0x41, 0x5A);
+		assert_eq!(parse_range("0041..005A").unwrap(), expected);
+		assert_eq!(parse_range("0041 .. 005A").unwrap(), expected);
+		assert_eq!(parse_range(" 0041\t..005A ").unwrap(), expected);
+	}
+
 	#[test]
 	fn parsing_invalid_range_returns_error() {
 		let err = parse_range("xx").unwrap_err();
-		assert!(err.contains("`xx` is not a valid range"))
+		assert!(err.contains("`xx` is not a valid range"));
+
+		let err = parse_range("").unwrap_err();
+		assert!(err.contains("`` is not a valid range"));
 	}
 
 	#[test]
@@ -64,4 +335,133 @@ mod tests {
 		let err = parse_range("1234..xx").unwrap_err();
 		assert!(err.contains("range end `xx` is not a valid code"));
 	}
+
+	#[test]
+	fn can_parse_code_sequence() {
+		assert_eq!(parse_code_sequence("0041"), Ok(vec![0x41]));
+		assert_eq!(
+			parse_code_sequence("0041 0308 10FFFF"),
+			Ok(vec![0x41, 0x308, 0x10FFFF])
+		);
+		assert_eq!(parse_code_sequence("  0030\t20E3 "), Ok(vec![0x30, 0x20E3]));
+		assert_eq!(parse_code_sequence(""), Ok(vec![]));
+	}
+
+	#[test]
+	fn parsing_invalid_code_sequence_returns_error() {
+		let err = parse_code_sequence("0041 xyz 0042").unwrap_err();
+		assert!(err.starts_with("`xyz` is not a valid code"));
+		assert!(err.ends_with("in sequence `0041 xyz 0042`"));
+
+		let err = parse_code_sequence("0041 +42").unwrap_err();
+		assert!(err.starts_with("`+42` is not a valid code"));
+	}
+
+	#[test]
+	fn can_parse_fields() {
+		let [a, b, c] = parse_fields::<3>("1;2;3").unwrap();
+		assert_eq!((a, b, c), ("1", "2", "3"));
+
+		let [a, b, c] = parse_fields::<3>(" 1 ; two words ;").unwrap();
+		assert_eq!((a, b, c), ("1", "two words", ""));
+
+		let [a] = parse_fields::<1>("single").unwrap();
+		assert_eq!(a, "single");
+	}
+
+	#[test]
+	fn parse_fields_with_too_few_fields_returns_error() {
+		let err = parse_fields::<3>("1;2").unwrap_err();
+		assert!(err.contains("invalid row format (expected 3 fields, found 2)"));
+	}
+
+	#[test]
+	fn parse_fields_with_too_many_fields_returns_error() {
+		let err = parse_fields::<3>("1;2;3;4").unwrap_err();
+		assert!(err.contains("invalid row format (expected 3 fields, found 4)"));
+	}
+
+	#[test]
+	fn can_parse_range_line() {
+		let (a, b, value) = parse_range_line("0041..005A    ; Upper").unwrap();
+		assert_eq!(a, 0x41);
+		assert_eq!(b, 0x5A);
+		assert_eq!(value, "Upper");
+
+		let (a, b, value) = parse_range_line("0041 .. 005A ; Upper").unwrap();
+		assert_eq!((a, b), (0x41, 0x5A));
+		assert_eq!(value, "Upper");
+
+		let (a, b, value) = parse_range_line("00AD ; Format ").unwrap();
+		assert_eq!(a, 0xAD);
+		assert_eq!(b, 0xAD);
+		assert_eq!(value, "Format");
+	}
+
+	#[test]
+	fn parsing_invalid_range_line_returns_error() {
+		let err = parse_range_line("0041..005A").unwrap_err();
+		assert!(err.contains("`0041..005A` is missing `;`"));
+
+		let err = parse_range_line("xx ; Upper").unwrap_err();
+		assert!(err.contains("`xx` is not a valid code"));
+		assert!(err.contains("-- in `xx ; Upper`"));
+	}
+
+	#[test]
+	fn can_parse_missing_annotation() {
+		let parsed = parse_missing_annotation("# @missing: 0000..10FFFF; Unknown");
+		assert_eq!(parsed, Some((0x0000, 0x10FFFF, "Unknown")));
+
+		let parsed = parse_missing_annotation("@missing: 0000..10FFFF; NFD_QC; Yes");
+		assert_eq!(parsed, Some((0x0000, 0x10FFFF, "NFD_QC; Yes")));
+
+		let parsed = parse_missing_annotation("#  @missing: 3400..4DBF ; W");
+		assert_eq!(parsed, Some((0x3400, 0x4DBF, "W")));
+
+		assert_eq!(parse_missing_annotation("# comment"), None);
+		assert_eq!(parse_missing_annotation("0000..10FFFF; Unknown"), None);
+		assert_eq!(parse_missing_annotation("# @missing: xx; Unknown"), None);
+	}
+
+	#[test]
+	fn sets_missing_defaults_in_table() {
+		use crate::{
+			input::InputFile, EastAsianWidth, EastAsianWidthProperty, LineBreak, LineBreakProperty,
+		};
+
+		let mut table = RangeTable::new();
+		let input = Input::get(InputFile::LineBreak);
+		set_missing_defaults(&mut table, &input, LineBreakProperty, LineBreak::parse).unwrap();
+		assert_eq!(table.lookup(0x41, LineBreakProperty), Some(LineBreak::XX));
+		assert_eq!(
+			table.lookup(0x10FFFF, LineBreakProperty),
+			Some(LineBreak::XX)
+		);
+
+		let key = EastAsianWidthProperty;
+		let err = set_missing_defaults(&mut table, &input, key, EastAsianWidth::parse);
+		assert_eq!(err, Err("`XX` is not a valid @missing value".to_string()));
+	}
+
+	#[test]
+	fn parse_all_collects_items_and_errors() {
+		let input = concat!(
+			"# header\n",
+			"0000..001F ; a\n",
+			"\n",
+			"0020 ; b # comment\n",
+			"xx ; c\n",
+			"0030\n",
+			"0040..zz ; d\n",
+		);
+		let (items, errors) = parse_all(input, parse_range_line);
+		assert_eq!(items, vec![(0x00, 0x1F, "a"), (0x20, 0x20, "b")]);
+
+		let lines = errors.iter().map(|x| x.0).collect::<Vec<_>>();
+		assert_eq!(lines, vec![5, 6, 7]);
+		assert!(errors[0].1.contains("`xx` is not a valid code"));
+		assert!(errors[1].1.contains("is missing `;`"));
+		assert!(errors[2].1.contains("range end"));
+	}
 }
@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+use crate::input::{Input, InputFile};
+
+/// Aliases for a property value from `PropertyValueAliases.txt`.
+///
+/// ```
+/// # use ucd_parser::PropertyValueAlias;
+/// assert_eq!(PropertyValueAlias::long_name("gc", "Lu"), Some("Uppercase_Letter"));
+/// assert_eq!(PropertyValueAlias::short_name("gc", "Uppercase_Letter"), Some("Lu"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PropertyValueAlias<'a> {
+	/// Short name for the property (e.g. `gc`).
+	pub property: &'a str,
+
+	/// Abbreviated name for the value (e.g. `Lu`).
+	pub short: &'a str,
+
+	/// Long name for the value (e.g. `Uppercase_Letter`).
+	pub long: &'a str,
+
+	/// Additional aliases for the value, if any.
+	///
+	/// For the `ccc` property this includes the numeric value for the class,
+	/// which is listed before the short name in the file.
+	pub extra: Vec<&'a str>,
+}
+
+impl<'a> PropertyValueAlias<'a> {
+	/// List of aliases from the UCD data in file order. Lazy-loaded from
+	/// `PropertyValueAliases.txt`.
+	pub fn list() -> &'static [PropertyValueAlias<'static>] {
+		static LIST: Lazy<Box<[PropertyValueAlias]>> = Lazy::new(|| {
+			let input = Input::get(InputFile::PropertyValueAliases);
+			let lines = input.lines();
+			let list = lines.map(|x| PropertyValueAlias::parse(x).unwrap());
+			let list = list.collect::<Vec<_>>();
+			list.into_boxed_slice()
+		});
+		&LIST
+	}
+
+	/// Return the long name for a property value given its short name.
+	pub fn long_name(property: &str, short: &str) -> Option<&'static str> {
+		static MAP: Lazy<HashMap<(&str, &str), &str>> = Lazy::new(|| {
+			let list = PropertyValueAlias::list().iter();
+			list.map(|x| ((x.property, x.short), x.long)).collect()
+		});
+		MAP.get(&(property, short)).copied()
+	}
+
+	/// Return the short name for a property value given its long name or
+	/// any of its additional aliases.
+	pub fn short_name(property: &str, long: &str) -> Option<&'static str> {
+		static MAP: Lazy<HashMap<(&str, &str), &str>> = Lazy::new(|| {
+			let mut map = HashMap::new();
+			for it in PropertyValueAlias::list() {
+				map.insert((it.property, it.long), it.short);
+				for extra in it.extra.iter() {
+					map.entry((it.property, *extra)).or_insert(it.short);
+				}
+			}
+			map
+		});
+		MAP.get(&(property, long)).copied()
+	}
+
+	pub fn parse(input: &'a str) -> Result<Self, String> {
+		let mut fields = input.split(';').map(|x| x.trim());
+		let property = fields.next().unwrap_or_default();
+		let mut fields = fields.collect::<Vec<_>>();
+		if property.is_empty() || fields.len() < 2 {
+			return Err(format!("invalid property value alias -- in `{}`", input));
+		}
+
+		let mut extra = Vec::new();
+		if property == "ccc" {
+			extra.push(fields.remove(0));
+			if fields.len() < 2 {
+				return Err(format!("invalid property value alias -- in `{}`", input));
+			}
+		}
+
+		let short = fields[0];
+		let long = fields[1];
+		extra.extend(fields.iter().skip(2));
+		Ok(PropertyValueAlias {
+			property,
+			short,
+			long,
+			extra,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_from_string() {
+		let alias = PropertyValueAlias::parse("gc ; Lu ; Uppercase_Letter").unwrap();
+		assert_eq!(alias.property, "gc");
+		assert_eq!(alias.short, "Lu");
+		assert_eq!(alias.long, "Uppercase_Letter");
+		assert!(alias.extra.is_empty());
+
+		let alias = PropertyValueAlias::parse("gc ; M ; Mark ; Combining_Mark").unwrap();
+		assert_eq!(alias.short, "M");
+		assert_eq!(alias.long, "Mark");
+		assert_eq!(alias.extra, vec!["Combining_Mark"]);
+
+		let alias = PropertyValueAlias::parse("ccc;   0; NR ; Not_Reordered").unwrap();
+		assert_eq!(alias.property, "ccc");
+		assert_eq!(alias.short, "NR");
+		assert_eq!(alias.long, "Not_Reordered");
+		assert_eq!(alias.extra, vec!["0"]);
+	}
+
+	#[test]
+	fn parse_invalid_input_returns_error() {
+		let err = PropertyValueAlias::parse("gc ; Lu").unwrap_err();
+		assert!(err.contains("invalid property value alias -- in `gc ; Lu`"));
+
+		let err = PropertyValueAlias::parse("ccc; 0; NR").unwrap_err();
+		assert!(err.contains("invalid property value alias"));
+	}
+
+	#[test]
+	fn can_load_from_ucd() {
+		let list = PropertyValueAlias::list();
+		assert!(list.len() > 1000);
+		assert!(list.iter().any(|x| x.property == "sc" && x.long == "Latin"));
+	}
+
+	#[test]
+	fn maps_between_short_and_long_names() {
+		assert_eq!(
+			PropertyValueAlias::long_name("gc", "Lu"),
+			Some("Uppercase_Letter")
+		);
+		assert_eq!(
+			PropertyValueAlias::short_name("gc", "Uppercase_Letter"),
+			Some("Lu")
+		);
+		assert_eq!(
+			PropertyValueAlias::short_name("gc", "Combining_Mark"),
+			Some("M")
+		);
+		assert_eq!(
+			PropertyValueAlias::long_name("bc", "AL"),
+			Some("Arabic_Letter")
+		);
+		assert_eq!(
+			PropertyValueAlias::long_name("ccc", "NR"),
+			Some("Not_Reordered")
+		);
+		assert_eq!(PropertyValueAlias::short_name("ccc", "0"), Some("NR"));
+
+		assert_eq!(
+			PropertyValueAlias::long_name("gc", "Uppercase_Letter"),
+			None
+		);
+		assert_eq!(PropertyValueAlias::long_name("bc", "Lu"), None);
+	}
+}
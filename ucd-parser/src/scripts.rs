@@ -0,0 +1,1214 @@
+use once_cell::sync::Lazy;
+use ucd_property_ranges::{PropertyKey, RangeTable};
+
+use crate::{
+	input::{Input, InputFile},
+	parse::{parse_range_line, OrUnknown},
+};
+
+/// Values for the `Script` property, identifying the writing system a
+/// character belongs to.
+///
+/// Values are declared in the order of their ISO 15924 short codes.
+///
+/// See https://www.unicode.org/reports/tr24/
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Script {
+	/// `Adlm` Adlam script.
+	Adlam,
+	/// `Aghb` Caucasian Albanian script.
+	CaucasianAlbanian,
+	/// `Ahom` Ahom script.
+	Ahom,
+	/// `Arab` Arabic script.
+	Arabic,
+	/// `Armi` Imperial Aramaic script.
+	ImperialAramaic,
+	/// `Armn` Armenian script.
+	Armenian,
+	/// `Avst` Avestan script.
+	Avestan,
+	/// `Bali` Balinese script.
+	Balinese,
+	/// `Bamu` Bamum script.
+	Bamum,
+	/// `Bass` Bassa Vah script.
+	BassaVah,
+	/// `Batk` Batak script.
+	Batak,
+	/// `Beng` Bengali script.
+	Bengali,
+	/// `Bhks` Bhaiksuki script.
+	Bhaiksuki,
+	/// `Bopo` Bopomofo script.
+	Bopomofo,
+	/// `Brah` Brahmi script.
+	Brahmi,
+	/// `Brai` Braille script.
+	Braille,
+	/// `Bugi` Buginese script.
+	Buginese,
+	/// `Buhd` Buhid script.
+	Buhid,
+	/// `Cakm` Chakma script.
+	Chakma,
+	/// `Cans` Canadian Aboriginal script.
+	CanadianAboriginal,
+	/// `Cari` Carian script.
+	Carian,
+	/// `Cham` Cham script.
+	Cham,
+	/// `Cher` Cherokee script.
+	Cherokee,
+	/// `Chrs` Chorasmian script.
+	Chorasmian,
+	/// `Copt` Coptic script.
+	Coptic,
+	/// `Cpmn` Cypro Minoan script.
+	CyproMinoan,
+	/// `Cprt` Cypriot script.
+	Cypriot,
+	/// `Cyrl` Cyrillic script.
+	Cyrillic,
+	/// `Deva` Devanagari script.
+	Devanagari,
+	/// `Diak` Dives Akuru script.
+	DivesAkuru,
+	/// `Dogr` Dogra script.
+	Dogra,
+	/// `Dsrt` Deseret script.
+	Deseret,
+	/// `Dupl` Duployan script.
+	Duployan,
+	/// `Egyp` Egyptian Hieroglyphs script.
+	EgyptianHieroglyphs,
+	/// `Elba` Elbasan script.
+	Elbasan,
+	/// `Elym` Elymaic script.
+	Elymaic,
+	/// `Ethi` Ethiopic script.
+	Ethiopic,
+	/// `Geor` Georgian script.
+	Georgian,
+	/// `Glag` Glagolitic script.
+	Glagolitic,
+	/// `Gong` Gunjala Gondi script.
+	GunjalaGondi,
+	/// `Gonm` Masaram Gondi script.
+	MasaramGondi,
+	/// `Goth` Gothic script.
+	Gothic,
+	/// `Gran` Grantha script.
+	Grantha,
+	/// `Grek` Greek script.
+	Greek,
+	/// `Gujr` Gujarati script.
+	Gujarati,
+	/// `Guru` Gurmukhi script.
+	Gurmukhi,
+	/// `Hang` Hangul script.
+	Hangul,
+	/// `Hani` Han script.
+	Han,
+	/// `Hano` Hanunoo script.
+	Hanunoo,
+	/// `Hatr` Hatran script.
+	Hatran,
+	/// `Hebr` Hebrew script.
+	Hebrew,
+	/// `Hira` Hiragana script.
+	Hiragana,
+	/// `Hluw` Anatolian Hieroglyphs script.
+	AnatolianHieroglyphs,
+	/// `Hmng` Pahawh Hmong script.
+	PahawhHmong,
+	/// `Hmnp` Nyiakeng Puachue Hmong script.
+	NyiakengPuachueHmong,
+	/// `Hrkt` Katakana Or Hiragana script.
+	KatakanaOrHiragana,
+	/// `Hung` Old Hungarian script.
+	OldHungarian,
+	/// `Ital` Old Italic script.
+	OldItalic,
+	/// `Java` Javanese script.
+	Javanese,
+	/// `Kali` Kayah Li script.
+	KayahLi,
+	/// `Kana` Katakana script.
+	Katakana,
+	/// `Khar` Kharoshthi script.
+	Kharoshthi,
+	/// `Khmr` Khmer script.
+	Khmer,
+	/// `Khoj` Khojki script.
+	Khojki,
+	/// `Kits` Khitan Small Script script.
+	KhitanSmallScript,
+	/// `Knda` Kannada script.
+	Kannada,
+	/// `Kthi` Kaithi script.
+	Kaithi,
+	/// `Lana` Tai Tham script.
+	TaiTham,
+	/// `Laoo` Lao script.
+	Lao,
+	/// `Latn` Latin script.
+	Latin,
+	/// `Lepc` Lepcha script.
+	Lepcha,
+	/// `Limb` Limbu script.
+	Limbu,
+	/// `Lina` Linear A script.
+	LinearA,
+	/// `Linb` Linear B script.
+	LinearB,
+	/// `Lisu` Lisu script.
+	Lisu,
+	/// `Lyci` Lycian script.
+	Lycian,
+	/// `Lydi` Lydian script.
+	Lydian,
+	/// `Mahj` Mahajani script.
+	Mahajani,
+	/// `Maka` Makasar script.
+	Makasar,
+	/// `Mand` Mandaic script.
+	Mandaic,
+	/// `Mani` Manichaean script.
+	Manichaean,
+	/// `Marc` Marchen script.
+	Marchen,
+	/// `Medf` Medefaidrin script.
+	Medefaidrin,
+	/// `Mend` Mende Kikakui script.
+	MendeKikakui,
+	/// `Merc` Meroitic Cursive script.
+	MeroiticCursive,
+	/// `Mero` Meroitic Hieroglyphs script.
+	MeroiticHieroglyphs,
+	/// `Mlym` Malayalam script.
+	Malayalam,
+	/// `Modi` Modi script.
+	Modi,
+	/// `Mong` Mongolian script.
+	Mongolian,
+	/// `Mroo` Mro script.
+	Mro,
+	/// `Mtei` Meetei Mayek script.
+	MeeteiMayek,
+	/// `Mult` Multani script.
+	Multani,
+	/// `Mymr` Myanmar script.
+	Myanmar,
+	/// `Nand` Nandinagari script.
+	Nandinagari,
+	/// `Narb` Old North Arabian script.
+	OldNorthArabian,
+	/// `Nbat` Nabataean script.
+	Nabataean,
+	/// `Newa` Newa script.
+	Newa,
+	/// `Nkoo` Nko script.
+	Nko,
+	/// `Nshu` Nushu script.
+	Nushu,
+	/// `Ogam` Ogham script.
+	Ogham,
+	/// `Olck` Ol Chiki script.
+	OlChiki,
+	/// `Orkh` Old Turkic script.
+	OldTurkic,
+	/// `Orya` Oriya script.
+	Oriya,
+	/// `Osge` Osage script.
+	Osage,
+	/// `Osma` Osmanya script.
+	Osmanya,
+	/// `Ougr` Old Uyghur script.
+	OldUyghur,
+	/// `Palm` Palmyrene script.
+	Palmyrene,
+	/// `Pauc` Pau Cin Hau script.
+	PauCinHau,
+	/// `Perm` Old Permic script.
+	OldPermic,
+	/// `Phag` Phags Pa script.
+	PhagsPa,
+	/// `Phli` Inscriptional Pahlavi script.
+	InscriptionalPahlavi,
+	/// `Phlp` Psalter Pahlavi script.
+	PsalterPahlavi,
+	/// `Phnx` Phoenician script.
+	Phoenician,
+	/// `Plrd` Miao script.
+	Miao,
+	/// `Prti` Inscriptional Parthian script.
+	InscriptionalParthian,
+	/// `Rjng` Rejang script.
+	Rejang,
+	/// `Rohg` Hanifi Rohingya script.
+	HanifiRohingya,
+	/// `Runr` Runic script.
+	Runic,
+	/// `Samr` Samaritan script.
+	Samaritan,
+	/// `Sarb` Old South Arabian script.
+	OldSouthArabian,
+	/// `Saur` Saurashtra script.
+	Saurashtra,
+	/// `Sgnw` SignWriting script.
+	SignWriting,
+	/// `Shaw` Shavian script.
+	Shavian,
+	/// `Shrd` Sharada script.
+	Sharada,
+	/// `Sidd` Siddham script.
+	Siddham,
+	/// `Sind` Khudawadi script.
+	Khudawadi,
+	/// `Sinh` Sinhala script.
+	Sinhala,
+	/// `Sogd` Sogdian script.
+	Sogdian,
+	/// `Sogo` Old Sogdian script.
+	OldSogdian,
+	/// `Sora` Sora Sompeng script.
+	SoraSompeng,
+	/// `Soyo` Soyombo script.
+	Soyombo,
+	/// `Sund` Sundanese script.
+	Sundanese,
+	/// `Sylo` Syloti Nagri script.
+	SylotiNagri,
+	/// `Syrc` Syriac script.
+	Syriac,
+	/// `Tagb` Tagbanwa script.
+	Tagbanwa,
+	/// `Takr` Takri script.
+	Takri,
+	/// `Tale` Tai Le script.
+	TaiLe,
+	/// `Talu` New Tai Lue script.
+	NewTaiLue,
+	/// `Taml` Tamil script.
+	Tamil,
+	/// `Tang` Tangut script.
+	Tangut,
+	/// `Tavt` Tai Viet script.
+	TaiViet,
+	/// `Telu` Telugu script.
+	Telugu,
+	/// `Tfng` Tifinagh script.
+	Tifinagh,
+	/// `Tglg` Tagalog script.
+	Tagalog,
+	/// `Thaa` Thaana script.
+	Thaana,
+	/// `Thai` Thai script.
+	Thai,
+	/// `Tibt` Tibetan script.
+	Tibetan,
+	/// `Tirh` Tirhuta script.
+	Tirhuta,
+	/// `Tnsa` Tangsa script.
+	Tangsa,
+	/// `Toto` Toto script.
+	Toto,
+	/// `Ugar` Ugaritic script.
+	Ugaritic,
+	/// `Vaii` Vai script.
+	Vai,
+	/// `Vith` Vithkuqi script.
+	Vithkuqi,
+	/// `Wara` Warang Citi script.
+	WarangCiti,
+	/// `Wcho` Wancho script.
+	Wancho,
+	/// `Xpeo` Old Persian script.
+	OldPersian,
+	/// `Xsux` Cuneiform script.
+	Cuneiform,
+	/// `Yezi` Yezidi script.
+	Yezidi,
+	/// `Yiii` Yi script.
+	Yi,
+	/// `Zanb` Zanabazar Square script.
+	ZanabazarSquare,
+	/// `Zinh` characters that inherit the script of the preceding character.
+	Inherited,
+	/// `Zyyy` characters used by multiple scripts.
+	Common,
+	/// `Zzzz` default for unassigned, private-use, and surrogate codepoints.
+	Unknown,
+}
+
+impl Script {
+	/// Build a [`RangeTable`] with the [`ScriptProperty`] for the entire
+	/// codespace from `Scripts.txt`.
+	///
+	/// Codepoints not listed in the file are set to [`Script::Unknown`].
+	pub fn load() -> RangeTable {
+		let mut table = RangeTable::new();
+		table.set_range(0..=0x10FFFF, ScriptProperty, Script::Unknown);
+		for &(sta, end, value) in script_ranges() {
+			table.set_range(sta..=end, ScriptProperty, value);
+		}
+		table
+	}
+
+	/// Parse a script from its long name, as used in `Scripts.txt` (e.g.
+	/// `Old_Italic`).
+	pub fn parse<T: AsRef<str>>(input: T) -> Option<Self> {
+		let script = match input.as_ref() {
+			"Adlam" => Script::Adlam,
+			"Caucasian_Albanian" => Script::CaucasianAlbanian,
+			"Ahom" => Script::Ahom,
+			"Arabic" => Script::Arabic,
+			"Imperial_Aramaic" => Script::ImperialAramaic,
+			"Armenian" => Script::Armenian,
+			"Avestan" => Script::Avestan,
+			"Balinese" => Script::Balinese,
+			"Bamum" => Script::Bamum,
+			"Bassa_Vah" => Script::BassaVah,
+			"Batak" => Script::Batak,
+			"Bengali" => Script::Bengali,
+			"Bhaiksuki" => Script::Bhaiksuki,
+			"Bopomofo" => Script::Bopomofo,
+			"Brahmi" => Script::Brahmi,
+			"Braille" => Script::Braille,
+			"Buginese" => Script::Buginese,
+			"Buhid" => Script::Buhid,
+			"Chakma" => Script::Chakma,
+			"Canadian_Aboriginal" => Script::CanadianAboriginal,
+			"Carian" => Script::Carian,
+			"Cham" => Script::Cham,
+			"Cherokee" => Script::Cherokee,
+			"Chorasmian" => Script::Chorasmian,
+			"Coptic" => Script::Coptic,
+			"Cypro_Minoan" => Script::CyproMinoan,
+			"Cypriot" => Script::Cypriot,
+			"Cyrillic" => Script::Cyrillic,
+			"Devanagari" => Script::Devanagari,
+			"Dives_Akuru" => Script::DivesAkuru,
+			"Dogra" => Script::Dogra,
+			"Deseret" => Script::Deseret,
+			"Duployan" => Script::Duployan,
+			"Egyptian_Hieroglyphs" => Script::EgyptianHieroglyphs,
+			"Elbasan" => Script::Elbasan,
+			"Elymaic" => Script::Elymaic,
+			"Ethiopic" => Script::Ethiopic,
+			"Georgian" => Script::Georgian,
+			"Glagolitic" => Script::Glagolitic,
+			"Gunjala_Gondi" => Script::GunjalaGondi,
+			"Masaram_Gondi" => Script::MasaramGondi,
+			"Gothic" => Script::Gothic,
+			"Grantha" => Script::Grantha,
+			"Greek" => Script::Greek,
+			"Gujarati" => Script::Gujarati,
+			"Gurmukhi" => Script::Gurmukhi,
+			"Hangul" => Script::Hangul,
+			"Han" => Script::Han,
+			"Hanunoo" => Script::Hanunoo,
+			"Hatran" => Script::Hatran,
+			"Hebrew" => Script::Hebrew,
+			"Hiragana" => Script::Hiragana,
+			"Anatolian_Hieroglyphs" => Script::AnatolianHieroglyphs,
+			"Pahawh_Hmong" => Script::PahawhHmong,
+			"Nyiakeng_Puachue_Hmong" => Script::NyiakengPuachueHmong,
+			"Katakana_Or_Hiragana" => Script::KatakanaOrHiragana,
+			"Old_Hungarian" => Script::OldHungarian,
+			"Old_Italic" => Script::OldItalic,
+			"Javanese" => Script::Javanese,
+			"Kayah_Li" => Script::KayahLi,
+			"Katakana" => Script::Katakana,
+			"Kharoshthi" => Script::Kharoshthi,
+			"Khmer" => Script::Khmer,
+			"Khojki" => Script::Khojki,
+			"Khitan_Small_Script" => Script::KhitanSmallScript,
+			"Kannada" => Script::Kannada,
+			"Kaithi" => Script::Kaithi,
+			"Tai_Tham" => Script::TaiTham,
+			"Lao" => Script::Lao,
+			"Latin" => Script::Latin,
+			"Lepcha" => Script::Lepcha,
+			"Limbu" => Script::Limbu,
+			"Linear_A" => Script::LinearA,
+			"Linear_B" => Script::LinearB,
+			"Lisu" => Script::Lisu,
+			"Lycian" => Script::Lycian,
+			"Lydian" => Script::Lydian,
+			"Mahajani" => Script::Mahajani,
+			"Makasar" => Script::Makasar,
+			"Mandaic" => Script::Mandaic,
+			"Manichaean" => Script::Manichaean,
+			"Marchen" => Script::Marchen,
+			"Medefaidrin" => Script::Medefaidrin,
+			"Mende_Kikakui" => Script::MendeKikakui,
+			"Meroitic_Cursive" => Script::MeroiticCursive,
+			"Meroitic_Hieroglyphs" => Script::MeroiticHieroglyphs,
+			"Malayalam" => Script::Malayalam,
+			"Modi" => Script::Modi,
+			"Mongolian" => Script::Mongolian,
+			"Mro" => Script::Mro,
+			"Meetei_Mayek" => Script::MeeteiMayek,
+			"Multani" => Script::Multani,
+			"Myanmar" => Script::Myanmar,
+			"Nandinagari" => Script::Nandinagari,
+			"Old_North_Arabian" => Script::OldNorthArabian,
+			"Nabataean" => Script::Nabataean,
+			"Newa" => Script::Newa,
+			"Nko" => Script::Nko,
+			"Nushu" => Script::Nushu,
+			"Ogham" => Script::Ogham,
+			"Ol_Chiki" => Script::OlChiki,
+			"Old_Turkic" => Script::OldTurkic,
+			"Oriya" => Script::Oriya,
+			"Osage" => Script::Osage,
+			"Osmanya" => Script::Osmanya,
+			"Old_Uyghur" => Script::OldUyghur,
+			"Palmyrene" => Script::Palmyrene,
+			"Pau_Cin_Hau" => Script::PauCinHau,
+			"Old_Permic" => Script::OldPermic,
+			"Phags_Pa" => Script::PhagsPa,
+			"Inscriptional_Pahlavi" => Script::InscriptionalPahlavi,
+			"Psalter_Pahlavi" => Script::PsalterPahlavi,
+			"Phoenician" => Script::Phoenician,
+			"Miao" => Script::Miao,
+			"Inscriptional_Parthian" => Script::InscriptionalParthian,
+			"Rejang" => Script::Rejang,
+			"Hanifi_Rohingya" => Script::HanifiRohingya,
+			"Runic" => Script::Runic,
+			"Samaritan" => Script::Samaritan,
+			"Old_South_Arabian" => Script::OldSouthArabian,
+			"Saurashtra" => Script::Saurashtra,
+			"SignWriting" => Script::SignWriting,
+			"Shavian" => Script::Shavian,
+			"Sharada" => Script::Sharada,
+			"Siddham" => Script::Siddham,
+			"Khudawadi" => Script::Khudawadi,
+			"Sinhala" => Script::Sinhala,
+			"Sogdian" => Script::Sogdian,
+			"Old_Sogdian" => Script::OldSogdian,
+			"Sora_Sompeng" => Script::SoraSompeng,
+			"Soyombo" => Script::Soyombo,
+			"Sundanese" => Script::Sundanese,
+			"Syloti_Nagri" => Script::SylotiNagri,
+			"Syriac" => Script::Syriac,
+			"Tagbanwa" => Script::Tagbanwa,
+			"Takri" => Script::Takri,
+			"Tai_Le" => Script::TaiLe,
+			"New_Tai_Lue" => Script::NewTaiLue,
+			"Tamil" => Script::Tamil,
+			"Tangut" => Script::Tangut,
+			"Tai_Viet" => Script::TaiViet,
+			"Telugu" => Script::Telugu,
+			"Tifinagh" => Script::Tifinagh,
+			"Tagalog" => Script::Tagalog,
+			"Thaana" => Script::Thaana,
+			"Thai" => Script::Thai,
+			"Tibetan" => Script::Tibetan,
+			"Tirhuta" => Script::Tirhuta,
+			"Tangsa" => Script::Tangsa,
+			"Toto" => Script::Toto,
+			"Ugaritic" => Script::Ugaritic,
+			"Vai" => Script::Vai,
+			"Vithkuqi" => Script::Vithkuqi,
+			"Warang_Citi" => Script::WarangCiti,
+			"Wancho" => Script::Wancho,
+			"Old_Persian" => Script::OldPersian,
+			"Cuneiform" => Script::Cuneiform,
+			"Yezidi" => Script::Yezidi,
+			"Yi" => Script::Yi,
+			"Zanabazar_Square" => Script::ZanabazarSquare,
+			"Inherited" => Script::Inherited,
+			"Common" => Script::Common,
+			"Unknown" => Script::Unknown,
+			_ => return None,
+		};
+		Some(script)
+	}
+
+	/// Same as [`Script::parse`], but returns [`OrUnknown::Unknown`] with the
+	/// input text for unrecognized values instead of [`None`].
+	pub fn parse_or_unknown<T: AsRef<str>>(input: T) -> OrUnknown<Self> {
+		OrUnknown::parse_with(input.as_ref(), |x| Self::parse(x))
+	}
+
+	/// Parse a script from its four-letter short code, as used in
+	/// `ScriptExtensions.txt` (e.g. `Ital`).
+	pub fn parse_short<T: AsRef<str>>(input: T) -> Option<Self> {
+		let script = match input.as_ref() {
+			"Adlm" => Script::Adlam,
+			"Aghb" => Script::CaucasianAlbanian,
+			"Ahom" => Script::Ahom,
+			"Arab" => Script::Arabic,
+			"Armi" => Script::ImperialAramaic,
+			"Armn" => Script::Armenian,
+			"Avst" => Script::Avestan,
+			"Bali" => Script::Balinese,
+			"Bamu" => Script::Bamum,
+			"Bass" => Script::BassaVah,
+			"Batk" => Script::Batak,
+			"Beng" => Script::Bengali,
+			"Bhks" => Script::Bhaiksuki,
+			"Bopo" => Script::Bopomofo,
+			"Brah" => Script::Brahmi,
+			"Brai" => Script::Braille,
+			"Bugi" => Script::Buginese,
+			"Buhd" => Script::Buhid,
+			"Cakm" => Script::Chakma,
+			"Cans" => Script::CanadianAboriginal,
+			"Cari" => Script::Carian,
+			"Cham" => Script::Cham,
+			"Cher" => Script::Cherokee,
+			"Chrs" => Script::Chorasmian,
+			"Copt" | "Qaac" => Script::Coptic,
+			"Cpmn" => Script::CyproMinoan,
+			"Cprt" => Script::Cypriot,
+			"Cyrl" => Script::Cyrillic,
+			"Deva" => Script::Devanagari,
+			"Diak" => Script::DivesAkuru,
+			"Dogr" => Script::Dogra,
+			"Dsrt" => Script::Deseret,
+			"Dupl" => Script::Duployan,
+			"Egyp" => Script::EgyptianHieroglyphs,
+			"Elba" => Script::Elbasan,
+			"Elym" => Script::Elymaic,
+			"Ethi" => Script::Ethiopic,
+			"Geor" => Script::Georgian,
+			"Glag" => Script::Glagolitic,
+			"Gong" => Script::GunjalaGondi,
+			"Gonm" => Script::MasaramGondi,
+			"Goth" => Script::Gothic,
+			"Gran" => Script::Grantha,
+			"Grek" => Script::Greek,
+			"Gujr" => Script::Gujarati,
+			"Guru" => Script::Gurmukhi,
+			"Hang" => Script::Hangul,
+			"Hani" => Script::Han,
+			"Hano" => Script::Hanunoo,
+			"Hatr" => Script::Hatran,
+			"Hebr" => Script::Hebrew,
+			"Hira" => Script::Hiragana,
+			"Hluw" => Script::AnatolianHieroglyphs,
+			"Hmng" => Script::PahawhHmong,
+			"Hmnp" => Script::NyiakengPuachueHmong,
+			"Hrkt" => Script::KatakanaOrHiragana,
+			"Hung" => Script::OldHungarian,
+			"Ital" => Script::OldItalic,
+			"Java" => Script::Javanese,
+			"Kali" => Script::KayahLi,
+			"Kana" => Script::Katakana,
+			"Khar" => Script::Kharoshthi,
+			"Khmr" => Script::Khmer,
+			"Khoj" => Script::Khojki,
+			"Kits" => Script::KhitanSmallScript,
+			"Knda" => Script::Kannada,
+			"Kthi" => Script::Kaithi,
+			"Lana" => Script::TaiTham,
+			"Laoo" => Script::Lao,
+			"Latn" => Script::Latin,
+			"Lepc" => Script::Lepcha,
+			"Limb" => Script::Limbu,
+			"Lina" => Script::LinearA,
+			"Linb" => Script::LinearB,
+			"Lisu" => Script::Lisu,
+			"Lyci" => Script::Lycian,
+			"Lydi" => Script::Lydian,
+			"Mahj" => Script::Mahajani,
+			"Maka" => Script::Makasar,
+			"Mand" => Script::Mandaic,
+			"Mani" => Script::Manichaean,
+			"Marc" => Script::Marchen,
+			"Medf" => Script::Medefaidrin,
+			"Mend" => Script::MendeKikakui,
+			"Merc" => Script::MeroiticCursive,
+			"Mero" => Script::MeroiticHieroglyphs,
+			"Mlym" => Script::Malayalam,
+			"Modi" => Script::Modi,
+			"Mong" => Script::Mongolian,
+			"Mroo" => Script::Mro,
+			"Mtei" => Script::MeeteiMayek,
+			"Mult" => Script::Multani,
+			"Mymr" => Script::Myanmar,
+			"Nand" => Script::Nandinagari,
+			"Narb" => Script::OldNorthArabian,
+			"Nbat" => Script::Nabataean,
+			"Newa" => Script::Newa,
+			"Nkoo" => Script::Nko,
+			"Nshu" => Script::Nushu,
+			"Ogam" => Script::Ogham,
+			"Olck" => Script::OlChiki,
+			"Orkh" => Script::OldTurkic,
+			"Orya" => Script::Oriya,
+			"Osge" => Script::Osage,
+			"Osma" => Script::Osmanya,
+			"Ougr" => Script::OldUyghur,
+			"Palm" => Script::Palmyrene,
+			"Pauc" => Script::PauCinHau,
+			"Perm" => Script::OldPermic,
+			"Phag" => Script::PhagsPa,
+			"Phli" => Script::InscriptionalPahlavi,
+			"Phlp" => Script::PsalterPahlavi,
+			"Phnx" => Script::Phoenician,
+			"Plrd" => Script::Miao,
+			"Prti" => Script::InscriptionalParthian,
+			"Rjng" => Script::Rejang,
+			"Rohg" => Script::HanifiRohingya,
+			"Runr" => Script::Runic,
+			"Samr" => Script::Samaritan,
+			"Sarb" => Script::OldSouthArabian,
+			"Saur" => Script::Saurashtra,
+			"Sgnw" => Script::SignWriting,
+			"Shaw" => Script::Shavian,
+			"Shrd" => Script::Sharada,
+			"Sidd" => Script::Siddham,
+			"Sind" => Script::Khudawadi,
+			"Sinh" => Script::Sinhala,
+			"Sogd" => Script::Sogdian,
+			"Sogo" => Script::OldSogdian,
+			"Sora" => Script::SoraSompeng,
+			"Soyo" => Script::Soyombo,
+			"Sund" => Script::Sundanese,
+			"Sylo" => Script::SylotiNagri,
+			"Syrc" => Script::Syriac,
+			"Tagb" => Script::Tagbanwa,
+			"Takr" => Script::Takri,
+			"Tale" => Script::TaiLe,
+			"Talu" => Script::NewTaiLue,
+			"Taml" => Script::Tamil,
+			"Tang" => Script::Tangut,
+			"Tavt" => Script::TaiViet,
+			"Telu" => Script::Telugu,
+			"Tfng" => Script::Tifinagh,
+			"Tglg" => Script::Tagalog,
+			"Thaa" => Script::Thaana,
+			"Thai" => Script::Thai,
+			"Tibt" => Script::Tibetan,
+			"Tirh" => Script::Tirhuta,
+			"Tnsa" => Script::Tangsa,
+			"Toto" => Script::Toto,
+			"Ugar" => Script::Ugaritic,
+			"Vaii" => Script::Vai,
+			"Vith" => Script::Vithkuqi,
+			"Wara" => Script::WarangCiti,
+			"Wcho" => Script::Wancho,
+			"Xpeo" => Script::OldPersian,
+			"Xsux" => Script::Cuneiform,
+			"Yezi" => Script::Yezidi,
+			"Yiii" => Script::Yi,
+			"Zanb" => Script::ZanabazarSquare,
+			"Zinh" | "Qaai" => Script::Inherited,
+			"Zyyy" => Script::Common,
+			"Zzzz" => Script::Unknown,
+			_ => return None,
+		};
+		Some(script)
+	}
+
+	/// Returns the four-letter short code for the script.
+	pub fn short_name(&self) -> &'static str {
+		match self {
+			Script::Adlam => "Adlm",
+			Script::CaucasianAlbanian => "Aghb",
+			Script::Ahom => "Ahom",
+			Script::Arabic => "Arab",
+			Script::ImperialAramaic => "Armi",
+			Script::Armenian => "Armn",
+			Script::Avestan => "Avst",
+			Script::Balinese => "Bali",
+			Script::Bamum => "Bamu",
+			Script::BassaVah => "Bass",
+			Script::Batak => "Batk",
+			Script::Bengali => "Beng",
+			Script::Bhaiksuki => "Bhks",
+			Script::Bopomofo => "Bopo",
+			Script::Brahmi => "Brah",
+			Script::Braille => "Brai",
+			Script::Buginese => "Bugi",
+			Script::Buhid => "Buhd",
+			Script::Chakma => "Cakm",
+			Script::CanadianAboriginal => "Cans",
+			Script::Carian => "Cari",
+			Script::Cham => "Cham",
+			Script::Cherokee => "Cher",
+			Script::Chorasmian => "Chrs",
+			Script::Coptic => "Copt",
+			Script::CyproMinoan => "Cpmn",
+			Script::Cypriot => "Cprt",
+			Script::Cyrillic => "Cyrl",
+			Script::Devanagari => "Deva",
+			Script::DivesAkuru => "Diak",
+			Script::Dogra => "Dogr",
+			Script::Deseret => "Dsrt",
+			Script::Duployan => "Dupl",
+			Script::EgyptianHieroglyphs => "Egyp",
+			Script::Elbasan => "Elba",
+			Script::Elymaic => "Elym",
+			Script::Ethiopic => "Ethi",
+			Script::Georgian => "Geor",
+			Script::Glagolitic => "Glag",
+			Script::GunjalaGondi => "Gong",
+			Script::MasaramGondi => "Gonm",
+			Script::Gothic => "Goth",
+			Script::Grantha => "Gran",
+			Script::Greek => "Grek",
+			Script::Gujarati => "Gujr",
+			Script::Gurmukhi => "Guru",
+			Script::Hangul => "Hang",
+			Script::Han => "Hani",
+			Script::Hanunoo => "Hano",
+			Script::Hatran => "Hatr",
+			Script::Hebrew => "Hebr",
+			Script::Hiragana => "Hira",
+			Script::AnatolianHieroglyphs => "Hluw",
+			Script::PahawhHmong => "Hmng",
+			Script::NyiakengPuachueHmong => "Hmnp",
+			Script::KatakanaOrHiragana => "Hrkt",
+			Script::OldHungarian => "Hung",
+			Script::OldItalic => "Ital",
+			Script::Javanese => "Java",
+			Script::KayahLi => "Kali",
+			Script::Katakana => "Kana",
+			Script::Kharoshthi => "Khar",
+			Script::Khmer => "Khmr",
+			Script::Khojki => "Khoj",
+			Script::KhitanSmallScript => "Kits",
+			Script::Kannada => "Knda",
+			Script::Kaithi => "Kthi",
+			Script::TaiTham => "Lana",
+			Script::Lao => "Laoo",
+			Script::Latin => "Latn",
+			Script::Lepcha => "Lepc",
+			Script::Limbu => "Limb",
+			Script::LinearA => "Lina",
+			Script::LinearB => "Linb",
+			Script::Lisu => "Lisu",
+			Script::Lycian => "Lyci",
+			Script::Lydian => "Lydi",
+			Script::Mahajani => "Mahj",
+			Script::Makasar => "Maka",
+			Script::Mandaic => "Mand",
+			Script::Manichaean => "Mani",
+			Script::Marchen => "Marc",
+			Script::Medefaidrin => "Medf",
+			Script::MendeKikakui => "Mend",
+			Script::MeroiticCursive => "Merc",
+			Script::MeroiticHieroglyphs => "Mero",
+			Script::Malayalam => "Mlym",
+			Script::Modi => "Modi",
+			Script::Mongolian => "Mong",
+			Script::Mro => "Mroo",
+			Script::MeeteiMayek => "Mtei",
+			Script::Multani => "Mult",
+			Script::Myanmar => "Mymr",
+			Script::Nandinagari => "Nand",
+			Script::OldNorthArabian => "Narb",
+			Script::Nabataean => "Nbat",
+			Script::Newa => "Newa",
+			Script::Nko => "Nkoo",
+			Script::Nushu => "Nshu",
+			Script::Ogham => "Ogam",
+			Script::OlChiki => "Olck",
+			Script::OldTurkic => "Orkh",
+			Script::Oriya => "Orya",
+			Script::Osage => "Osge",
+			Script::Osmanya => "Osma",
+			Script::OldUyghur => "Ougr",
+			Script::Palmyrene => "Palm",
+			Script::PauCinHau => "Pauc",
+			Script::OldPermic => "Perm",
+			Script::PhagsPa => "Phag",
+			Script::InscriptionalPahlavi => "Phli",
+			Script::PsalterPahlavi => "Phlp",
+			Script::Phoenician => "Phnx",
+			Script::Miao => "Plrd",
+			Script::InscriptionalParthian => "Prti",
+			Script::Rejang => "Rjng",
+			Script::HanifiRohingya => "Rohg",
+			Script::Runic => "Runr",
+			Script::Samaritan => "Samr",
+			Script::OldSouthArabian => "Sarb",
+			Script::Saurashtra => "Saur",
+			Script::SignWriting => "Sgnw",
+			Script::Shavian => "Shaw",
+			Script::Sharada => "Shrd",
+			Script::Siddham => "Sidd",
+			Script::Khudawadi => "Sind",
+			Script::Sinhala => "Sinh",
+			Script::Sogdian => "Sogd",
+			Script::OldSogdian => "Sogo",
+			Script::SoraSompeng => "Sora",
+			Script::Soyombo => "Soyo",
+			Script::Sundanese => "Sund",
+			Script::SylotiNagri => "Sylo",
+			Script::Syriac => "Syrc",
+			Script::Tagbanwa => "Tagb",
+			Script::Takri => "Takr",
+			Script::TaiLe => "Tale",
+			Script::NewTaiLue => "Talu",
+			Script::Tamil => "Taml",
+			Script::Tangut => "Tang",
+			Script::TaiViet => "Tavt",
+			Script::Telugu => "Telu",
+			Script::Tifinagh => "Tfng",
+			Script::Tagalog => "Tglg",
+			Script::Thaana => "Thaa",
+			Script::Thai => "Thai",
+			Script::Tibetan => "Tibt",
+			Script::Tirhuta => "Tirh",
+			Script::Tangsa => "Tnsa",
+			Script::Toto => "Toto",
+			Script::Ugaritic => "Ugar",
+			Script::Vai => "Vaii",
+			Script::Vithkuqi => "Vith",
+			Script::WarangCiti => "Wara",
+			Script::Wancho => "Wcho",
+			Script::OldPersian => "Xpeo",
+			Script::Cuneiform => "Xsux",
+			Script::Yezidi => "Yezi",
+			Script::Yi => "Yiii",
+			Script::ZanabazarSquare => "Zanb",
+			Script::Inherited => "Zinh",
+			Script::Common => "Zyyy",
+			Script::Unknown => "Zzzz",
+		}
+	}
+}
+
+impl std::fmt::Display for Script {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let output = match self {
+			Script::Adlam => "Adlam",
+			Script::CaucasianAlbanian => "Caucasian_Albanian",
+			Script::Ahom => "Ahom",
+			Script::Arabic => "Arabic",
+			Script::ImperialAramaic => "Imperial_Aramaic",
+			Script::Armenian => "Armenian",
+			Script::Avestan => "Avestan",
+			Script::Balinese => "Balinese",
+			Script::Bamum => "Bamum",
+			Script::BassaVah => "Bassa_Vah",
+			Script::Batak => "Batak",
+			Script::Bengali => "Bengali",
+			Script::Bhaiksuki => "Bhaiksuki",
+			Script::Bopomofo => "Bopomofo",
+			Script::Brahmi => "Brahmi",
+			Script::Braille => "Braille",
+			Script::Buginese => "Buginese",
+			Script::Buhid => "Buhid",
+			Script::Chakma => "Chakma",
+			Script::CanadianAboriginal => "Canadian_Aboriginal",
+			Script::Carian => "Carian",
+			Script::Cham => "Cham",
+			Script::Cherokee => "Cherokee",
+			Script::Chorasmian => "Chorasmian",
+			Script::Coptic => "Coptic",
+			Script::CyproMinoan => "Cypro_Minoan",
+			Script::Cypriot => "Cypriot",
+			Script::Cyrillic => "Cyrillic",
+			Script::Devanagari => "Devanagari",
+			Script::DivesAkuru => "Dives_Akuru",
+			Script::Dogra => "Dogra",
+			Script::Deseret => "Deseret",
+			Script::Duployan => "Duployan",
+			Script::EgyptianHieroglyphs => "Egyptian_Hieroglyphs",
+			Script::Elbasan => "Elbasan",
+			Script::Elymaic => "Elymaic",
+			Script::Ethiopic => "Ethiopic",
+			Script::Georgian => "Georgian",
+			Script::Glagolitic => "Glagolitic",
+			Script::GunjalaGondi => "Gunjala_Gondi",
+			Script::MasaramGondi => "Masaram_Gondi",
+			Script::Gothic => "Gothic",
+			Script::Grantha => "Grantha",
+			Script::Greek => "Greek",
+			Script::Gujarati => "Gujarati",
+			Script::Gurmukhi => "Gurmukhi",
+			Script::Hangul => "Hangul",
+			Script::Han => "Han",
+			Script::Hanunoo => "Hanunoo",
+			Script::Hatran => "Hatran",
+			Script::Hebrew => "Hebrew",
+			Script::Hiragana => "Hiragana",
+			Script::AnatolianHieroglyphs => "Anatolian_Hieroglyphs",
+			Script::PahawhHmong => "Pahawh_Hmong",
+			Script::NyiakengPuachueHmong => "Nyiakeng_Puachue_Hmong",
+			Script::KatakanaOrHiragana => "Katakana_Or_Hiragana",
+			Script::OldHungarian => "Old_Hungarian",
+			Script::OldItalic => "Old_Italic",
+			Script::Javanese => "Javanese",
+			Script::KayahLi => "Kayah_Li",
+			Script::Katakana => "Katakana",
+			Script::Kharoshthi => "Kharoshthi",
+			Script::Khmer => "Khmer",
+			Script::Khojki => "Khojki",
+			Script::KhitanSmallScript => "Khitan_Small_Script",
+			Script::Kannada => "Kannada",
+			Script::Kaithi => "Kaithi",
+			Script::TaiTham => "Tai_Tham",
+			Script::Lao => "Lao",
+			Script::Latin => "Latin",
+			Script::Lepcha => "Lepcha",
+			Script::Limbu => "Limbu",
+			Script::LinearA => "Linear_A",
+			Script::LinearB => "Linear_B",
+			Script::Lisu => "Lisu",
+			Script::Lycian => "Lycian",
+			Script::Lydian => "Lydian",
+			Script::Mahajani => "Mahajani",
+			Script::Makasar => "Makasar",
+			Script::Mandaic => "Mandaic",
+			Script::Manichaean => "Manichaean",
+			Script::Marchen => "Marchen",
+			Script::Medefaidrin => "Medefaidrin",
+			Script::MendeKikakui => "Mende_Kikakui",
+			Script::MeroiticCursive => "Meroitic_Cursive",
+			Script::MeroiticHieroglyphs => "Meroitic_Hieroglyphs",
+			Script::Malayalam => "Malayalam",
+			Script::Modi => "Modi",
+			Script::Mongolian => "Mongolian",
+			Script::Mro => "Mro",
+			Script::MeeteiMayek => "Meetei_Mayek",
+			Script::Multani => "Multani",
+			Script::Myanmar => "Myanmar",
+			Script::Nandinagari => "Nandinagari",
+			Script::OldNorthArabian => "Old_North_Arabian",
+			Script::Nabataean => "Nabataean",
+			Script::Newa => "Newa",
+			Script::Nko => "Nko",
+			Script::Nushu => "Nushu",
+			Script::Ogham => "Ogham",
+			Script::OlChiki => "Ol_Chiki",
+			Script::OldTurkic => "Old_Turkic",
+			Script::Oriya => "Oriya",
+			Script::Osage => "Osage",
+			Script::Osmanya => "Osmanya",
+			Script::OldUyghur => "Old_Uyghur",
+			Script::Palmyrene => "Palmyrene",
+			Script::PauCinHau => "Pau_Cin_Hau",
+			Script::OldPermic => "Old_Permic",
+			Script::PhagsPa => "Phags_Pa",
+			Script::InscriptionalPahlavi => "Inscriptional_Pahlavi",
+			Script::PsalterPahlavi => "Psalter_Pahlavi",
+			Script::Phoenician => "Phoenician",
+			Script::Miao => "Miao",
+			Script::InscriptionalParthian => "Inscriptional_Parthian",
+			Script::Rejang => "Rejang",
+			Script::HanifiRohingya => "Hanifi_Rohingya",
+			Script::Runic => "Runic",
+			Script::Samaritan => "Samaritan",
+			Script::OldSouthArabian => "Old_South_Arabian",
+			Script::Saurashtra => "Saurashtra",
+			Script::SignWriting => "SignWriting",
+			Script::Shavian => "Shavian",
+			Script::Sharada => "Sharada",
+			Script::Siddham => "Siddham",
+			Script::Khudawadi => "Khudawadi",
+			Script::Sinhala => "Sinhala",
+			Script::Sogdian => "Sogdian",
+			Script::OldSogdian => "Old_Sogdian",
+			Script::SoraSompeng => "Sora_Sompeng",
+			Script::Soyombo => "Soyombo",
+			Script::Sundanese => "Sundanese",
+			Script::SylotiNagri => "Syloti_Nagri",
+			Script::Syriac => "Syriac",
+			Script::Tagbanwa => "Tagbanwa",
+			Script::Takri => "Takri",
+			Script::TaiLe => "Tai_Le",
+			Script::NewTaiLue => "New_Tai_Lue",
+			Script::Tamil => "Tamil",
+			Script::Tangut => "Tangut",
+			Script::TaiViet => "Tai_Viet",
+			Script::Telugu => "Telugu",
+			Script::Tifinagh => "Tifinagh",
+			Script::Tagalog => "Tagalog",
+			Script::Thaana => "Thaana",
+			Script::Thai => "Thai",
+			Script::Tibetan => "Tibetan",
+			Script::Tirhuta => "Tirhuta",
+			Script::Tangsa => "Tangsa",
+			Script::Toto => "Toto",
+			Script::Ugaritic => "Ugaritic",
+			Script::Vai => "Vai",
+			Script::Vithkuqi => "Vithkuqi",
+			Script::WarangCiti => "Warang_Citi",
+			Script::Wancho => "Wancho",
+			Script::OldPersian => "Old_Persian",
+			Script::Cuneiform => "Cuneiform",
+			Script::Yezidi => "Yezidi",
+			Script::Yi => "Yi",
+			Script::ZanabazarSquare => "Zanabazar_Square",
+			Script::Inherited => "Inherited",
+			Script::Common => "Common",
+			Script::Unknown => "Unknown",
+		};
+		write!(f, "{}", output)
+	}
+}
+
+/// [`PropertyKey`] for [`Script`] values in a [`RangeTable`].
+#[derive(Clone, PartialEq)]
+pub struct ScriptProperty;
+
+impl PropertyKey for ScriptProperty {
+	type Value = Script;
+}
+
+/// Returns the set of scripts for a codepoint, combining its [`Script`] from
+/// `Scripts.txt` with any extensions from `ScriptExtensions.txt`.
+///
+/// The result is sorted and has no duplicates. Codepoints not listed in
+/// `Scripts.txt` have the [`Script::Unknown`] script.
+///
+/// ```
+/// # use ucd_parser::{script_set, Script};
+/// assert_eq!(script_set(0x41), vec![Script::Latin]);
+/// ```
+pub fn script_set(code: u32) -> Vec<Script> {
+	static EXTENSIONS: Lazy<Vec<(u32, u32, Vec<Script>)>> = Lazy::new(|| {
+		let input = Input::get(InputFile::ScriptExtensions);
+		let lines = input.lines().map(|line| {
+			let (sta, end, value) = parse_range_line(line).unwrap();
+			let scripts = value.split_whitespace().map(|x| {
+				Script::parse_short(x)
+					.unwrap_or_else(|| panic!("invalid script `{}` -- in `{}`", x, line))
+			});
+			(sta, end, scripts.collect::<Vec<_>>())
+		});
+		let mut list = lines.collect::<Vec<_>>();
+		list.sort_by_key(|x| x.0);
+		list
+	});
+
+	let scripts = script_ranges();
+	let index = scripts.partition_point(|x| x.1 < code);
+	let script = scripts
+		.get(index)
+		.filter(|x| x.0 <= code)
+		.map(|x| x.2)
+		.unwrap_or(Script::Unknown);
+
+	let mut output = vec![script];
+	let index = EXTENSIONS.partition_point(|x| x.1 < code);
+	if let Some((_, _, extensions)) = EXTENSIONS.get(index).filter(|x| x.0 <= code) {
+		output.extend(extensions.iter().copied());
+	}
+	output.sort();
+	output.dedup();
+	output
+}
+
+/// Ranges from `Scripts.txt` sorted by codepoint.
+fn script_ranges() -> &'static [(u32, u32, Script)] {
+	static RANGES: Lazy<Vec<(u32, u32, Script)>> = Lazy::new(|| {
+		let input = Input::get(InputFile::Scripts);
+		let lines = input.lines().map(|line| {
+			let (sta, end, value) = parse_range_line(line).unwrap();
+			let script = Script::parse(value)
+				.unwrap_or_else(|| panic!("invalid script `{}` -- in `{}`", value, line));
+			(sta, end, script)
+		});
+		let mut ranges = lines.collect::<Vec<_>>();
+		ranges.sort_by_key(|x| x.0);
+		ranges
+	});
+	&RANGES
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn supports_to_string() {
+		fn check(input: Script, expected: &'static str) {
+			assert_eq!(input.to_string(), expected);
+			assert_eq!(Script::parse(input.to_string()).expect(expected), input);
+		}
+
+		check(Script::Common, "Common");
+		check(Script::Inherited, "Inherited");
+		check(Script::Latin, "Latin");
+		check(Script::OldItalic, "Old_Italic");
+		check(Script::SignWriting, "SignWriting");
+		check(Script::Unknown, "Unknown");
+	}
+
+	#[test]
+	fn parses_from_short_name() {
+		fn check(input: &'static str, expected: Script) {
+			assert_eq!(Script::parse_short(input), Some(expected));
+			assert_eq!(expected.short_name(), input);
+		}
+
+		check("Zyyy", Script::Common);
+		check("Zinh", Script::Inherited);
+		check("Latn", Script::Latin);
+		check("Ital", Script::OldItalic);
+		check("Zzzz", Script::Unknown);
+
+		assert_eq!(Script::parse_short("Qaai"), Some(Script::Inherited));
+	}
+
+	#[test]
+	fn parse_from_invalid_string_is_none() {
+		assert_eq!(Script::parse("xx"), None);
+		assert_eq!(Script::parse("Latn"), None);
+		assert_eq!(Script::parse_short("Latin"), None);
+	}
+
+	#[test]
+	fn parse_or_unknown_preserves_unrecognized_value() {
+		let value = Script::parse_or_unknown("Latin");
+		assert_eq!(value, OrUnknown::Known(Script::Latin));
+		assert_eq!(value.to_string(), "Latin");
+
+		let value = Script::parse_or_unknown("Future_Script");
+		assert_eq!(value, OrUnknown::Unknown("Future_Script".to_string()));
+		assert_eq!(value.to_string(), "Future_Script");
+		assert_eq!(value.known(), None);
+	}
+
+	#[test]
+	fn returns_script_set_for_codepoint() {
+		assert_eq!(script_set(0x0041), vec![Script::Latin]);
+		assert_eq!(script_set(0x0020), vec![Script::Common]);
+		assert_eq!(script_set(0x0378), vec![Script::Unknown]);
+
+		// IDEOGRAPHIC COMMA
+		assert_eq!(
+			script_set(0x3001),
+			vec![
+				Script::Bopomofo,
+				Script::Hangul,
+				Script::Han,
+				Script::Hiragana,
+				Script::Katakana,
+				Script::Yi,
+				Script::Common,
+			]
+		);
+
+		// DEVANAGARI DANDA
+		let set = script_set(0x0964);
+		assert!(set.contains(&Script::Common));
+		assert!(set.contains(&Script::Devanagari));
+		assert!(set.contains(&Script::Bengali));
+		assert!(set.windows(2).all(|x| x[0] < x[1]));
+	}
+
+	#[test]
+	fn loads_table_from_ucd() {
+		let table = Script::load();
+		assert_eq!(table.lookup(0x0020, ScriptProperty), Some(Script::Common));
+		assert_eq!(table.lookup(0x0041, ScriptProperty), Some(Script::Latin));
+		assert_eq!(table.lookup(0x0378, ScriptProperty), Some(Script::Unknown));
+		assert_eq!(table.lookup(0x3001, ScriptProperty), Some(Script::Common));
+		assert_eq!(table.lookup(0x4E00, ScriptProperty), Some(Script::Han));
+		assert_eq!(
+			table.lookup(0x10FFFF, ScriptProperty),
+			Some(Script::Unknown)
+		);
+	}
+}
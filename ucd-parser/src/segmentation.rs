@@ -0,0 +1,76 @@
+use ucd_property_ranges::RangeTable;
+
+use crate::{GraphemeBreak, SentenceBreak, WordBreak};
+
+/// Build a [`RangeTable`] with the [`GraphemeBreakProperty`], the
+/// [`WordBreakProperty`], and the [`SentenceBreakProperty`] for the entire
+/// codespace, as used by the Unicode Text Segmentation algorithms.
+///
+/// Having all three properties in the same table aligns their ranges, and
+/// the table is coalesced so that each range has a unique combination of
+/// values.
+///
+/// [`GraphemeBreakProperty`]: crate::GraphemeBreakProperty
+/// [`WordBreakProperty`]: crate::WordBreakProperty
+/// [`SentenceBreakProperty`]: crate::SentenceBreakProperty
+///
+/// ```
+/// # use ucd_parser::*;
+/// let table = build_segmentation_table();
+/// let row = table.find(0x0D).unwrap();
+/// assert_eq!(row.get(GraphemeBreakProperty), Some(GraphemeBreak::CR));
+/// assert_eq!(row.get(WordBreakProperty), Some(WordBreak::CR));
+/// assert_eq!(row.get(SentenceBreakProperty), Some(SentenceBreak::CR));
+/// ```
+pub fn build_segmentation_table() -> RangeTable {
+	let mut table = RangeTable::new();
+	GraphemeBreak::set_ranges(&mut table);
+	WordBreak::set_ranges(&mut table);
+	SentenceBreak::set_ranges(&mut table);
+	table.coalesce();
+	table
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{GraphemeBreakProperty, SentenceBreakProperty, WordBreakProperty};
+
+	#[test]
+	fn builds_combined_table() {
+		let table = build_segmentation_table();
+		let values = |code: u32| {
+			let row = table.find(code).unwrap();
+			(
+				row.get(GraphemeBreakProperty).unwrap(),
+				row.get(WordBreakProperty).unwrap(),
+				row.get(SentenceBreakProperty).unwrap(),
+			)
+		};
+
+		assert_eq!(
+			values(0x000D),
+			(GraphemeBreak::CR, WordBreak::CR, SentenceBreak::CR)
+		);
+		assert_eq!(
+			values(0x0301),
+			(
+				GraphemeBreak::Extend,
+				WordBreak::Extend,
+				SentenceBreak::Extend
+			)
+		);
+		assert_eq!(
+			values(0x10FFFF),
+			(GraphemeBreak::Other, WordBreak::Other, SentenceBreak::Other)
+		);
+
+		// the table covers the codespace without gaps and is coalesced
+		assert_eq!(table.rows().next().unwrap().first, 0);
+		assert_eq!(table.rows().next_back().unwrap().last, 0x10FFFF);
+		for (prev, next) in table.rows().zip(table.rows().skip(1)) {
+			assert_eq!(prev.last + 1, next.first);
+			assert!(!prev.has_same_properties(&next));
+		}
+	}
+}
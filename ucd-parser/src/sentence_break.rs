@@ -0,0 +1,185 @@
+use ucd_property_ranges::{PropertyKey, RangeTable};
+
+use crate::{
+	input::{Input, InputFile},
+	parse::{parse_range_line, OrUnknown},
+};
+
+/// Values for the `Sentence_Break` property used by the sentence boundary
+/// rules in Unicode Text Segmentation.
+///
+/// See https://www.unicode.org/reports/tr29/#Sentence_Boundaries
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SentenceBreak {
+	/// Default for codepoints not listed in the data file.
+	Other,
+	/// `CR` carriage return.
+	CR,
+	/// `LF` line feed.
+	LF,
+	/// `Extend`: combining marks and other grapheme extenders.
+	Extend,
+	/// `Sep`: next line and line/paragraph separators.
+	Sep,
+	/// `Format` characters.
+	Format,
+	/// `Sp` whitespace.
+	Sp,
+	/// `Lower` lowercase letters.
+	Lower,
+	/// `Upper` uppercase and titlecase letters.
+	Upper,
+	/// `OLetter` other alphabetic characters.
+	OLetter,
+	/// `Numeric` digits.
+	Numeric,
+	/// `ATerm`: full stop and similar.
+	ATerm,
+	/// `SContinue`: comma, colon, and similar.
+	SContinue,
+	/// `STerm`: sentence terminal punctuation.
+	STerm,
+	/// `Close`: closing punctuation and quotation marks.
+	Close,
+}
+
+impl SentenceBreak {
+	pub fn parse<T: AsRef<str>>(input: T) -> Option<Self> {
+		let value = match input.as_ref() {
+			"Other" => SentenceBreak::Other,
+			"CR" => SentenceBreak::CR,
+			"LF" => SentenceBreak::LF,
+			"Extend" => SentenceBreak::Extend,
+			"Sep" => SentenceBreak::Sep,
+			"Format" => SentenceBreak::Format,
+			"Sp" => SentenceBreak::Sp,
+			"Lower" => SentenceBreak::Lower,
+			"Upper" => SentenceBreak::Upper,
+			"OLetter" => SentenceBreak::OLetter,
+			"Numeric" => SentenceBreak::Numeric,
+			"ATerm" => SentenceBreak::ATerm,
+			"SContinue" => SentenceBreak::SContinue,
+			"STerm" => SentenceBreak::STerm,
+			"Close" => SentenceBreak::Close,
+			_ => return None,
+		};
+		Some(value)
+	}
+
+	/// Same as [`SentenceBreak::parse`], but returns [`OrUnknown::Unknown`] with the
+	/// input text for unrecognized values instead of [`None`].
+	pub fn parse_or_unknown<T: AsRef<str>>(input: T) -> OrUnknown<Self> {
+		OrUnknown::parse_with(input.as_ref(), |x| Self::parse(x))
+	}
+
+	/// Build a [`RangeTable`] with the [`SentenceBreakProperty`] for the
+	/// entire codespace from `SentenceBreakProperty.txt`.
+	///
+	/// Codepoints not listed in the file are set to [`SentenceBreak::Other`],
+	/// which is the `@missing` default declared by the file.
+	///
+	/// Note that the `Format` and `Extend` values are taken as-is from the
+	/// explicit entries in the file, which already account for the general
+	/// category of those characters.
+	pub fn build_range_table() -> RangeTable {
+		let mut table = RangeTable::new();
+		Self::set_ranges(&mut table);
+		table
+	}
+
+	/// Set the [`SentenceBreakProperty`] for the entire codespace in an
+	/// existing table. See [`SentenceBreak::build_range_table`].
+	pub fn set_ranges(table: &mut RangeTable) {
+		table.set_range(0..=0x10FFFF, SentenceBreakProperty, SentenceBreak::Other);
+
+		let input = Input::get(InputFile::SentenceBreakProperty);
+		for line in input.lines() {
+			let (sta, end, value) = parse_range_line(line).unwrap();
+			let value = SentenceBreak::parse(value)
+				.unwrap_or_else(|| panic!("invalid sentence break `{}` -- in `{}`", value, line));
+			table.set_range(sta..=end, SentenceBreakProperty, value);
+		}
+	}
+}
+
+impl std::fmt::Display for SentenceBreak {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let output = match self {
+			SentenceBreak::Other => "Other",
+			SentenceBreak::CR => "CR",
+			SentenceBreak::LF => "LF",
+			SentenceBreak::Extend => "Extend",
+			SentenceBreak::Sep => "Sep",
+			SentenceBreak::Format => "Format",
+			SentenceBreak::Sp => "Sp",
+			SentenceBreak::Lower => "Lower",
+			SentenceBreak::Upper => "Upper",
+			SentenceBreak::OLetter => "OLetter",
+			SentenceBreak::Numeric => "Numeric",
+			SentenceBreak::ATerm => "ATerm",
+			SentenceBreak::SContinue => "SContinue",
+			SentenceBreak::STerm => "STerm",
+			SentenceBreak::Close => "Close",
+		};
+		write!(f, "{}", output)
+	}
+}
+
+/// [`PropertyKey`] for [`SentenceBreak`] values in a [`RangeTable`].
+#[derive(Clone, PartialEq)]
+pub struct SentenceBreakProperty;
+
+impl PropertyKey for SentenceBreakProperty {
+	type Value = SentenceBreak;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn supports_to_string() {
+		fn check(input: SentenceBreak, expected: &'static str) {
+			assert_eq!(input.to_string(), expected);
+			assert_eq!(
+				SentenceBreak::parse(input.to_string()).expect(expected),
+				input
+			);
+		}
+
+		check(SentenceBreak::Other, "Other");
+		check(SentenceBreak::CR, "CR");
+		check(SentenceBreak::LF, "LF");
+		check(SentenceBreak::Extend, "Extend");
+		check(SentenceBreak::Sep, "Sep");
+		check(SentenceBreak::Format, "Format");
+		check(SentenceBreak::Sp, "Sp");
+		check(SentenceBreak::Lower, "Lower");
+		check(SentenceBreak::Upper, "Upper");
+		check(SentenceBreak::OLetter, "OLetter");
+		check(SentenceBreak::Numeric, "Numeric");
+		check(SentenceBreak::ATerm, "ATerm");
+		check(SentenceBreak::SContinue, "SContinue");
+		check(SentenceBreak::STerm, "STerm");
+		check(SentenceBreak::Close, "Close");
+	}
+
+	#[test]
+	fn parse_from_invalid_string_is_none() {
+		assert_eq!(SentenceBreak::parse("xx"), None);
+	}
+
+	#[test]
+	fn builds_table_from_ucd() {
+		let table = SentenceBreak::build_range_table();
+		let value = |code: u32| table.find(code).and_then(|x| x.get(SentenceBreakProperty));
+
+		// unlisted codepoints use the default
+		assert_eq!(value(0x0024), Some(SentenceBreak::Other));
+		assert_eq!(value(0x10FFFF), Some(SentenceBreak::Other));
+
+		assert_eq!(value(0x0021), Some(SentenceBreak::STerm));
+		assert_eq!(value(0x00AD), Some(SentenceBreak::Format));
+		assert_eq!(value(0x200D), Some(SentenceBreak::Extend));
+	}
+}
@@ -0,0 +1,305 @@
+use once_cell::sync::Lazy;
+
+use crate::{
+	input::{Input, InputFile},
+	parse::{parse_code, parse_code_sequence},
+};
+
+/// Conditional or multi-codepoint case mapping from `SpecialCasing.txt`.
+///
+/// These complement the simple mappings in `UnicodeData.txt`. Mappings with
+/// [`conditions`](SpecialCasing::conditions) only apply in the given
+/// context or language.
+///
+/// ```
+/// # use ucd_parser::{SpecialCasing, CasingCondition};
+/// let entry = SpecialCasing::parse("03A3; 03C2; 03A3; 03A3; Final_Sigma;").unwrap();
+/// assert_eq!(entry.code, 0x3A3);
+/// assert_eq!(entry.lower, vec![0x3C2]);
+/// assert_eq!(entry.conditions, vec![CasingCondition::FinalSigma]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpecialCasing {
+	/// Codepoint being mapped.
+	pub code: u32,
+
+	/// Lowercase mapping. Can be empty.
+	pub lower: Vec<u32>,
+
+	/// Titlecase mapping. Can be empty.
+	pub title: Vec<u32>,
+
+	/// Uppercase mapping. Can be empty.
+	pub upper: Vec<u32>,
+
+	/// Conditions for the mapping to apply. Empty for unconditional mappings.
+	pub conditions: Vec<CasingCondition>,
+}
+
+impl SpecialCasing {
+	/// List of mappings from the UCD data in file order. Lazy-loaded from
+	/// `SpecialCasing.txt`.
+	pub fn list() -> &'static [SpecialCasing] {
+		static LIST: Lazy<Box<[SpecialCasing]>> = Lazy::new(|| {
+			let input = Input::get(InputFile::SpecialCasing);
+			let lines = input.lines();
+			let list = lines.map(|x| SpecialCasing::parse(x).unwrap());
+			let list = list.collect::<Vec<_>>();
+			list.into_boxed_slice()
+		});
+		&LIST
+	}
+
+	pub fn parse(input: &str) -> Result<Self, String> {
+		let error = |err: String| format!("special casing: {} -- in `{}`", err, input);
+
+		// lines end with a `;` so there is an empty trailing field, and the
+		// conditions field is optional
+		let fields = input.split(';').map(|x| x.trim()).collect::<Vec<_>>();
+		let (fields, trailing) = fields.split_at(fields.len() - 1);
+		if !(fields.len() == 4 || fields.len() == 5) || !trailing[0].is_empty() {
+			return Err(error(format!(
+				"invalid row format (expected 4 or 5 fields, found {})",
+				fields.len()
+			)));
+		}
+
+		let mapping = |input: &str| parse_code_sequence(input).map_err(error);
+
+		let code = parse_code(fields[0]).map_err(error)?;
+		let lower = mapping(fields[1])?;
+		let title = mapping(fields[2])?;
+		let upper = mapping(fields[3])?;
+		let conditions = match fields.get(4) {
+			Some(conditions) => CasingCondition::parse_list(conditions).map_err(error)?,
+			None => Vec::new(),
+		};
+		Ok(SpecialCasing {
+			code,
+			lower,
+			title,
+			upper,
+			conditions,
+		})
+	}
+}
+
+impl std::fmt::Display for SpecialCasing {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		fn write_mapping(f: &mut std::fmt::Formatter<'_>, mapping: &[u32]) -> std::fmt::Result {
+			for (index, code) in mapping.iter().enumerate() {
+				if index > 0 {
+					write!(f, " ")?;
+				}
+				write!(f, "{:04X}", code)?;
+			}
+			write!(f, "; ")
+		}
+
+		write!(f, "{:04X}; ", self.code)?;
+		write_mapping(f, &self.lower)?;
+		write_mapping(f, &self.title)?;
+		write_mapping(f, &self.upper)?;
+		if !self.conditions.is_empty() {
+			for (index, condition) in self.conditions.iter().enumerate() {
+				if index > 0 {
+					write!(f, " ")?;
+				}
+				write!(f, "{}", condition)?;
+			}
+			write!(f, "; ")?;
+		}
+		Ok(())
+	}
+}
+
+/// Condition for a [`SpecialCasing`] mapping to apply.
+///
+/// See the "Casing Contexts" and "Case Mappings" sections in the Unicode
+/// Standard, chapter 3.13.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CasingCondition {
+	/// `Final_Sigma` the character is preceded by a cased letter and is not
+	/// followed by one (ignoring case-ignorable characters).
+	FinalSigma,
+	/// `After_Soft_Dotted` there is a soft-dotted character before, with no
+	/// intervening combining character of class 0 or 230.
+	AfterSoftDotted,
+	/// `More_Above` the character is followed by a combining character of
+	/// class 230, with no intervening class 0 or 230.
+	MoreAbove,
+	/// `Before_Dot` the character is followed by U+0307 COMBINING DOT ABOVE,
+	/// with no intervening class 0 or 230.
+	BeforeDot,
+	/// `After_I` there is an uppercase I before, with no intervening
+	/// combining character of class 0 or 230.
+	AfterI,
+	/// `Not_X` negation of one of the context conditions.
+	Not(Box<CasingCondition>),
+	/// Language tag (e.g. `lt`, `tr` or `az`) for language-sensitive
+	/// mappings.
+	Language(String),
+}
+
+impl CasingCondition {
+	/// Parse a single condition token.
+	///
+	/// Any lowercase alphabetic token with two or three letters is parsed as
+	/// a [`CasingCondition::Language`].
+	pub fn parse<T: AsRef<str>>(input: T) -> Option<Self> {
+		let input = input.as_ref();
+		let value = match input {
+			"Final_Sigma" => CasingCondition::FinalSigma,
+			"After_Soft_Dotted" => CasingCondition::AfterSoftDotted,
+			"More_Above" => CasingCondition::MoreAbove,
+			"Before_Dot" => CasingCondition::BeforeDot,
+			"After_I" => CasingCondition::AfterI,
+			_ => {
+				if let Some(negated) = input.strip_prefix("Not_") {
+					let negated = Self::parse(negated)?;
+					if matches!(
+						negated,
+						CasingCondition::Language(..) | CasingCondition::Not(..)
+					) {
+						return None;
+					}
+					CasingCondition::Not(Box::new(negated))
+				} else if (2..=3).contains(&input.len())
+					&& input.chars().all(|x| x.is_ascii_lowercase())
+				{
+					CasingCondition::Language(input.to_string())
+				} else {
+					return None;
+				}
+			}
+		};
+		Some(value)
+	}
+
+	/// Parse a space-separated list of conditions, as used in the conditions
+	/// field of `SpecialCasing.txt`.
+	pub fn parse_list<T: AsRef<str>>(input: T) -> Result<Vec<Self>, String> {
+		input
+			.as_ref()
+			.split_whitespace()
+			.map(|x| Self::parse(x).ok_or_else(|| format!("`{}` is not a valid condition", x)))
+			.collect()
+	}
+}
+
+impl std::fmt::Display for CasingCondition {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let output = match self {
+			CasingCondition::FinalSigma => "Final_Sigma",
+			CasingCondition::AfterSoftDotted => "After_Soft_Dotted",
+			CasingCondition::MoreAbove => "More_Above",
+			CasingCondition::BeforeDot => "Before_Dot",
+			CasingCondition::AfterI => "After_I",
+			CasingCondition::Not(condition) => return write!(f, "Not_{}", condition),
+			CasingCondition::Language(language) => language,
+		};
+		write!(f, "{}", output)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn condition_supports_to_string() {
+		fn check(input: CasingCondition, expected: &'static str) {
+			assert_eq!(input.to_string(), expected);
+			assert_eq!(
+				CasingCondition::parse(input.to_string()).expect(expected),
+				input
+			);
+		}
+
+		check(CasingCondition::FinalSigma, "Final_Sigma");
+		check(CasingCondition::AfterSoftDotted, "After_Soft_Dotted");
+		check(CasingCondition::MoreAbove, "More_Above");
+		check(CasingCondition::BeforeDot, "Before_Dot");
+		check(CasingCondition::AfterI, "After_I");
+		check(
+			CasingCondition::Not(Box::new(CasingCondition::BeforeDot)),
+			"Not_Before_Dot",
+		);
+		check(CasingCondition::Language("lt".to_string()), "lt");
+	}
+
+	#[test]
+	fn condition_parse_from_invalid_string_is_none() {
+		assert_eq!(CasingCondition::parse("Final_sigma"), None);
+		assert_eq!(CasingCondition::parse("Not_lt"), None);
+		assert_eq!(CasingCondition::parse("Not_Not_After_I"), None);
+		assert_eq!(CasingCondition::parse("LT"), None);
+		assert_eq!(CasingCondition::parse("x"), None);
+		assert_eq!(CasingCondition::parse(""), None);
+	}
+
+	#[test]
+	fn parses_condition_list() {
+		let list = CasingCondition::parse_list("tr Not_Before_Dot").unwrap();
+		assert_eq!(
+			list,
+			vec![
+				CasingCondition::Language("tr".to_string()),
+				CasingCondition::Not(Box::new(CasingCondition::BeforeDot)),
+			]
+		);
+
+		let err = CasingCondition::parse_list("lt Some_Context").unwrap_err();
+		assert_eq!(err, "`Some_Context` is not a valid condition");
+	}
+
+	#[test]
+	fn parses_from_string() {
+		let entry = SpecialCasing::parse("00DF; 00DF; 0053 0073; 0053 0053;").unwrap();
+		assert_eq!(entry.code, 0xDF);
+		assert_eq!(entry.lower, vec![0xDF]);
+		assert_eq!(entry.title, vec![0x53, 0x73]);
+		assert_eq!(entry.upper, vec![0x53, 0x53]);
+		assert!(entry.conditions.is_empty());
+		assert_eq!(entry.to_string(), "00DF; 00DF; 0053 0073; 0053 0053; ");
+
+		let entry = SpecialCasing::parse("0307; ; 0307; 0307; lt After_Soft_Dotted;").unwrap();
+		assert!(entry.lower.is_empty());
+		assert_eq!(
+			entry.conditions,
+			vec![
+				CasingCondition::Language("lt".to_string()),
+				CasingCondition::AfterSoftDotted,
+			]
+		);
+		assert_eq!(
+			entry.to_string(),
+			"0307; ; 0307; 0307; lt After_Soft_Dotted; "
+		);
+	}
+
+	#[test]
+	fn parse_invalid_input_returns_error() {
+		let err = SpecialCasing::parse("0049; 0131; 0049; 0049; tr Bad;").unwrap_err();
+		assert!(err.contains("`Bad` is not a valid condition"));
+		assert!(err.contains("-- in `0049; 0131; 0049; 0049; tr Bad;`"));
+
+		let err = SpecialCasing::parse("0049; 0131; 0049;").unwrap_err();
+		assert!(err.contains("expected 4 or 5 fields, found 3"));
+
+		let err = SpecialCasing::parse("0049; 0131; 0049; 0049").unwrap_err();
+		assert!(err.contains("expected 4 or 5 fields"));
+	}
+
+	#[test]
+	fn loads_from_ucd() {
+		let list = SpecialCasing::list();
+		assert_eq!(list.len(), 119);
+
+		let turkic = list.iter().filter(|x| {
+			x.conditions
+				.contains(&CasingCondition::Language("tr".to_string()))
+		});
+		assert_eq!(turkic.count(), 4);
+	}
+}
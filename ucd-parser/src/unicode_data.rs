@@ -1,7 +1,14 @@
+use std::{
+	borrow::Cow,
+	collections::{HashMap, HashSet},
+};
+
 use once_cell::sync::Lazy;
+use ucd_property_ranges::{PropertyKey, RangeTable};
 
 use super::data::*;
 use super::input::*;
+use super::is_default_ignorable;
 use super::parse::*;
 
 /// Represents the data for one encoded character in the Unicode Standard. Every
@@ -19,7 +26,7 @@ use super::parse::*;
 ///     println!("{}: {} ({})", row.code, row.name, row.category);
 /// }
 /// ```
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct UnicodeData<'a> {
 	/// Codepoint value. Note that for codepoint ranges this can represent the
 	/// start or end of a range of codepoints.
@@ -79,14 +86,131 @@ impl<'a> UnicodeData<'a> {
 	pub fn list() -> &'static [UnicodeData<'static>] {
 		static ROWS: Lazy<Box<[UnicodeData]>> = Lazy::new(|| {
 			let input = Input::get(InputFile::UnicodeData);
-			let lines = input.lines();
-			let rows = lines.map(|x| UnicodeData::parse(x).unwrap());
+			// the file has no comments or blank lines, so lines map directly
+			let lines = input.lines().enumerate();
+			let rows = lines.map(|(n, x)| UnicodeData::parse_at(n + 1, x).unwrap());
 			let rows = rows.collect::<Vec<_>>();
 			rows.into_boxed_slice()
 		});
 		&ROWS
 	}
 
+	/// Find the data row for a codepoint in the UCD data.
+	///
+	/// Codepoints inside a `First>`/`Last>` range resolve to the row for the
+	/// start of the range. Returns [`None`] for unassigned codepoints.
+	pub fn find(code: u32) -> Option<&'static UnicodeData<'static>> {
+		find_row(Self::list(), code)
+	}
+
+	/// Return a copy of the row that owns its string fields, so it doesn't
+	/// borrow from the source text.
+	pub fn to_owned(&self) -> UnicodeDataOwned {
+		UnicodeDataOwned {
+			code: self.code,
+			name: self.name.to_string(),
+			category: self.category,
+			combining_class: self.combining_class,
+			bidi: self.bidi,
+			decomposition: self.decomposition.clone(),
+			decimal_value: self.decimal_value,
+			digit_value: self.digit_value,
+			numeric_value: self.numeric_value,
+			mirrored: self.mirrored,
+			unicode_old_name: self.unicode_old_name.to_string(),
+			iso_10646_comment: self.iso_10646_comment.to_string(),
+			uppercase_mapping: self.uppercase_mapping,
+			lowercase_mapping: self.lowercase_mapping,
+			titlecase_mapping: self.titlecase_mapping,
+		}
+	}
+
+	/// Check the consistency between the numeric fields of the row:
+	///
+	/// - a decimal value implies a digit value with the same value;
+	/// - a digit value implies a numeric value with the same integer value;
+	/// - a decimal value implies the `Nd` category, and vice versa.
+	///
+	/// Returns an error describing the first inconsistency.
+	pub fn validate(&self) -> Result<(), String> {
+		let error = |msg: String| Err(format!("U+{:04X}: {}", self.code, msg));
+		if let DecimalValue::Some(decimal) = self.decimal_value {
+			if self.digit_value != DigitValue::Some(decimal) {
+				return error(format!(
+					"decimal value {} does not match digit value {:?}",
+					decimal, self.digit_value
+				));
+			}
+			if self.category != Category::NumberDecimalDigit {
+				return error(format!(
+					"decimal value {} with category {}",
+					decimal, self.category
+				));
+			}
+		} else if self.category == Category::NumberDecimalDigit {
+			return error("category Nd without a decimal value".to_string());
+		}
+
+		if let DigitValue::Some(digit) = self.digit_value {
+			if self.numeric_value != NumericValue::Integer(digit as i64) {
+				return error(format!(
+					"digit value {} does not match numeric value {:?}",
+					digit, self.numeric_value
+				));
+			}
+		}
+		Ok(())
+	}
+
+	/// Returns the canonical combining class as a [`CombiningClass`].
+	pub fn combining_class_typed(&self) -> CombiningClass {
+		CombiningClass(self.combining_class)
+	}
+
+	/// Returns the row's codepoint as a [`char`].
+	///
+	/// Returns [`None`] for surrogates and for the `First>`/`Last>` rows
+	/// marking a range, since those don't describe a single character.
+	///
+	/// ```
+	/// # use ucd_parser::UnicodeData;
+	/// assert_eq!(UnicodeData::find(0x41).unwrap().as_char(), Some('A'));
+	/// ```
+	pub fn as_char(&self) -> Option<char> {
+		if self.is_range_first() || self.is_range_last() {
+			None
+		} else {
+			char::from_u32(self.code)
+		}
+	}
+
+	/// Returns true if this row marks the start of a range of codepoints
+	/// (e.g. `<CJK Ideograph, First>`).
+	pub fn is_range_first(&self) -> bool {
+		self.name.starts_with('<') && self.name.ends_with(", First>")
+	}
+
+	/// Returns true if this row marks the end of a range of codepoints
+	/// (e.g. `<CJK Ideograph, Last>`).
+	pub fn is_range_last(&self) -> bool {
+		self.name.starts_with('<') && self.name.ends_with(", Last>")
+	}
+
+	/// Returns the name for this row, or the range label for a `First>` row
+	/// (e.g. `<CJK Ideograph>` for `<CJK Ideograph, First>`).
+	pub fn display_name(&self) -> Cow<'a, str> {
+		match self.name.strip_suffix(", First>") {
+			Some(label) => Cow::Owned(format!("{}>", label)),
+			None => Cow::Borrowed(self.name),
+		}
+	}
+
+	/// Same as [`UnicodeData::parse`], but prefixes errors with the given
+	/// line number (e.g. `line 42: parsing unicode data: ...`).
+	pub fn parse_at(line: usize, input: &'a str) -> Result<Self, String> {
+		Self::parse(input).map_err(|err| format!("line {}: {}", line, err))
+	}
+
 	pub fn parse(input: &'a str) -> Result<Self, String> {
 		//----[ parsing helpers ]---------------------------------------------//
 
@@ -106,42 +230,18 @@ impl<'a> UnicodeData<'a> {
 
 		//----[ row parsing ]-------------------------------------------------//
 
-		if input.len() == 0 {
+		if input.is_empty() {
 			return Err(error_message("empty input"));
 		}
 
-		let mut fields = input.split(';');
-		let mut next_field = || {
-			fields
-				.next()
-				.ok_or_else(|| error_message("invalid row format"))
-		};
-
-		let code = next_field()?;
-		let name = next_field()?;
-		let category = next_field()?;
-		let combining_class = next_field()?;
-		let bidi = next_field()?;
-		let decomposition = next_field()?;
-		let decimal_value = next_field()?;
-		let digit_value = next_field()?;
-		let numeric_value = next_field()?;
-		let mirrored = next_field()?;
-		let unicode_old_name = next_field()?;
-		let iso_10646_comment = next_field()?;
-		let uppercase_mapping = next_field()?;
-		let lowercase_mapping = next_field()?;
-		let titlecase_mapping = next_field()?;
-
-		if fields.count() != 0 {
-			return Err(error_message("invalid row format"));
-		}
+		let [code, name, category, combining_class, bidi, decomposition, decimal_value, digit_value, numeric_value, mirrored, unicode_old_name, iso_10646_comment, uppercase_mapping, lowercase_mapping, titlecase_mapping] =
+			parse_fields::<15>(input).map_err(|err| error_message(&err))?;
 
 		//----[ field parsing ]-----------------------------------------------//
 
 		let code = parse_code(code).map_err(|err| error_message(&err))?;
 
-		if name.trim().len() == 0 {
+		if name.trim().is_empty() {
 			return Err(error_message("empty name"));
 		}
 
@@ -152,7 +252,7 @@ impl<'a> UnicodeData<'a> {
 
 		let bidi = Bidi::parse(bidi).ok_or_else(|| field_error("bidirectional category", bidi))?;
 
-		let decomposition = if decomposition.len() > 0 {
+		let decomposition = if !decomposition.is_empty() {
 			Some(Decomposition::parse(decomposition).map_err(|err| {
 				format!("{} ({})", field_error("decomposition", decomposition), err)
 			})?)
@@ -160,13 +260,13 @@ impl<'a> UnicodeData<'a> {
 			None
 		};
 
-		let decimal_value = if decimal_value.len() > 0 {
+		let decimal_value = if !decimal_value.is_empty() {
 			DecimalValue::Some(parse_u32("decimal value", decimal_value)?)
 		} else {
 			DecimalValue::None
 		};
 
-		let digit_value = if digit_value.len() > 0 {
+		let digit_value = if !digit_value.is_empty() {
 			DigitValue::Some(parse_u32("digit value", digit_value)?)
 		} else {
 			DigitValue::None
@@ -182,7 +282,7 @@ impl<'a> UnicodeData<'a> {
 		};
 
 		let parse_case = |name: &str, input: &str| -> Result<_, String> {
-			if input.len() > 0 {
+			if !input.is_empty() {
 				Ok(CaseMapping::Some(
 					u32::from_str_radix(input, 16).map_err(|_| field_error(name, input))?,
 				))
@@ -251,10 +351,547 @@ impl<'a> std::fmt::Display for UnicodeData<'a> {
 	}
 }
 
+#[cfg(feature = "serde")]
+impl<'a> UnicodeData<'a> {
+	/// Serialize the row as a JSON object with all fields.
+	///
+	/// Enum values use the abbreviations from the UCD files (e.g. `"Lu"` for
+	/// the category). Fields that are empty in the source are `null`, except
+	/// for the string fields which are kept as empty strings.
+	///
+	/// Requires the `serde` feature.
+	pub fn to_json(&self) -> String {
+		fn optional<T: Into<serde_json::Value>>(value: Option<T>) -> serde_json::Value {
+			value.map(|x| x.into()).unwrap_or(serde_json::Value::Null)
+		}
+
+		fn mapping(value: CaseMapping) -> serde_json::Value {
+			optional(value.code())
+		}
+
+		let decomposition = self.decomposition.as_ref().map(|x| {
+			let tag = x.tag.map(|tag| {
+				let tag = tag.to_string();
+				tag.trim_start_matches('<')
+					.trim_end_matches('>')
+					.to_string()
+			});
+			serde_json::json!({ "tag": tag, "codes": x.codes })
+		});
+		let decimal_value = match self.decimal_value {
+			DecimalValue::Some(value) => Some(value),
+			DecimalValue::None => None,
+		};
+		let digit_value = match self.digit_value {
+			DigitValue::Some(value) => Some(value),
+			DigitValue::None => None,
+		};
+		let numeric_value = match self.numeric_value {
+			NumericValue::None => None,
+			value => Some(value.to_string()),
+		};
+
+		let json = serde_json::json!({
+			"code": self.code,
+			"name": self.name,
+			"category": self.category.to_string(),
+			"combining_class": self.combining_class,
+			"bidi": self.bidi.to_string(),
+			"decomposition": decomposition,
+			"decimal_value": decimal_value,
+			"digit_value": digit_value,
+			"numeric_value": numeric_value,
+			"mirrored": self.mirrored.to_string(),
+			"unicode_old_name": self.unicode_old_name,
+			"iso_10646_comment": self.iso_10646_comment,
+			"uppercase_mapping": mapping(self.uppercase_mapping),
+			"lowercase_mapping": mapping(self.lowercase_mapping),
+			"titlecase_mapping": mapping(self.titlecase_mapping),
+		});
+		json.to_string()
+	}
+}
+
+/// Owned version of [`UnicodeData`], with [`String`] fields instead of
+/// borrowing from the source text.
+///
+/// See [`UnicodeData::to_owned`] and [`UnicodeDataOwned::as_ref`] for the
+/// conversion between both.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct UnicodeDataOwned {
+	pub code: u32,
+	pub name: String,
+	pub category: Category,
+	pub combining_class: u32,
+	pub bidi: Bidi,
+	pub decomposition: Option<Decomposition>,
+	pub decimal_value: DecimalValue,
+	pub digit_value: DigitValue,
+	pub numeric_value: NumericValue,
+	pub mirrored: Mirrored,
+	pub unicode_old_name: String,
+	pub iso_10646_comment: String,
+	pub uppercase_mapping: CaseMapping,
+	pub lowercase_mapping: CaseMapping,
+	pub titlecase_mapping: CaseMapping,
+}
+
+impl UnicodeDataOwned {
+	/// Return a [`UnicodeData`] borrowing the fields from this row.
+	pub fn as_ref(&self) -> UnicodeData<'_> {
+		UnicodeData {
+			code: self.code,
+			name: &self.name,
+			category: self.category,
+			combining_class: self.combining_class,
+			bidi: self.bidi,
+			decomposition: self.decomposition.clone(),
+			decimal_value: self.decimal_value,
+			digit_value: self.digit_value,
+			numeric_value: self.numeric_value,
+			mirrored: self.mirrored,
+			unicode_old_name: &self.unicode_old_name,
+			iso_10646_comment: &self.iso_10646_comment,
+			uppercase_mapping: self.uppercase_mapping,
+			lowercase_mapping: self.lowercase_mapping,
+			titlecase_mapping: self.titlecase_mapping,
+		}
+	}
+}
+
+impl std::fmt::Display for UnicodeDataOwned {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		self.as_ref().fmt(f)
+	}
+}
+
+/// Find the row for a codepoint in a sorted list of rows, resolving
+/// codepoints inside a `First>`/`Last>` range to the start of the range.
+fn find_row<'r, 'a>(rows: &'r [UnicodeData<'a>], code: u32) -> Option<&'r UnicodeData<'a>> {
+	match rows.binary_search_by_key(&code, |x| x.code) {
+		Ok(index) if index > 0 && rows[index].is_range_last() => Some(&rows[index - 1]),
+		Ok(index) => Some(&rows[index]),
+		Err(index) => {
+			let is_inside_range = index > 0
+				&& index < rows.len()
+				&& rows[index - 1].is_range_first()
+				&& rows[index].is_range_last();
+			if is_inside_range {
+				Some(&rows[index - 1])
+			} else {
+				None
+			}
+		}
+	}
+}
+
+/// Index of [`UnicodeData`] rows parsed from a `UnicodeData.txt` source
+/// text, which can be queried by codepoint.
+///
+/// ```
+/// # use ucd_parser::UnicodeDataMap;
+/// let text = "0041;LATIN CAPITAL LETTER A;Lu;0;L;;;;;N;;;;0061;";
+/// let map = UnicodeDataMap::from_str(text).unwrap();
+/// assert_eq!(map.get(0x41).unwrap().name, "LATIN CAPITAL LETTER A");
+/// assert!(map.get(0x42).is_none());
+/// ```
+#[derive(Debug)]
+pub struct UnicodeDataMap<'a> {
+	rows: Vec<UnicodeData<'a>>,
+}
+
+impl<'a> UnicodeDataMap<'a> {
+	/// Parse the rows from a source text in the `UnicodeData.txt` format.
+	///
+	/// Note that the map borrows from the text, so this doesn't implement
+	/// [`std::str::FromStr`].
+	#[allow(clippy::should_implement_trait)]
+	pub fn from_str(input: &'a str) -> Result<Self, String> {
+		let lines = input.lines().map(|x| x.trim()).filter(|x| !x.is_empty());
+		let mut rows = lines
+			.map(UnicodeData::parse)
+			.collect::<Result<Vec<_>, _>>()?;
+		rows.sort_by_key(|x| x.code);
+		Ok(UnicodeDataMap { rows })
+	}
+
+	/// Return the data for a codepoint, or [`None`] if it is unassigned.
+	///
+	/// Codepoints inside a `First>`/`Last>` range return a copy of the row
+	/// for the start of the range, with the `code` set to the codepoint.
+	pub fn get(&self, code: u32) -> Option<Cow<'_, UnicodeData<'a>>> {
+		let row = find_row(&self.rows, code)?;
+		if row.code == code && !row.is_range_first() {
+			Some(Cow::Borrowed(row))
+		} else {
+			let mut row = row.clone();
+			row.code = code;
+			Some(Cow::Owned(row))
+		}
+	}
+
+	/// Find a codepoint by its exact character name.
+	///
+	/// Labels such as `<control>` and the `First>`/`Last>` range rows are not
+	/// character names, so those are never matched.
+	pub fn find_by_name(&self, name: &str) -> Option<u32> {
+		let rows = self.rows.iter().filter(|x| !x.name.starts_with('<'));
+		rows.filter(|x| x.name == name).map(|x| x.code).next()
+	}
+
+	/// Find a codepoint by its character name, ignoring case and treating
+	/// any run of spaces, hyphens, and underbars as a single space.
+	///
+	/// See also [`UnicodeDataMap::find_by_name`].
+	pub fn find_by_name_loose(&self, name: &str) -> Option<u32> {
+		let name = normalize_char_name(name);
+		let rows = self.rows.iter().filter(|x| !x.name.starts_with('<'));
+		rows.filter(|x| normalize_char_name(x.name) == name)
+			.map(|x| x.code)
+			.next()
+	}
+
+	/// Iterator over every assigned codepoint in the map, in codepoint order,
+	/// with the row for its data.
+	///
+	/// `First>`/`Last>` ranges are expanded lazily into their individual
+	/// codepoints, all of them returning the row for the start of the range.
+	/// Surrogate codepoints are skipped.
+	pub fn iter_codepoints(&self) -> impl Iterator<Item = (u32, &UnicodeData<'a>)> + '_ {
+		let rows = self.rows.iter().enumerate();
+		let rows = rows.filter(|(_, row)| row.category != Category::OtherSurrogate);
+		let ranges = rows.filter_map(|(index, row)| {
+			if row.is_range_last() && index > 0 && self.rows[index - 1].is_range_first() {
+				return None;
+			}
+			let last = match self.rows.get(index + 1) {
+				Some(next) if row.is_range_first() && next.is_range_last() => next.code,
+				_ => row.code,
+			};
+			Some((row, last))
+		});
+		ranges.flat_map(|(row, last)| (row.code..=last).map(move |code| (code, row)))
+	}
+
+	/// Number of rows in the map. Note that ranges count as two rows.
+	pub fn len(&self) -> usize {
+		self.rows.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.rows.is_empty()
+	}
+}
+
+fn normalize_char_name(name: &str) -> String {
+	let mut output = String::with_capacity(name.len());
+	for word in name.split([' ', '-', '_']) {
+		if !word.is_empty() {
+			if !output.is_empty() {
+				output.push(' ');
+			}
+			output.push_str(&word.to_lowercase());
+		}
+	}
+	output
+}
+
+/// Returns the name for a codepoint from the UCD data, or [`None`] if the
+/// codepoint is unassigned.
+///
+/// Codepoints inside a `First>`/`Last>` range don't have an individual name
+/// in the data, so those return the range label (e.g. `<CJK Ideograph>`).
+pub fn name_of(code: u32) -> Option<Cow<'static, str>> {
+	UnicodeData::find(code).map(|row| row.display_name())
+}
+
+/// Returns a one-line summary of a codepoint with its name and some of its
+/// key properties, for debugging and tooling output:
+///
+/// ```
+/// # use ucd_parser::describe_codepoint;
+/// assert_eq!(
+///     describe_codepoint(0x41),
+///     "U+0041 LATIN CAPITAL LETTER A (Lu, bidi=L, ccc=0)"
+/// );
+/// assert_eq!(describe_codepoint(0x378), "U+0378 <unassigned>");
+/// ```
+pub fn describe_codepoint(code: u32) -> String {
+	match (UnicodeData::find(code), name_of(code)) {
+		(Some(row), Some(name)) => format!(
+			"U+{:04X} {} ({}, bidi={}, ccc={})",
+			code, name, row.category, row.bidi, row.combining_class
+		),
+		_ => format!("U+{:04X} <unassigned>", code),
+	}
+}
+
+/// Returns the rows from [`UnicodeData::list`] with a codepoint in the given
+/// plane, in file order. This can be used to split the work of processing the
+/// data by plane.
+///
+/// Ranges are kept as their `First`/`Last` row pair. In the UCD data a range
+/// never crosses a plane boundary, so both rows are always in the same plane.
+pub fn rows_in_plane(plane: u8) -> Vec<&'static UnicodeData<'static>> {
+	let sta = (plane as u32) << 16;
+	let end = sta + 0x10000;
+	let list = UnicodeData::list();
+	let index = list.partition_point(|x| x.code < sta);
+	list[index..].iter().take_while(|x| x.code < end).collect()
+}
+
+/// Iterator over the codepoints with a decomposition mapping in the UCD data,
+/// in file order.
+pub fn decompositions() -> impl Iterator<Item = (u32, &'static Decomposition)> {
+	let rows = UnicodeData::list().iter();
+	rows.filter_map(|x| x.decomposition.as_ref().map(|d| (x.code, d)))
+}
+
+/// Build a map from each canonical decomposition sequence to its composite
+/// codepoint, which can be used for canonical composition.
+///
+/// Only the decompositions that are part of the canonical composition are
+/// included in the map. The excluded decompositions (i.e. the ones with the
+/// `Full_Composition_Exclusion` property) are:
+///
+/// - composition exclusions listed in `CompositionExclusions.txt`;
+/// - singleton decompositions mapping to a single codepoint;
+/// - non-starter decompositions, where either the composite or the first
+///   codepoint in the decomposition has a non-zero combining class.
+///
+/// ```
+/// # use ucd_parser::decomposition_reverse_map;
+/// let map = decomposition_reverse_map();
+/// assert_eq!(map.get(&vec![0x0041, 0x0300]), Some(&0x00C0));
+/// ```
+pub fn decomposition_reverse_map() -> HashMap<Vec<u32>, u32> {
+	let input = Input::get(InputFile::CompositionExclusions);
+	let exclusions = input.lines().map(|x| parse_code(x).unwrap());
+	let exclusions = exclusions.collect::<HashSet<_>>();
+
+	let combining_class = |code: u32| UnicodeData::find(code).map(|x| x.combining_class);
+	let is_starter = |code: u32| combining_class(code).unwrap_or(0) == 0;
+
+	let list = decompositions().filter(|(code, decomposition)| {
+		decomposition.tag.is_none()
+			&& decomposition.codes.len() > 1
+			&& !exclusions.contains(code)
+			&& is_starter(*code)
+			&& is_starter(decomposition.codes[0])
+	});
+	list.map(|(code, decomposition)| (decomposition.codes.clone(), code))
+		.collect()
+}
+
+/// Returns true if the codepoint is a noncharacter, i.e. one of the 66
+/// codepoints permanently reserved for internal use: `U+FDD0..U+FDEF` and the
+/// last two codepoints of each plane (`U+xxFFFE` and `U+xxFFFF`).
+pub fn is_noncharacter(code: u32) -> bool {
+	(0xFDD0..=0xFDEF).contains(&code) || (code <= 0x10FFFF && code & 0xFFFE == 0xFFFE)
+}
+
+/// Returns true if a codepoint should be rendered by a text renderer.
+///
+/// This is `false` for codepoints that:
+///
+/// - are unassigned in `UnicodeData.txt`;
+/// - have the `Cs` (surrogate) general category;
+/// - are noncharacters (see [`is_noncharacter`]);
+/// - have the `Default_Ignorable_Code_Point` property.
+///
+/// All other codepoints, including controls and private use, are `true`.
+pub fn should_render(code: u32) -> bool {
+	let assigned = match UnicodeData::find(code) {
+		Some(row) => row.category != Category::OtherSurrogate,
+		None => false,
+	};
+	assigned && !is_noncharacter(code) && !is_default_ignorable(code)
+}
+
+/// Approximation of the `Grapheme_Extend` property using only the data from
+/// `UnicodeData.txt`, for consumers that don't want to depend on the derived
+/// properties.
+///
+/// This is true for the `Mn` and `Me` general categories, plus a short list
+/// of `Other_Grapheme_Extend` codepoints from `PropList.txt`:
+///
+/// - `U+200C` ZERO WIDTH NON-JOINER;
+/// - `U+FF9E..U+FF9F` halfwidth katakana sound marks;
+/// - `U+E0020..U+E007F` tag characters.
+///
+/// Note that this is NOT the full derived property. Notably, it does not
+/// include the spacing marks (`Mc`) listed in `Other_Grapheme_Extend`, such
+/// as `U+09BE` BENGALI VOWEL SIGN AA.
+pub fn is_grapheme_extend_basic(code: u32) -> bool {
+	match code {
+		0x200C | 0xFF9E..=0xFF9F | 0xE0020..=0xE007F => true,
+		_ => UnicodeData::find(code)
+			.map(|x| {
+				x.category == Category::MarkNonSpacing || x.category == Category::MarkEnclosing
+			})
+			.unwrap_or(false),
+	}
+}
+
+/// Compute summary statistics for the `UnicodeData.txt` data. See
+/// [`UnicodeDataStats`].
+pub fn stats() -> UnicodeDataStats {
+	let mut stats = UnicodeDataStats::default();
+	let rows = UnicodeData::list();
+	stats.rows = rows.len();
+	for (index, row) in rows.iter().enumerate() {
+		let count = if row.is_range_first() {
+			stats.ranges += 1;
+			match rows.get(index + 1) {
+				Some(last) if last.is_range_last() => (last.code - row.code + 1) as usize,
+				_ => 1,
+			}
+		} else if row.is_range_last() {
+			continue;
+		} else {
+			1
+		};
+
+		stats.assigned += count;
+		if row.decomposition.is_some() {
+			stats.decompositions += count;
+		}
+		if row.numeric_value != NumericValue::None {
+			stats.numeric_values += count;
+		}
+		*stats.categories.entry(row.category.major()).or_default() += count;
+	}
+	stats
+}
+
+/// Summary statistics for the `UnicodeData.txt` data, returned by
+/// [`stats`].
+///
+/// Codepoint counts include all codepoints inside `First>`/`Last>` ranges.
+#[derive(Clone, Debug, Default)]
+pub struct UnicodeDataStats {
+	/// Number of rows in the data file.
+	pub rows: usize,
+
+	/// Number of `First>`/`Last>` row pairs representing ranges.
+	pub ranges: usize,
+
+	/// Number of assigned codepoints.
+	pub assigned: usize,
+
+	/// Number of codepoints with a decomposition mapping.
+	pub decompositions: usize,
+
+	/// Number of codepoints with a numeric value.
+	pub numeric_values: usize,
+
+	/// Number of codepoints for each major category.
+	pub categories: HashMap<MajorCategory, usize>,
+}
+
+/// [`PropertyKey`] for the assigned status of codepoints in a [`RangeTable`].
+#[derive(Clone, PartialEq)]
+pub struct AssignedProperty;
+
+impl PropertyKey for AssignedProperty {
+	type Value = bool;
+}
+
+/// Check that the codepoints in a list of entries are strictly increasing,
+/// returning an error describing the first offending pair.
+///
+/// A `First>` row opens a range that must be closed by the next row with a
+/// `Last>` name, so any other row between them is an error, even if its
+/// codepoint is in order. An unclosed `First>` row or a `Last>` row without
+/// a matching `First>` is also an error.
+///
+/// This catches corrupted or concatenated data that would otherwise silently
+/// produce overlapping entries in the generated tables.
+pub fn check_sorted_unique(entries: &[UnicodeData]) -> Result<(), String> {
+	let unopened = |row: &UnicodeData| {
+		format!(
+			"UnicodeData: U+{:04X} `{}` closes a range that was not started",
+			row.code, row.name
+		)
+	};
+
+	if let Some(row) = entries.first().filter(|row| row.is_range_last()) {
+		return Err(unopened(row));
+	}
+
+	for pair in entries.windows(2) {
+		let (prev, next) = (&pair[0], &pair[1]);
+		if next.code == prev.code {
+			return Err(format!(
+				"UnicodeData: duplicate codepoint U+{:04X} (`{}` and `{}`)",
+				next.code, prev.name, next.name
+			));
+		} else if next.code < prev.code {
+			return Err(format!(
+				"UnicodeData: U+{:04X} `{}` is out of order after U+{:04X} `{}`",
+				next.code, next.name, prev.code, prev.name
+			));
+		}
+
+		if prev.is_range_first() && !next.is_range_last() {
+			return Err(format!(
+				"UnicodeData: U+{:04X} `{}` is inside the range started by U+{:04X} `{}`",
+				next.code, next.name, prev.code, prev.name
+			));
+		}
+
+		if next.is_range_last() && !prev.is_range_first() {
+			return Err(unopened(next));
+		}
+	}
+
+	if let Some(row) = entries.last().filter(|row| row.is_range_first()) {
+		return Err(format!(
+			"UnicodeData: U+{:04X} `{}` starts a range that is not closed",
+			row.code, row.name
+		));
+	}
+	Ok(())
+}
+
+/// Build a [`RangeTable`] with the [`AssignedProperty`] for the entire
+/// codespace from `UnicodeData.txt`.
+///
+/// Codepoints with a data row, including those inside `First>`/`Last>` ranges
+/// and surrogates, are assigned. All other codepoints are set to `false`.
+pub fn build_assigned_table() -> RangeTable {
+	// collect contiguous runs first, since setting each codepoint in the
+	// table individually is too slow
+	let mut runs: Vec<(u32, u32)> = Vec::new();
+	let mut range_first = None;
+	for row in UnicodeData::list() {
+		let first = if row.is_range_first() {
+			range_first = Some(row.code);
+			continue;
+		} else if row.is_range_last() {
+			range_first.take().unwrap_or(row.code)
+		} else {
+			row.code
+		};
+
+		match runs.last_mut() {
+			Some((_, last)) if *last + 1 == first => *last = row.code,
+			_ => runs.push((first, row.code)),
+		}
+	}
+
+	let mut table = RangeTable::new();
+	table.set_range(0..=0x10FFFF, AssignedProperty, false);
+	for (first, last) in runs {
+		table.set_range(first..=last, AssignedProperty, true);
+	}
+	table
+}
+
 /// Values for the decimal digit value property for a character.
 ///
 /// See also [`DigitValue`], [`NumericValue`].
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum DecimalValue {
 	None,
 	Some(u32),
@@ -264,7 +901,7 @@ pub enum DecimalValue {
 /// not necessarily a decimal digit.
 ///
 /// See also [`DecimalValue`], [`NumericValue`].
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum DigitValue {
 	None,
 	Some(u32),
@@ -272,7 +909,7 @@ pub enum DigitValue {
 
 /// Mirrored property for characters in bidirectional text. The list of
 /// mirrored characters is printed in Chapter 4 of the Unicode Standard.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Mirrored {
 	No,
 	Yes,
@@ -297,12 +934,37 @@ impl std::fmt::Display for Mirrored {
 /// These mappings are always one-to-one, not one-to-many or many-to-one. It
 /// also doesn't contain information about context-sensitive case mappings
 /// (i.e. `SpecialCasing.txt`).
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum CaseMapping {
 	None,
 	Some(u32),
 }
 
+impl CaseMapping {
+	/// Returns the target codepoint for the mapping, or [`None`] if there is
+	/// no mapping.
+	pub fn code(&self) -> Option<u32> {
+		match self {
+			CaseMapping::Some(code) => Some(*code),
+			CaseMapping::None => None,
+		}
+	}
+
+	/// Returns the target codepoint for the mapping, or the given default if
+	/// there is no mapping. The default is usually the character's own
+	/// codepoint, since a character without a mapping maps to itself:
+	///
+	/// ```
+	/// # use ucd_parser::UnicodeData;
+	/// let row = UnicodeData::find(0x61).unwrap();
+	/// assert_eq!(row.uppercase_mapping.or_self(row.code), 0x41);
+	/// assert_eq!(row.lowercase_mapping.or_self(row.code), 0x61);
+	/// ```
+	pub fn or_self(&self, default: u32) -> u32 {
+		self.code().unwrap_or(default)
+	}
+}
+
 impl std::fmt::Display for CaseMapping {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		match self {
@@ -317,6 +979,38 @@ mod tests {
 	use super::*;
 	use crate::DecompositionTag;
 
+	#[test]
+	#[cfg(feature = "serde")]
+	fn serializes_to_json() {
+		let row = UnicodeData::find(0x41).unwrap();
+		assert_eq!(
+			row.to_json(),
+			concat!(
+				r#"{"bidi":"L","category":"Lu","code":65,"combining_class":0,"#,
+				r#""decimal_value":null,"decomposition":null,"digit_value":null,"#,
+				r#""iso_10646_comment":"","lowercase_mapping":97,"mirrored":"N","#,
+				r#""name":"LATIN CAPITAL LETTER A","numeric_value":null,"#,
+				r#""titlecase_mapping":null,"unicode_old_name":"","uppercase_mapping":null}"#,
+			)
+		);
+
+		let row = UnicodeData::find(0xBD).unwrap();
+		let json = row.to_json();
+		assert!(json.contains(r#""decomposition":{"codes":[49,8260,50],"tag":"fraction"}"#));
+		assert!(json.contains(r#""numeric_value":"1/2""#));
+		assert!(json.contains(r#""category":"No""#));
+
+		let row = UnicodeData::find(0x35).unwrap();
+		let json = row.to_json();
+		assert!(json.contains(r#""decimal_value":5,"#));
+		assert!(json.contains(r#""digit_value":5,"#));
+		assert!(json.contains(r#""numeric_value":"5""#));
+
+		let row = UnicodeData::find(0xC0).unwrap();
+		let json = row.to_json();
+		assert!(json.contains(r#""decomposition":{"codes":[65,768],"tag":null}"#));
+	}
+
 	#[test]
 	fn can_create_new() {
 		let decomposition = Decomposition {
@@ -484,6 +1178,134 @@ mod tests {
 		};
 	}
 
+	#[test]
+	fn validates_numeric_fields() {
+		let row = UnicodeData::parse("0031;DIGIT ONE;Nd;0;EN;;1;1;1;N;;;;;").unwrap();
+		assert_eq!(row.validate(), Ok(()));
+
+		let row =
+			UnicodeData::parse("00B9;SUPERSCRIPT ONE;No;0;EN;<super> 0031;;1;1;N;;;;;").unwrap();
+		assert_eq!(row.validate(), Ok(()));
+
+		let row = UnicodeData::parse("0031;DIGIT ONE;Nd;0;EN;;1;2;1;N;;;;;").unwrap();
+		let err = row.validate().unwrap_err();
+		assert!(err.contains("U+0031: decimal value 1 does not match digit value"));
+
+		let row = UnicodeData::parse("0031;DIGIT ONE;Nd;0;EN;;1;1;2;N;;;;;").unwrap();
+		let err = row.validate().unwrap_err();
+		assert!(err.contains("digit value 1 does not match numeric value"));
+
+		let row = UnicodeData::parse("0031;DIGIT ONE;No;0;EN;;1;1;1;N;;;;;").unwrap();
+		let err = row.validate().unwrap_err();
+		assert!(err.contains("decimal value 1 with category No"));
+
+		let row = UnicodeData::parse("0031;DIGIT ONE;Nd;0;EN;;;;;N;;;;;").unwrap();
+		let err = row.validate().unwrap_err();
+		assert!(err.contains("category Nd without a decimal value"));
+	}
+
+	#[test]
+	fn resolves_case_mapping_code() {
+		assert_eq!(CaseMapping::Some(0x41).code(), Some(0x41));
+		assert_eq!(CaseMapping::None.code(), None);
+
+		assert_eq!(CaseMapping::Some(0x41).or_self(0x61), 0x41);
+		assert_eq!(CaseMapping::None.or_self(0x61), 0x61);
+	}
+
+	#[test]
+	fn bundled_data_is_valid() {
+		for row in UnicodeData::list() {
+			assert_eq!(row.validate(), Ok(()));
+		}
+	}
+
+	#[test]
+	fn checks_entries_are_sorted_and_unique() {
+		let parse = |lines: &[&'static str]| {
+			let rows = lines.iter().map(|x| UnicodeData::parse(x).unwrap());
+			rows.collect::<Vec<_>>()
+		};
+
+		assert_eq!(check_sorted_unique(UnicodeData::list()), Ok(()));
+		assert_eq!(check_sorted_unique(&[]), Ok(()));
+
+		let sorted = parse(&[
+			"0041;A;Lu;0;L;;;;;N;;;;0061;",
+			"0042;B;Lu;0;L;;;;;N;;;;0062;",
+			"3400;<CJK Ideograph Extension A, First>;Lo;0;L;;;;;N;;;;;",
+			"4DBF;<CJK Ideograph Extension A, Last>;Lo;0;L;;;;;N;;;;;",
+			"4DC0;HEXAGRAM FOR THE CREATIVE HEAVEN;So;0;ON;;;;;N;;;;;",
+		]);
+		assert_eq!(check_sorted_unique(&sorted), Ok(()));
+
+		let duplicate = parse(&[
+			"0041;A;Lu;0;L;;;;;N;;;;0061;",
+			"0042;B;Lu;0;L;;;;;N;;;;0062;",
+			"0042;BB;Lu;0;L;;;;;N;;;;0062;",
+		]);
+		assert_eq!(
+			check_sorted_unique(&duplicate),
+			Err("UnicodeData: duplicate codepoint U+0042 (`B` and `BB`)".to_string())
+		);
+
+		let out_of_order = parse(&[
+			"0041;A;Lu;0;L;;;;;N;;;;0061;",
+			"0043;C;Lu;0;L;;;;;N;;;;0063;",
+			"0042;B;Lu;0;L;;;;;N;;;;0062;",
+		]);
+		assert_eq!(
+			check_sorted_unique(&out_of_order),
+			Err("UnicodeData: U+0042 `B` is out of order after U+0043 `C`".to_string())
+		);
+
+		let inside_range = parse(&[
+			"3400;<CJK Ideograph Extension A, First>;Lo;0;L;;;;;N;;;;;",
+			"3500;CJK;Lo;0;L;;;;;N;;;;;",
+			"4DBF;<CJK Ideograph Extension A, Last>;Lo;0;L;;;;;N;;;;;",
+		]);
+		assert_eq!(
+			check_sorted_unique(&inside_range),
+			Err("UnicodeData: U+3500 `CJK` is inside the range started by U+3400 `<CJK Ideograph Extension A, First>`".to_string())
+		);
+
+		let unclosed = parse(&[
+			"0041;A;Lu;0;L;;;;;N;;;;0061;",
+			"3400;<CJK Ideograph Extension A, First>;Lo;0;L;;;;;N;;;;;",
+		]);
+		assert_eq!(
+			check_sorted_unique(&unclosed),
+			Err("UnicodeData: U+3400 `<CJK Ideograph Extension A, First>` starts a range that is not closed".to_string())
+		);
+
+		let unopened = parse(&[
+			"0041;A;Lu;0;L;;;;;N;;;;0061;",
+			"4DBF;<CJK Ideograph Extension A, Last>;Lo;0;L;;;;;N;;;;;",
+		]);
+		assert_eq!(
+			check_sorted_unique(&unopened),
+			Err("UnicodeData: U+4DBF `<CJK Ideograph Extension A, Last>` closes a range that was not started".to_string())
+		);
+
+		let unopened = parse(&["4DBF;<CJK Ideograph Extension A, Last>;Lo;0;L;;;;;N;;;;;"]);
+		assert!(check_sorted_unique(&unopened)
+			.unwrap_err()
+			.contains("closes a range that was not started"));
+	}
+
+	#[test]
+	fn parse_at_includes_line_number_in_error() {
+		let err = UnicodeData::parse_at(42, "x1;name;Ll;0;L;;0;0;0;N;;;0;0;0").unwrap_err();
+		assert!(err.starts_with("line 42: parsing unicode data:"));
+		assert!(err.contains("`x1` is not a valid code"));
+
+		let row = UnicodeData::parse_at(1, "0041;A;Lu;0;L;;;;;N;;;;0061;").unwrap();
+		assert_eq!(
+			row,
+			UnicodeData::parse("0041;A;Lu;0;L;;;;;N;;;;0061;").unwrap()
+		);
+	}
+
 	#[test]
 	fn parse_panics_on_invalid_input() {
 		check_parsing!(
@@ -589,16 +1411,154 @@ mod tests {
 		assert!(has_entries);
 	}
 
+	#[test]
+	fn supports_clone_and_hash() {
+		use std::collections::HashSet;
+
+		let a = UnicodeData::parse("0041;LATIN CAPITAL LETTER A;Lu;0;L;;;;;N;;;;0061;").unwrap();
+		let b = UnicodeData::parse("00C0;LATIN CAPITAL LETTER A WITH GRAVE;Lu;0;L;0041 0300;;;;N;LATIN CAPITAL LETTER A GRAVE;;;00E0;").unwrap();
+		assert_eq!(a.clone(), a);
+
+		let mut set = HashSet::new();
+		assert!(set.insert(a.clone()));
+		assert!(set.insert(b.clone()));
+		assert!(!set.insert(a.clone()));
+		assert_eq!(set.len(), 2);
+		assert!(set.contains(&b));
+	}
+
+	#[test]
+	fn converts_to_and_from_owned() {
+		let owned = {
+			let input = String::from(
+				"00C0;LATIN CAPITAL LETTER A WITH GRAVE;Lu;0;L;0041 0300;;;;N;LATIN CAPITAL LETTER A GRAVE;;;00E0;",
+			);
+			let entry = UnicodeData::parse(&input).unwrap();
+			entry.to_owned()
+		};
+
+		assert_eq!(owned.name, "LATIN CAPITAL LETTER A WITH GRAVE");
+		assert_eq!(owned.unicode_old_name, "LATIN CAPITAL LETTER A GRAVE");
+
+		let entry = UnicodeData::list().iter().find(|x| x.code == 0xC0).unwrap();
+		assert_eq!(&owned.as_ref(), entry);
+		assert_eq!(entry.to_owned(), owned);
+		assert_eq!(owned.to_string(), entry.to_string());
+	}
+
+	#[test]
+	fn unicode_data_map_returns_row_for_codepoint() {
+		let text = concat!(
+			"0041;LATIN CAPITAL LETTER A;Lu;0;L;;;;;N;;;;0061;\n",
+			"4E00;<CJK Ideograph, First>;Lo;0;L;;;;;N;;;;;\n",
+			"9FFF;<CJK Ideograph, Last>;Lo;0;L;;;;;N;;;;;\n",
+		);
+		let map = UnicodeDataMap::from_str(text).unwrap();
+		assert_eq!(map.len(), 3);
+
+		let row = map.get(0x41).unwrap();
+		assert!(matches!(row, Cow::Borrowed(_)));
+		assert_eq!(row.code, 0x41);
+		assert_eq!(row.name, "LATIN CAPITAL LETTER A");
+
+		for code in [0x4E00, 0x6C34, 0x9FFF] {
+			let row = map.get(code).unwrap();
+			assert_eq!(row.code, code);
+			assert_eq!(row.name, "<CJK Ideograph, First>");
+			assert_eq!(row.category, Category::LetterOther);
+		}
+
+		assert!(map.get(0x42).is_none());
+		assert!(map.get(0x4DFF).is_none());
+		assert!(map.get(0xA000).is_none());
+	}
+
+	#[test]
+	fn unicode_data_map_iterates_codepoints() {
+		let text = concat!(
+			"0041;LATIN CAPITAL LETTER A;Lu;0;L;;;;;N;;;;0061;\n",
+			"0042;LATIN CAPITAL LETTER B;Lu;0;L;;;;;N;;;;0062;\n",
+			"3400;<CJK Ideograph Extension A, First>;Lo;0;L;;;;;N;;;;;\n",
+			"340F;<CJK Ideograph Extension A, Last>;Lo;0;L;;;;;N;;;;;\n",
+			"D800;<Non Private Use High Surrogate, First>;Cs;0;L;;;;;N;;;;;\n",
+			"DB7F;<Non Private Use High Surrogate, Last>;Cs;0;L;;;;;N;;;;;\n",
+			"E000;<Private Use, First>;Co;0;L;;;;;N;;;;;\n",
+			"E001;<Private Use, Last>;Co;0;L;;;;;N;;;;;\n",
+		);
+		let map = UnicodeDataMap::from_str(text).unwrap();
+		let codes = map.iter_codepoints().collect::<Vec<_>>();
+		assert_eq!(codes.len(), 2 + 16 + 2);
+
+		assert_eq!(codes[0].0, 0x41);
+		assert_eq!(codes[0].1.name, "LATIN CAPITAL LETTER A");
+		assert_eq!(codes[1].0, 0x42);
+
+		for (index, (code, row)) in codes[2..18].iter().enumerate() {
+			assert_eq!(*code, 0x3400 + index as u32);
+			assert_eq!(row.name, "<CJK Ideograph Extension A, First>");
+			assert_eq!(row.category, Category::LetterOther);
+		}
+
+		assert_eq!(codes[18].0, 0xE000);
+		assert_eq!(codes[19].0, 0xE001);
+		assert_eq!(codes[19].1.category, Category::OtherPrivateUse);
+	}
+
+	#[test]
+	fn unicode_data_map_iterates_all_ucd_codepoints() {
+		let map = UnicodeDataMap::from_str(Input::get(InputFile::UnicodeData).text()).unwrap();
+		let stats = stats();
+		let count = map.iter_codepoints().count();
+		assert_eq!(count, stats.assigned - 0x800);
+		assert!(map
+			.iter_codepoints()
+			.all(|(code, _)| map.get(code).is_some()));
+	}
+
+	#[test]
+	fn unicode_data_map_finds_codepoint_by_name() {
+		let text = concat!(
+			"0000;<control>;Cc;0;BN;;;;;N;NULL;;;;\n",
+			"0041;LATIN CAPITAL LETTER A;Lu;0;L;;;;;N;;;;0061;\n",
+			"002D;HYPHEN-MINUS;Pd;0;ES;;;;;N;;;;;\n",
+			"4E00;<CJK Ideograph, First>;Lo;0;L;;;;;N;;;;;\n",
+			"9FFF;<CJK Ideograph, Last>;Lo;0;L;;;;;N;;;;;\n",
+		);
+		let map = UnicodeDataMap::from_str(text).unwrap();
+
+		assert_eq!(map.find_by_name("LATIN CAPITAL LETTER A"), Some(0x41));
+		assert_eq!(map.find_by_name("HYPHEN-MINUS"), Some(0x2D));
+		assert_eq!(map.find_by_name("latin capital letter a"), None);
+		assert_eq!(map.find_by_name("LATIN CAPITAL LETTER B"), None);
+		assert_eq!(map.find_by_name("<control>"), None);
+		assert_eq!(map.find_by_name("<CJK Ideograph, First>"), None);
+
+		assert_eq!(map.find_by_name_loose("latin capital letter a"), Some(0x41));
+		assert_eq!(
+			map.find_by_name_loose("Latin  Capital-Letter_A"),
+			Some(0x41)
+		);
+		assert_eq!(map.find_by_name_loose("hyphen minus"), Some(0x2D));
+		assert_eq!(map.find_by_name_loose("latin capital lettera"), None);
+		assert_eq!(map.find_by_name_loose("<cjk ideograph, first>"), None);
+	}
+
+	#[test]
+	fn unicode_data_map_from_invalid_text_returns_error() {
+		let err = UnicodeDataMap::from_str("0041;A").unwrap_err();
+		assert!(err.contains("invalid row format"));
+	}
+
 	#[test]
 	fn can_load_from_ucd() {
 		let source = include_ucd!("UnicodeData.txt");
 		let source = source.lines().collect::<Vec<_>>();
-		assert!(source.len() > 0);
+		assert!(!source.is_empty());
 
 		let rows = UnicodeData::list();
 
-		assert!(rows.len() > 0);
-		for (n, row) in rows.into_iter().enumerate() {
+		assert!(!rows.is_empty());
+		for (n, row) in rows.iter().enumerate() {
 			let row_as_text = row.to_string();
 			let source = source[n];
 			assert_eq!(
@@ -611,4 +1571,226 @@ mod tests {
 			);
 		}
 	}
+
+	#[test]
+	fn splits_rows_by_plane() {
+		let plane0 = rows_in_plane(0);
+		assert_eq!(plane0[0].code, 0x0000);
+		assert!(plane0.iter().any(|x| x.code == 0x41));
+		assert!(plane0.iter().all(|x| x.code <= 0xFFFF));
+
+		let plane1 = rows_in_plane(1);
+		assert!(plane1.iter().any(|x| x.code == 0x1F600));
+		assert!(plane1.iter().all(|x| (0x10000..=0x1FFFF).contains(&x.code)));
+
+		let plane15 = rows_in_plane(15);
+		assert_eq!(plane15.len(), 2);
+		assert!(plane15[0].is_range_first());
+		assert!(plane15[1].is_range_last());
+
+		assert!(rows_in_plane(17).is_empty());
+
+		let total: usize = (0..=16).map(|x| rows_in_plane(x).len()).sum();
+		assert_eq!(total, UnicodeData::list().len());
+	}
+
+	#[test]
+	fn builds_assigned_table() {
+		let table = build_assigned_table();
+		let value = |code: u32| table.lookup(code, AssignedProperty);
+
+		assert!((0..=0x7F).all(|code| value(code) == Some(true)));
+		assert_eq!(value(0x0378), Some(false));
+		assert_eq!(value(0x4E00), Some(true));
+		assert_eq!(value(0xD800), Some(true));
+		assert_eq!(value(0x10FFFD), Some(true));
+		assert_eq!(value(0x10FFFE), Some(false));
+
+		// covers the entire codespace without gaps
+		let mut next = 0;
+		for row in table.rows() {
+			assert_eq!(row.first, next);
+			next = row.last + 1;
+		}
+		assert_eq!(next, 0x110000);
+	}
+
+	#[test]
+	fn computes_stats() {
+		let stats = stats();
+		assert_eq!(stats.rows, UnicodeData::list().len());
+		assert!(stats.ranges > 10);
+		assert!(stats.assigned > 100_000);
+		assert!(stats.decompositions > 5_000);
+		assert!(stats.numeric_values > 1_000);
+		assert!(stats.categories[&MajorCategory::Letter] > 100_000);
+		assert_eq!(stats.categories.values().sum::<usize>(), stats.assigned);
+	}
+
+	#[test]
+	fn iterates_decompositions() {
+		let list = decompositions().collect::<Vec<_>>();
+		let (_, decomposition) = list.iter().find(|x| x.0 == 0xC0).unwrap();
+		assert_eq!(decomposition.tag, None);
+		assert_eq!(decomposition.codes, vec![0x41, 0x300]);
+		assert!(list.iter().all(|x| x.0 != 0x41));
+
+		let expected = UnicodeData::list()
+			.iter()
+			.filter(|x| x.decomposition.is_some())
+			.count();
+		assert_eq!(list.len(), expected);
+	}
+
+	#[test]
+	fn builds_decomposition_reverse_map() {
+		let map = decomposition_reverse_map();
+		assert_eq!(map.get(&vec![0x0041, 0x0300]), Some(&0x00C0));
+		assert_eq!(map.get(&vec![0x1100, 0x1161]), None);
+
+		// DEVANAGARI LETTER QA is a composition exclusion
+		let qa = UnicodeData::find(0x0958).unwrap();
+		let qa = qa.decomposition.as_ref().unwrap();
+		assert!(!map.contains_key(&qa.codes));
+		assert!(!map.values().any(|x| *x == 0x0958));
+
+		// ANGSTROM SIGN is a singleton
+		assert!(!map.values().any(|x| *x == 0x212B));
+
+		// COMBINING GREEK DIALYTIKA TONOS is a non-starter decomposition
+		assert!(!map.values().any(|x| *x == 0x0344));
+	}
+
+	#[test]
+	fn decomposition_reverse_map_matches_full_composition_exclusion() {
+		let input = include_ucd!("DerivedNormalizationProps.txt");
+		let mut excluded = HashSet::new();
+		for line in input.lines() {
+			let (sta, end, value) = parse_range_line(line).unwrap();
+			if value == "Full_Composition_Exclusion" {
+				excluded.extend(sta..=end);
+			}
+		}
+
+		let map = decomposition_reverse_map();
+		for (code, decomposition) in decompositions() {
+			if decomposition.tag.is_none() {
+				let included = map.get(&decomposition.codes) == Some(&code);
+				assert_eq!(included, !excluded.contains(&code), "U+{:04X}", code);
+			}
+		}
+	}
+
+	#[test]
+	fn returns_typed_combining_class() {
+		let row = UnicodeData::find(0x0301).unwrap();
+		assert_eq!(row.combining_class_typed(), CombiningClass::ABOVE);
+
+		let row = UnicodeData::find(0x0041).unwrap();
+		assert_eq!(row.combining_class_typed(), CombiningClass::NOT_REORDERED);
+	}
+
+	#[test]
+	fn checks_noncharacters() {
+		assert!(is_noncharacter(0xFDD0));
+		assert!(is_noncharacter(0xFDEF));
+		assert!(is_noncharacter(0xFFFE));
+		assert!(is_noncharacter(0x1FFFF));
+		assert!(is_noncharacter(0x10FFFF));
+
+		assert!(!is_noncharacter(0xFDCF));
+		assert!(!is_noncharacter(0xFDF0));
+		assert!(!is_noncharacter(0xFFFD));
+		assert!(!is_noncharacter(0x11FFFE));
+	}
+
+	#[test]
+	fn checks_should_render() {
+		assert!(should_render(0x0041));
+		assert!(should_render(0x4E01));
+		assert!(should_render(0xE000));
+
+		assert!(!should_render(0x200B)); // ZERO WIDTH SPACE
+		assert!(!should_render(0xFFFF));
+		assert!(!should_render(0xD800));
+		assert!(!should_render(0x0378));
+	}
+
+	#[test]
+	fn approximates_grapheme_extend() {
+		assert!(is_grapheme_extend_basic(0x0301)); // Mn
+		assert!(is_grapheme_extend_basic(0x20DD)); // Me
+		assert!(is_grapheme_extend_basic(0x200C));
+		assert!(is_grapheme_extend_basic(0xE0041));
+
+		assert!(!is_grapheme_extend_basic(0x0041));
+		assert!(!is_grapheme_extend_basic(0x0903)); // Mc
+		assert!(!is_grapheme_extend_basic(0x0378)); // unassigned
+	}
+
+	#[test]
+	fn returns_row_as_char() {
+		let row = UnicodeData::find(0x41).unwrap();
+		assert_eq!(row.as_char(), Some('A'));
+
+		let row = UnicodeData::find(0x1F600).unwrap();
+		assert_eq!(row.as_char(), Some('\u{1F600}'));
+
+		let row = UnicodeData::find(0xD800).unwrap();
+		assert_eq!(row.category, Category::OtherSurrogate);
+		assert_eq!(row.as_char(), None);
+
+		// surrogates are invalid as char even outside a range
+		let row = UnicodeData::parse("DC00;LOW SURROGATE;Cs;0;L;;;;;N;;;;;").unwrap();
+		assert_eq!(row.as_char(), None);
+
+		let row = UnicodeData::find(0x4E00).unwrap();
+		assert!(row.is_range_first());
+		assert_eq!(row.as_char(), None);
+	}
+
+	#[test]
+	fn display_name_uses_range_label() {
+		let row = UnicodeData::parse("0041;LATIN CAPITAL LETTER A;Lu;0;L;;;;;N;;;;0061;").unwrap();
+		assert_eq!(row.display_name(), "LATIN CAPITAL LETTER A");
+
+		let row = UnicodeData::parse("3400;<CJK Ideograph Extension A, First>;Lo;0;L;;;;;N;;;;;")
+			.unwrap();
+		assert_eq!(row.display_name(), "<CJK Ideograph Extension A>");
+		assert_eq!(
+			name_of(0x3401).as_deref(),
+			Some("<CJK Ideograph Extension A>")
+		);
+	}
+
+	#[test]
+	fn describes_assigned_codepoint() {
+		assert_eq!(
+			describe_codepoint(0x41),
+			"U+0041 LATIN CAPITAL LETTER A (Lu, bidi=L, ccc=0)"
+		);
+		assert_eq!(
+			describe_codepoint(0x301),
+			"U+0301 COMBINING ACUTE ACCENT (Mn, bidi=NSM, ccc=230)"
+		);
+		assert_eq!(
+			describe_codepoint(0x4E01),
+			"U+4E01 <CJK Ideograph> (Lo, bidi=L, ccc=0)"
+		);
+	}
+
+	#[test]
+	fn describes_unassigned_codepoint() {
+		assert_eq!(describe_codepoint(0x378), "U+0378 <unassigned>");
+		assert_eq!(describe_codepoint(0x10FFFF), "U+10FFFF <unassigned>");
+	}
+
+	#[test]
+	fn find_resolves_codepoint_ranges() {
+		assert_eq!(UnicodeData::find(0x41).unwrap().code, 0x41);
+		assert_eq!(UnicodeData::find(0x4E00).unwrap().code, 0x4E00);
+		assert_eq!(UnicodeData::find(0x4E01).unwrap().code, 0x4E00);
+		assert_eq!(UnicodeData::find(0x9FFF).unwrap().code, 0x4E00);
+		assert_eq!(UnicodeData::find(0x378), None);
+	}
 }
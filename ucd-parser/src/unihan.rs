@@ -0,0 +1,119 @@
+use crate::{input::Input, parse::parse_code};
+
+/// Single property entry from one of the Unihan-style data files.
+///
+/// Those files are tab-separated, with a `U+XXXX` codepoint, a property tag
+/// and its value in each line (e.g. `U+1B170<tab>kReading<tab>i5`).
+///
+/// Besides the `Unihan_*.txt` files from the Unihan database, the same
+/// format is used by `NushuSources.txt` and `TangutSources.txt`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnihanEntry<'a> {
+	/// Codepoint for the entry.
+	pub code: u32,
+
+	/// Property tag (e.g. `kDefinition`).
+	pub property: &'a str,
+
+	/// Raw value for the property.
+	pub value: &'a str,
+}
+
+impl<'a> UnihanEntry<'a> {
+	/// List of entries from a Unihan-style file in the UCD directory, in
+	/// file order. Comment and blank lines are ignored.
+	///
+	/// The file is read at runtime using [`Input::read`] and will panic if
+	/// it cannot be read or contains invalid lines.
+	///
+	/// ```
+	/// # use ucd_parser::UnihanEntry;
+	/// let list = UnihanEntry::list("NushuSources.txt");
+	/// assert_eq!(list[0].code, 0x1B170);
+	/// assert_eq!(list[0].property, "kSrc_NushuDuben");
+	/// ```
+	pub fn list(file: &str) -> Vec<UnihanEntry<'static>> {
+		let input = Input::read(format!("vendor-data/ucd/{}", file));
+		let lines = input.raw().lines();
+		let lines = lines.filter(|x| !Self::is_comment(x));
+		lines.map(|x| UnihanEntry::parse(x).unwrap()).collect()
+	}
+
+	/// Parse a single line in the `U+XXXX<tab>PROPERTY<tab>VALUE` format.
+	///
+	/// Values can contain spaces and are only trimmed at the end of the line.
+	pub fn parse(input: &'a str) -> Result<Self, String> {
+		let error = |err: String| format!("unihan: {} -- in `{}`", err, input);
+		let mut fields = input.trim_end().splitn(3, '\t');
+		let (code, property, value) = match (fields.next(), fields.next(), fields.next()) {
+			(Some(code), Some(property), Some(value)) => (code, property, value),
+			_ => return Err(error("invalid row format (expected 3 fields)".to_string())),
+		};
+
+		let code = code
+			.strip_prefix("U+")
+			.ok_or_else(|| error(format!("`{}` is not a valid code (missing `U+`)", code)))?;
+		let code = parse_code(code).map_err(error)?;
+		if property.is_empty() {
+			return Err(error("empty property".to_string()));
+		}
+		Ok(UnihanEntry {
+			code,
+			property,
+			value,
+		})
+	}
+
+	fn is_comment(line: &str) -> bool {
+		let line = line.trim();
+		line.is_empty() || line.starts_with('#')
+	}
+}
+
+impl<'a> std::fmt::Display for UnihanEntry<'a> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "U+{:04X}\t{}\t{}", self.code, self.property, self.value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_line() {
+		let entry = UnihanEntry::parse("U+4E00\tkDefinition\tone; a, an; alone").unwrap();
+		assert_eq!(entry.code, 0x4E00);
+		assert_eq!(entry.property, "kDefinition");
+		assert_eq!(entry.value, "one; a, an; alone");
+		assert_eq!(entry.to_string(), "U+4E00\tkDefinition\tone; a, an; alone");
+
+		let entry = UnihanEntry::parse("U+20000\tkMandarin\tqiū huà\r").unwrap();
+		assert_eq!(entry.code, 0x20000);
+		assert_eq!(entry.property, "kMandarin");
+		assert_eq!(entry.value, "qiū huà");
+	}
+
+	#[test]
+	fn parse_requires_prefix_and_fields() {
+		let err = UnihanEntry::parse("4E00\tkDefinition\tone").unwrap_err();
+		assert!(err.contains("missing `U+`"));
+
+		let err = UnihanEntry::parse("U+4E00 kDefinition one").unwrap_err();
+		assert!(err.contains("expected 3 fields"));
+
+		let err = UnihanEntry::parse("U+XYZ\tkDefinition\tone").unwrap_err();
+		assert!(err.contains("`XYZ` is not a valid code"));
+	}
+
+	#[test]
+	fn lists_entries_from_file() {
+		let list = UnihanEntry::list("TangutSources.txt");
+		assert_eq!(list[0].to_string(), "U+17000\tkTGT_MergedSrc\tL2008-0008");
+		assert!(list.iter().all(|x| x.property.starts_with('k')));
+
+		let list = UnihanEntry::list("NushuSources.txt");
+		let readings = list.iter().filter(|x| x.property == "kReading");
+		assert_eq!(readings.count(), 396);
+	}
+}
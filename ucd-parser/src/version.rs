@@ -26,6 +26,69 @@ pub fn unicode_version() -> &'static str {
 	&VERSION
 }
 
+/// Major, minor, and patch numbers for [`unicode_version`].
+///
+/// Returns `(0, 0, 0)` if the version could not be extracted from the UCD
+/// `ReadMe.txt`.
+pub fn unicode_version_parts() -> (u32, u32, u32) {
+	let version = Version::current();
+	(version.major, version.minor, version.patch)
+}
+
+/// Version of the Unicode Standard as a `MAJOR.MINOR.PATCH` triple.
+///
+/// Versions are ordered, so they can be compared against a minimum:
+///
+/// ```
+/// # use ucd_parser::Version;
+/// assert!(Version::current() >= Version::new(14, 0, 0));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+	pub major: u32,
+	pub minor: u32,
+	pub patch: u32,
+}
+
+impl Version {
+	pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+		Version {
+			major,
+			minor,
+			patch,
+		}
+	}
+
+	/// Version of the bundled UCD data, from [`unicode_version`].
+	///
+	/// Defaults to `0.0.0` if the version string is malformed.
+	pub fn current() -> Self {
+		Self::parse(unicode_version()).unwrap_or_default()
+	}
+
+	/// Parse a three-part `MAJOR.MINOR.PATCH` version (e.g. `15.1.0`).
+	pub fn parse<T: AsRef<str>>(input: T) -> Result<Self, String> {
+		let input = input.as_ref();
+		let error = || format!("`{}` is not a valid version", input);
+		let mut parts = input
+			.split('.')
+			.map(|x| x.parse::<u32>().map_err(|_| error()));
+		let major = parts.next().ok_or_else(error)??;
+		let minor = parts.next().ok_or_else(error)??;
+		let patch = parts.next().ok_or_else(error)??;
+		if parts.next().is_some() {
+			return Err(error());
+		}
+		Ok(Version::new(major, minor, patch))
+	}
+}
+
+impl std::fmt::Display for Version {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -33,7 +96,7 @@ mod tests {
 	#[test]
 	fn should_provide_unicode_version() {
 		let version = unicode_version();
-		assert!(version != "");
+		assert!(!version.is_empty());
 
 		let parts = version.split(".");
 		let parts = parts.collect::<Vec<_>>();
@@ -46,4 +109,39 @@ mod tests {
 
 		assert!(parts[0] >= 14);
 	}
+
+	#[test]
+	fn should_provide_unicode_version_parts() {
+		let (major, minor, patch) = unicode_version_parts();
+		assert!(major >= 14);
+		assert_eq!(format!("{}.{}.{}", major, minor, patch), unicode_version());
+		assert_eq!(Version::current(), Version::new(major, minor, patch));
+	}
+
+	#[test]
+	fn parses_version_string() {
+		assert_eq!(Version::parse("15.1.0"), Ok(Version::new(15, 1, 0)));
+		assert_eq!(Version::parse("6.3.12"), Ok(Version::new(6, 3, 12)));
+		assert_eq!(Version::new(15, 1, 0).to_string(), "15.1.0");
+	}
+
+	#[test]
+	fn parse_invalid_version_is_error() {
+		assert_eq!(
+			Version::parse(""),
+			Err("`` is not a valid version".to_string())
+		);
+		assert!(Version::parse("15").is_err());
+		assert!(Version::parse("15.1").is_err());
+		assert!(Version::parse("15.1.0.0").is_err());
+		assert!(Version::parse("15.x.0").is_err());
+	}
+
+	#[test]
+	fn versions_are_ordered() {
+		assert!(Version::new(15, 1, 0) > Version::new(15, 0, 0));
+		assert!(Version::new(15, 0, 0) > Version::new(14, 9, 9));
+		assert!(Version::new(9, 0, 0) < Version::new(10, 0, 0));
+		assert!(Version::default() < Version::new(0, 0, 1));
+	}
 }
@@ -0,0 +1,203 @@
+use ucd_property_ranges::{PropertyKey, RangeTable};
+
+use crate::{
+	input::{Input, InputFile},
+	parse::{parse_range_line, OrUnknown},
+};
+
+/// Values for the `Word_Break` property used by the word boundary rules in
+/// Unicode Text Segmentation.
+///
+/// See https://www.unicode.org/reports/tr29/#Word_Boundaries
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WordBreak {
+	/// Default for codepoints not listed in the data file.
+	Other,
+	/// `CR` carriage return.
+	CR,
+	/// `LF` line feed.
+	LF,
+	/// `Newline`: vertical tab, form feed, and line/paragraph separators.
+	Newline,
+	/// `Extend`: combining marks and other grapheme extenders.
+	Extend,
+	/// `ZWJ` zero width joiner.
+	ZWJ,
+	/// `Regional_Indicator` symbols used for flag sequences.
+	RegionalIndicator,
+	/// `Format` characters.
+	Format,
+	/// `Katakana` characters.
+	Katakana,
+	/// `Hebrew_Letter` characters.
+	HebrewLetter,
+	/// `ALetter` alphabetic characters.
+	ALetter,
+	/// `Single_Quote` apostrophe.
+	SingleQuote,
+	/// `Double_Quote` quotation mark.
+	DoubleQuote,
+	/// `MidNumLet`: full stop and similar.
+	MidNumLet,
+	/// `MidLetter`: colon, middle dot, and similar.
+	MidLetter,
+	/// `MidNum`: comma, semicolon, and similar.
+	MidNum,
+	/// `Numeric` digits.
+	Numeric,
+	/// `ExtendNumLet`: connector punctuation such as the low line.
+	ExtendNumLet,
+	/// `WSegSpace`: whitespace that is not a line break.
+	WSegSpace,
+}
+
+impl WordBreak {
+	pub fn parse<T: AsRef<str>>(input: T) -> Option<Self> {
+		let value = match input.as_ref() {
+			"Other" => WordBreak::Other,
+			"CR" => WordBreak::CR,
+			"LF" => WordBreak::LF,
+			"Newline" => WordBreak::Newline,
+			"Extend" => WordBreak::Extend,
+			"ZWJ" => WordBreak::ZWJ,
+			"Regional_Indicator" => WordBreak::RegionalIndicator,
+			"Format" => WordBreak::Format,
+			"Katakana" => WordBreak::Katakana,
+			"Hebrew_Letter" => WordBreak::HebrewLetter,
+			"ALetter" => WordBreak::ALetter,
+			"Single_Quote" => WordBreak::SingleQuote,
+			"Double_Quote" => WordBreak::DoubleQuote,
+			"MidNumLet" => WordBreak::MidNumLet,
+			"MidLetter" => WordBreak::MidLetter,
+			"MidNum" => WordBreak::MidNum,
+			"Numeric" => WordBreak::Numeric,
+			"ExtendNumLet" => WordBreak::ExtendNumLet,
+			"WSegSpace" => WordBreak::WSegSpace,
+			_ => return None,
+		};
+		Some(value)
+	}
+
+	/// Same as [`WordBreak::parse`], but returns [`OrUnknown::Unknown`] with the
+	/// input text for unrecognized values instead of [`None`].
+	pub fn parse_or_unknown<T: AsRef<str>>(input: T) -> OrUnknown<Self> {
+		OrUnknown::parse_with(input.as_ref(), |x| Self::parse(x))
+	}
+
+	/// Build a [`RangeTable`] with the [`WordBreakProperty`] for the entire
+	/// codespace from `WordBreakProperty.txt`.
+	///
+	/// Codepoints not listed in the file are set to [`WordBreak::Other`],
+	/// which is the `@missing` default declared by the file.
+	///
+	/// Note that the `Format` and `Extend` values are taken as-is from the
+	/// explicit entries in the file, which already account for the general
+	/// category of those characters.
+	pub fn build_range_table() -> RangeTable {
+		let mut table = RangeTable::new();
+		Self::set_ranges(&mut table);
+		table
+	}
+
+	/// Set the [`WordBreakProperty`] for the entire codespace in an
+	/// existing table. See [`WordBreak::build_range_table`].
+	pub fn set_ranges(table: &mut RangeTable) {
+		table.set_range(0..=0x10FFFF, WordBreakProperty, WordBreak::Other);
+
+		let input = Input::get(InputFile::WordBreakProperty);
+		for line in input.lines() {
+			let (sta, end, value) = parse_range_line(line).unwrap();
+			let value = WordBreak::parse(value)
+				.unwrap_or_else(|| panic!("invalid word break `{}` -- in `{}`", value, line));
+			table.set_range(sta..=end, WordBreakProperty, value);
+		}
+	}
+}
+
+impl std::fmt::Display for WordBreak {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let output = match self {
+			WordBreak::Other => "Other",
+			WordBreak::CR => "CR",
+			WordBreak::LF => "LF",
+			WordBreak::Newline => "Newline",
+			WordBreak::Extend => "Extend",
+			WordBreak::ZWJ => "ZWJ",
+			WordBreak::RegionalIndicator => "Regional_Indicator",
+			WordBreak::Format => "Format",
+			WordBreak::Katakana => "Katakana",
+			WordBreak::HebrewLetter => "Hebrew_Letter",
+			WordBreak::ALetter => "ALetter",
+			WordBreak::SingleQuote => "Single_Quote",
+			WordBreak::DoubleQuote => "Double_Quote",
+			WordBreak::MidNumLet => "MidNumLet",
+			WordBreak::MidLetter => "MidLetter",
+			WordBreak::MidNum => "MidNum",
+			WordBreak::Numeric => "Numeric",
+			WordBreak::ExtendNumLet => "ExtendNumLet",
+			WordBreak::WSegSpace => "WSegSpace",
+		};
+		write!(f, "{}", output)
+	}
+}
+
+/// [`PropertyKey`] for [`WordBreak`] values in a [`RangeTable`].
+#[derive(Clone, PartialEq)]
+pub struct WordBreakProperty;
+
+impl PropertyKey for WordBreakProperty {
+	type Value = WordBreak;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn supports_to_string() {
+		fn check(input: WordBreak, expected: &'static str) {
+			assert_eq!(input.to_string(), expected);
+			assert_eq!(WordBreak::parse(input.to_string()).expect(expected), input);
+		}
+
+		check(WordBreak::Other, "Other");
+		check(WordBreak::CR, "CR");
+		check(WordBreak::LF, "LF");
+		check(WordBreak::Newline, "Newline");
+		check(WordBreak::Extend, "Extend");
+		check(WordBreak::ZWJ, "ZWJ");
+		check(WordBreak::RegionalIndicator, "Regional_Indicator");
+		check(WordBreak::Format, "Format");
+		check(WordBreak::Katakana, "Katakana");
+		check(WordBreak::HebrewLetter, "Hebrew_Letter");
+		check(WordBreak::ALetter, "ALetter");
+		check(WordBreak::SingleQuote, "Single_Quote");
+		check(WordBreak::DoubleQuote, "Double_Quote");
+		check(WordBreak::MidNumLet, "MidNumLet");
+		check(WordBreak::MidLetter, "MidLetter");
+		check(WordBreak::MidNum, "MidNum");
+		check(WordBreak::Numeric, "Numeric");
+		check(WordBreak::ExtendNumLet, "ExtendNumLet");
+		check(WordBreak::WSegSpace, "WSegSpace");
+	}
+
+	#[test]
+	fn parse_from_invalid_string_is_none() {
+		assert_eq!(WordBreak::parse("xx"), None);
+	}
+
+	#[test]
+	fn builds_table_from_ucd() {
+		let table = WordBreak::build_range_table();
+		let value = |code: u32| table.find(code).and_then(|x| x.get(WordBreakProperty));
+
+		// unlisted codepoints use the default
+		assert_eq!(value(0x0021), Some(WordBreak::Other));
+		assert_eq!(value(0x10FFFF), Some(WordBreak::Other));
+
+		assert_eq!(value(0x0041), Some(WordBreak::ALetter));
+		assert_eq!(value(0x00AD), Some(WordBreak::Format));
+		assert_eq!(value(0x200D), Some(WordBreak::ZWJ));
+		assert_eq!(value(0x0301), Some(WordBreak::Extend));
+	}
+}
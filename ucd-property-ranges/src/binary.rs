@@ -0,0 +1,287 @@
+//! Compact binary serialization for single-property tables.
+//!
+//! This is intended for caching parsed data between build runs, so there
+//! is no compatibility guarantee across versions of this crate.
+//!
+//! The format starts with the [`MAGIC`] bytes, followed by the table of
+//! distinct values and the list of ranges, with each range referencing its
+//! value by index. All integers are little-endian `u32`:
+//!
+//! ```text
+//! MAGIC
+//! VALUE_COUNT VALUE...
+//! RANGE_COUNT (FIRST LAST VALUE_INDEX)...
+//! ```
+
+use crate::{PropertyKey, RangeTable};
+
+/// Header for the binary format, including the format version.
+pub const MAGIC: &[u8; 4] = b"URT\x01";
+
+/// Trait for property values that can be written by [`RangeTable::to_bytes`].
+pub trait Encode {
+	fn encode(&self, output: &mut Vec<u8>);
+}
+
+/// Trait for property values that can be read by [`RangeTable::from_bytes`].
+pub trait Decode: Sized {
+	/// Decode a value from the start of the input, advancing it past the
+	/// value.
+	fn decode(input: &mut &[u8]) -> Result<Self, String>;
+}
+
+impl Encode for u32 {
+	fn encode(&self, output: &mut Vec<u8>) {
+		output.extend_from_slice(&self.to_le_bytes());
+	}
+}
+
+impl Decode for u32 {
+	fn decode(input: &mut &[u8]) -> Result<Self, String> {
+		if input.len() < 4 {
+			return Err("unexpected end of input".to_string());
+		}
+		let (bytes, rest) = input.split_at(4);
+		*input = rest;
+		Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+	}
+}
+
+impl Encode for bool {
+	fn encode(&self, output: &mut Vec<u8>) {
+		output.push(*self as u8);
+	}
+}
+
+impl Decode for bool {
+	fn decode(input: &mut &[u8]) -> Result<Self, String> {
+		let (value, rest) = input
+			.split_first()
+			.ok_or_else(|| "unexpected end of input".to_string())?;
+		*input = rest;
+		match value {
+			0 => Ok(false),
+			1 => Ok(true),
+			_ => Err(format!("invalid bool value {}", value)),
+		}
+	}
+}
+
+impl Encode for &'static str {
+	fn encode(&self, output: &mut Vec<u8>) {
+		(self.len() as u32).encode(output);
+		output.extend_from_slice(self.as_bytes());
+	}
+}
+
+/// Decoded strings are leaked to provide the `'static` lifetime. Since
+/// values are stored only once in the table, this leaks one string for
+/// each distinct value.
+impl Decode for &'static str {
+	fn decode(input: &mut &[u8]) -> Result<Self, String> {
+		let len = u32::decode(input)? as usize;
+		if input.len() < len {
+			return Err("unexpected end of input".to_string());
+		}
+		let (bytes, rest) = input.split_at(len);
+		*input = rest;
+		let text = std::str::from_utf8(bytes).map_err(|err| format!("invalid string ({})", err))?;
+		Ok(Box::leak(text.to_string().into_boxed_str()))
+	}
+}
+
+impl RangeTable {
+	/// Serialize a single property from the table to bytes.
+	///
+	/// Only ranges that have the property set are included. Use
+	/// [`RangeTable::from_bytes`] to load the table back.
+	///
+	/// ```
+	/// # use ucd_property_ranges::*;
+	/// #[derive(Clone, PartialEq)]
+	/// struct Key;
+	///
+	/// impl PropertyKey for Key {
+	///     type Value = u32;
+	/// }
+	///
+	/// let mut table = RangeTable::new();
+	/// table.set_range(0..10, Key, 42);
+	///
+	/// let bytes = table.to_bytes(Key);
+	/// let table = RangeTable::from_bytes(&bytes, Key).unwrap();
+	/// assert_eq!(table.lookup(5, Key), Some(42));
+	/// ```
+	pub fn to_bytes<T: PropertyKey>(&self, key: T) -> Vec<u8>
+	where
+		T::Value: Encode,
+	{
+		let mut values: Vec<T::Value> = Vec::new();
+		let mut ranges = Vec::new();
+		for row in self.rows() {
+			if let Some(value) = row.get_ref(key.clone()) {
+				let index = match values.iter().position(|x| x == value) {
+					Some(index) => index,
+					None => {
+						values.push(value.clone());
+						values.len() - 1
+					}
+				};
+				ranges.push((row.first, row.last, index as u32));
+			}
+		}
+
+		let mut output = MAGIC.to_vec();
+		(values.len() as u32).encode(&mut output);
+		for value in values.iter() {
+			value.encode(&mut output);
+		}
+		(ranges.len() as u32).encode(&mut output);
+		for (first, last, index) in ranges {
+			first.encode(&mut output);
+			last.encode(&mut output);
+			index.encode(&mut output);
+		}
+		output
+	}
+
+	/// Load a table with a single property from the bytes generated by
+	/// [`RangeTable::to_bytes`].
+	///
+	/// Returns an error if the input is truncated or invalid.
+	pub fn from_bytes<T: PropertyKey>(bytes: &[u8], key: T) -> Result<RangeTable, String>
+	where
+		T::Value: Decode,
+	{
+		let error = |err: String| format!("RangeTable: invalid binary data ({})", err);
+		let mut input = bytes
+			.strip_prefix(MAGIC.as_slice())
+			.ok_or_else(|| error("invalid header".to_string()))?;
+
+		let value_count = u32::decode(&mut input).map_err(error)?;
+		let mut values = Vec::new();
+		for _ in 0..value_count {
+			values.push(T::Value::decode(&mut input).map_err(error)?);
+		}
+
+		let mut table = RangeTable::new();
+		let range_count = u32::decode(&mut input).map_err(error)?;
+		let mut next_first = 0;
+		for _ in 0..range_count {
+			let first = u32::decode(&mut input).map_err(error)?;
+			let last = u32::decode(&mut input).map_err(error)?;
+			let index = u32::decode(&mut input).map_err(error)?;
+			if first < next_first || last < first || last > 0x10FFFF {
+				return Err(error(format!("invalid range {:04X}..{:04X}", first, last)));
+			}
+			let value = values
+				.get(index as usize)
+				.ok_or_else(|| error(format!("invalid value index {}", index)))?;
+			table.set_range(first..=last, key.clone(), value.clone());
+			next_first = last + 1;
+		}
+
+		if !input.is_empty() {
+			return Err(error(format!("{} trailing bytes", input.len())));
+		}
+		Ok(table)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[derive(Clone, PartialEq)]
+	struct Name;
+
+	impl PropertyKey for Name {
+		type Value = &'static str;
+	}
+
+	#[derive(Clone, PartialEq)]
+	struct Flag;
+
+	impl PropertyKey for Flag {
+		type Value = bool;
+	}
+
+	#[test]
+	fn round_trips_table() {
+		let mut table = RangeTable::new();
+		table.set_range(0x00..=0x1F, Name, "control");
+		table.set_range(0x41..=0x5A, Name, "upper");
+		table.set_range(0x61..=0x7A, Name, "lower");
+		table.set_range(0x7F..=0x9F, Name, "control");
+		table.set_range(0x10FFFF..=0x10FFFF, Name, "last");
+		table.set_range(0x30..=0x39, Flag, true);
+
+		let bytes = table.to_bytes(Name);
+		let loaded = RangeTable::from_bytes(&bytes, Name).unwrap();
+		assert_eq!(loaded.collect_property(Name), {
+			let mut expected = table.collect_property(Name);
+			expected.retain(|x| x.1.is_some());
+			expected
+		});
+		assert_eq!(loaded.lookup(0x30, Flag), None);
+
+		let bytes = table.to_bytes(Flag);
+		let loaded = RangeTable::from_bytes(&bytes, Flag).unwrap();
+		assert_eq!(loaded.count(), 1);
+		assert_eq!(loaded.lookup(0x35, Flag), Some(true));
+	}
+
+	#[test]
+	fn stores_distinct_values_once() {
+		let mut table = RangeTable::new();
+		table.set_range(0x00..=0x1F, Name, "control");
+		table.set_range(0x41..=0x5A, Name, "upper");
+		table.set_range(0x7F..=0x9F, Name, "control");
+
+		let bytes = table.to_bytes(Name);
+		let text = String::from_utf8_lossy(&bytes);
+		assert_eq!(text.matches("control").count(), 1);
+	}
+
+	#[test]
+	fn invalid_input_returns_error() {
+		let mut table = RangeTable::new();
+		table.set_range(0x00..=0x1F, Name, "control");
+		table.set_range(0x41..=0x5A, Name, "upper");
+
+		let bytes = table.to_bytes(Name);
+		for len in 0..bytes.len() {
+			let err = RangeTable::from_bytes(&bytes[..len], Name).err().unwrap();
+			assert!(err.starts_with("RangeTable: invalid binary data"));
+		}
+
+		let err = RangeTable::from_bytes(b"XYZ\x01", Name).err().unwrap();
+		assert!(err.contains("invalid header"));
+
+		let mut trailing = bytes.clone();
+		trailing.push(0);
+		let err = RangeTable::from_bytes(&trailing, Name).err().unwrap();
+		assert!(err.contains("1 trailing bytes"));
+
+		// a range pointing at a missing value
+		let mut bytes = MAGIC.to_vec();
+		0u32.encode(&mut bytes);
+		1u32.encode(&mut bytes);
+		for value in [0u32, 10, 0] {
+			value.encode(&mut bytes);
+		}
+		let err = RangeTable::from_bytes(&bytes, Name).err().unwrap();
+		assert!(err.contains("invalid value index 0"));
+
+		// a range past the Unicode codespace
+		let mut bytes = MAGIC.to_vec();
+		1u32.encode(&mut bytes);
+		true.encode(&mut bytes);
+		1u32.encode(&mut bytes);
+		for value in [0u32, 0x110000, 0] {
+			value.encode(&mut bytes);
+		}
+		let err = RangeTable::from_bytes(&bytes, Flag).err().unwrap();
+		assert!(err.contains("invalid range 0000..110000"));
+	}
+}
@@ -0,0 +1,1024 @@
+//! Helpers for generating Rust source code from the property data.
+
+use std::io::{self, Write};
+
+use crate::{ranges::CodepointRangeMap, PropertyKey, RangeTable};
+
+/// Trait for property values that can be emitted as Rust source code.
+///
+/// ```
+/// # use ucd_property_ranges::codegen::ToRustLiteral;
+/// assert_eq!(<Option<u8>>::rust_type(), "Option<u8>");
+/// assert_eq!(Some(42u8).to_rust_literal(), "Some(42)");
+/// assert_eq!("a \"b\"".to_rust_literal(), r#""a \"b\"""#);
+/// ```
+pub trait ToRustLiteral {
+	/// Rust type for the values.
+	fn rust_type() -> String;
+
+	/// Rust literal expression for the value.
+	fn to_rust_literal(&self) -> String;
+}
+
+macro_rules! impl_to_rust_literal {
+	($($type:ty),*) => {
+		$(
+			impl ToRustLiteral for $type {
+				fn rust_type() -> String {
+					stringify!($type).to_string()
+				}
+
+				fn to_rust_literal(&self) -> String {
+					format!("{:?}", self)
+				}
+			}
+		)*
+	};
+}
+
+impl_to_rust_literal!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, bool, char);
+
+impl ToRustLiteral for &'static str {
+	fn rust_type() -> String {
+		"&'static str".to_string()
+	}
+
+	fn to_rust_literal(&self) -> String {
+		format!("{:?}", self)
+	}
+}
+
+impl<T: ToRustLiteral> ToRustLiteral for Option<T> {
+	fn rust_type() -> String {
+		format!("Option<{}>", T::rust_type())
+	}
+
+	fn to_rust_literal(&self) -> String {
+		match self {
+			Some(value) => format!("Some({})", value.to_rust_literal()),
+			None => "None".to_string(),
+		}
+	}
+}
+
+/// Emit the source for a function `fn_name(code: u32) -> Option<Value>`
+/// that maps codepoints to a property's values from the table, using a
+/// `match` with an arm for each range that has the property set.
+///
+/// ```
+/// # use ucd_property_ranges::{codegen::emit_match_fn, *};
+/// #[derive(Clone, PartialEq)]
+/// struct Key;
+///
+/// impl PropertyKey for Key {
+///     type Value = bool;
+/// }
+///
+/// let mut table = RangeTable::new();
+/// table.set_range(0x30..=0x39, Key, true);
+/// let code = emit_match_fn(&table, "is_digit", Key);
+/// assert!(code.contains("pub fn is_digit(code: u32) -> Option<bool> {"));
+/// assert!(code.contains("\t\t0x0030..=0x0039 => Some(true),\n"));
+/// ```
+pub fn emit_match_fn<T: PropertyKey>(table: &RangeTable, fn_name: &str, key: T) -> String
+where
+	T::Value: ToRustLiteral,
+{
+	to_string(|w| write_match_fn(w, table, fn_name, key))
+}
+
+/// Same as [`emit_match_fn`], but writes the output directly to `w`.
+pub fn write_match_fn<W: Write, T: PropertyKey>(
+	w: &mut W,
+	table: &RangeTable,
+	fn_name: &str,
+	key: T,
+) -> io::Result<()>
+where
+	T::Value: ToRustLiteral,
+{
+	writeln!(
+		w,
+		"pub fn {}(code: u32) -> Option<{}> {{",
+		fn_name,
+		T::Value::rust_type()
+	)?;
+	writeln!(w, "\tmatch code {{")?;
+	let width = table.max_hex_width();
+	for row in table.rows() {
+		if let Some(value) = row.get(key.clone()) {
+			let value = value.to_rust_literal();
+			if row.first == row.last {
+				writeln!(w, "\t\t0x{:0width$X} => Some({}),", row.first, value)?;
+			} else {
+				writeln!(
+					w,
+					"\t\t0x{:0width$X}..=0x{:0width$X} => Some({}),",
+					row.first, row.last, value,
+				)?;
+			}
+		}
+	}
+	writeln!(w, "\t\t_ => None,")?;
+	writeln!(w, "\t}}")?;
+	writeln!(w, "}}")
+}
+
+/// Emit the source for a `pub enum` with the given variants.
+///
+/// Each variant can have an optional doc string, which is emitted as `///`
+/// comments for the variant (one per line for multi-line strings):
+///
+/// ```
+/// # use ucd_property_ranges::codegen::emit_enum;
+/// let code = emit_enum(
+///     "Category",
+///     &[("Lu", Some("`Lu` Uppercase_Letter")), ("Ll", None)],
+/// );
+/// assert!(code.contains("pub enum Category {"));
+/// assert!(code.contains("\t/// `Lu` Uppercase_Letter\n\tLu,\n"));
+/// ```
+pub fn emit_enum(name: &str, variants: &[(&str, Option<&str>)]) -> String {
+	to_string(|w| write_enum(w, name, variants))
+}
+
+/// Same as [`emit_enum`], but writes the output directly to `w`.
+pub fn write_enum<W: Write>(
+	w: &mut W,
+	name: &str,
+	variants: &[(&str, Option<&str>)],
+) -> io::Result<()> {
+	writeln!(w, "#[derive(Copy, Clone, Debug, PartialEq, Eq)]")?;
+	writeln!(w, "pub enum {} {{", name)?;
+	for (variant, doc) in variants {
+		if let Some(doc) = doc {
+			for line in doc.lines() {
+				if line.is_empty() {
+					writeln!(w, "\t///")?;
+				} else {
+					writeln!(w, "\t/// {}", line)?;
+				}
+			}
+		}
+		writeln!(w, "\t{},", variant)?;
+	}
+	writeln!(w, "}}")
+}
+
+impl<T: Default + Clone + ToRustLiteral> CodepointRangeMap<T> {
+	/// Emit the source for a static sorted array with the ranges in the map
+	/// and a function doing a binary search on the array to lookup the value
+	/// for a codepoint.
+	///
+	/// The array is named after `name` in uppercase and contains a tuple of
+	/// `(first, last, value)` for each range, with inclusive bounds. Values
+	/// are emitted using [`ToRustLiteral`] and must be `Copy`:
+	///
+	/// ```
+	/// # use ucd_property_ranges::ranges::CodepointRangeMap;
+	/// let mut map = CodepointRangeMap::default();
+	/// map.set(0x30, 0x39, |v| *v = 1u8);
+	/// map.set(0x41, 0x5A, |v| *v = 2u8);
+	///
+	/// let code = map.emit_slice_table("class");
+	/// assert_eq!(
+	///     code,
+	///     concat!(
+	///         "pub static CLASS: [(u32, u32, u8); 2] = [\n",
+	///         "\t(0x0030, 0x0039, 1),\n",
+	///         "\t(0x0041, 0x005A, 2),\n",
+	///         "];\n",
+	///         "\n",
+	///         "pub fn class(code: u32) -> Option<u8> {\n",
+	///         "\tlet index = CLASS.binary_search_by(|&(first, last, _)| {\n",
+	///         "\t\tif code < first {\n",
+	///         "\t\t\tstd::cmp::Ordering::Greater\n",
+	///         "\t\t} else if code > last {\n",
+	///         "\t\t\tstd::cmp::Ordering::Less\n",
+	///         "\t\t} else {\n",
+	///         "\t\t\tstd::cmp::Ordering::Equal\n",
+	///         "\t\t}\n",
+	///         "\t});\n",
+	///         "\tindex.ok().map(|index| CLASS[index].2)\n",
+	///         "}\n",
+	///     )
+	/// );
+	/// ```
+	pub fn emit_slice_table(&self, name: &str) -> String {
+		to_string(|w| self.write_slice_table(w, name))
+	}
+
+	/// Same as [`emit_slice_table`](CodepointRangeMap::emit_slice_table),
+	/// but writes the output directly to `w`.
+	pub fn write_slice_table<W: Write>(&self, w: &mut W, name: &str) -> io::Result<()> {
+		let table_name = name.to_uppercase();
+		let value_type = T::rust_type();
+
+		writeln!(
+			w,
+			"pub static {}: [(u32, u32, {}); {}] = [",
+			table_name,
+			value_type,
+			self.count()
+		)?;
+		let last = self.iter().next_back().map(|x| x.last).unwrap_or(0);
+		let width = format!("{:04X}", last).len();
+		for range in self.iter() {
+			writeln!(
+				w,
+				"\t(0x{:0width$X}, 0x{:0width$X}, {}),",
+				range.first,
+				range.last,
+				range.value.to_rust_literal()
+			)?;
+		}
+		writeln!(w, "];")?;
+		writeln!(w)?;
+		writeln!(w, "pub fn {}(code: u32) -> Option<{}> {{", name, value_type)?;
+		writeln!(
+			w,
+			"\tlet index = {}.binary_search_by(|&(first, last, _)| {{",
+			table_name
+		)?;
+		writeln!(w, "\t\tif code < first {{")?;
+		writeln!(w, "\t\t\tstd::cmp::Ordering::Greater")?;
+		writeln!(w, "\t\t}} else if code > last {{")?;
+		writeln!(w, "\t\t\tstd::cmp::Ordering::Less")?;
+		writeln!(w, "\t\t}} else {{")?;
+		writeln!(w, "\t\t\tstd::cmp::Ordering::Equal")?;
+		writeln!(w, "\t\t}}")?;
+		writeln!(w, "\t}});")?;
+		writeln!(w, "\tindex.ok().map(|index| {}[index].2)", table_name)?;
+		writeln!(w, "}}")
+	}
+}
+
+/// Options for [`emit_two_stage`] and [`write_two_stage`].
+pub struct TwoStageOptions<'a> {
+	/// Name for the generated lookup function. The arrays are named after it
+	/// in uppercase.
+	pub name: &'a str,
+
+	/// Number of bits for the codepoints in each block, i.e. the block size
+	/// is `1 << block_bits`.
+	pub block_bits: u32,
+}
+
+impl<'a> TwoStageOptions<'a> {
+	/// Options with the given name and the default block size of 128.
+	pub fn new(name: &'a str) -> Self {
+		TwoStageOptions {
+			name,
+			block_bits: 7,
+		}
+	}
+}
+
+/// In-memory representation of a two-stage lookup table for a property.
+///
+/// The codespace is split into blocks of `1 << block_bits` codepoints. The
+/// first stage maps each block to an index in the second stage, which holds
+/// the values for the distinct blocks. Codepoints past the last block have
+/// no value.
+pub struct TwoStageTable<V> {
+	pub block_bits: u32,
+	pub stage1: Vec<usize>,
+	pub stage2: Vec<Option<V>>,
+}
+
+impl<V: Clone + PartialEq> TwoStageTable<V> {
+	/// Build the two-stage table for a property in a [`RangeTable`].
+	///
+	/// Panics if `block_bits` is not in the `1..=16` range.
+	pub fn build<T: PropertyKey<Value = V>>(table: &RangeTable, key: T, block_bits: u32) -> Self {
+		assert!(
+			(1..=16).contains(&block_bits),
+			"TwoStageTable: block_bits must be in 1..=16, got {}",
+			block_bits
+		);
+		let block_size = 1usize << block_bits;
+		let len = table.rows().next_back().map(|x| x.last as usize + 1);
+		let len = len.unwrap_or(0);
+
+		let mut stage1 = Vec::new();
+		let mut stage2: Vec<Option<V>> = Vec::new();
+		for block_start in (0..len).step_by(block_size) {
+			let block = (block_start..block_start + block_size)
+				.map(|code| table.lookup(code as u32, key.clone()))
+				.collect::<Vec<_>>();
+			let existing = stage2
+				.chunks(block_size)
+				.position(|x| x == block.as_slice());
+			let index = match existing {
+				Some(index) => index,
+				None => {
+					stage2.extend(block);
+					stage2.len() / block_size - 1
+				}
+			};
+			stage1.push(index);
+		}
+
+		TwoStageTable {
+			block_bits,
+			stage1,
+			stage2,
+		}
+	}
+
+	/// Lookup the value for a codepoint.
+	pub fn get(&self, code: u32) -> Option<V> {
+		let block = *self.stage1.get((code >> self.block_bits) as usize)?;
+		let mask = (1usize << self.block_bits) - 1;
+		self.stage2[(block << self.block_bits) | (code as usize & mask)].clone()
+	}
+}
+
+/// Emit the source for a two-stage lookup table with a property's values.
+///
+/// This generates the `NAME_INDEX` and `NAME_DATA` static arrays and a
+/// `name(code: u32) -> Option<Value>` lookup function. See [`TwoStageTable`]
+/// for the table structure.
+///
+/// ```
+/// # use ucd_property_ranges::{codegen::*, *};
+/// #[derive(Clone, PartialEq)]
+/// struct Key;
+///
+/// impl PropertyKey for Key {
+///     type Value = u8;
+/// }
+///
+/// let mut table = RangeTable::new();
+/// table.set_range(0x30..=0x39, Key, 1);
+/// let code = emit_two_stage(&table, Key, &TwoStageOptions::new("digit"));
+/// assert!(code.contains("pub static DIGIT_INDEX: [u8; 1] = ["));
+/// assert!(code.contains("pub static DIGIT_DATA: [Option<u8>; 128] = ["));
+/// assert!(code.contains("pub fn digit(code: u32) -> Option<u8> {"));
+/// ```
+pub fn emit_two_stage<T: PropertyKey>(table: &RangeTable, key: T, opts: &TwoStageOptions) -> String
+where
+	T::Value: ToRustLiteral,
+{
+	to_string(|w| write_two_stage(w, table, key, opts))
+}
+
+/// Same as [`emit_two_stage`], but writes the output directly to `w`.
+pub fn write_two_stage<W: Write, T: PropertyKey>(
+	w: &mut W,
+	table: &RangeTable,
+	key: T,
+	opts: &TwoStageOptions,
+) -> io::Result<()>
+where
+	T::Value: ToRustLiteral,
+{
+	const ITEMS_PER_LINE: usize = 16;
+
+	let two_stage = TwoStageTable::build(table, key, opts.block_bits);
+	let table_name = opts.name.to_uppercase();
+	let value_type = <Option<T::Value>>::rust_type();
+
+	let block_count = two_stage.stage2.len() >> opts.block_bits;
+	let index_type = if block_count <= 0x100 {
+		"u8"
+	} else if block_count <= 0x10000 {
+		"u16"
+	} else {
+		"u32"
+	};
+
+	writeln!(
+		w,
+		"pub static {}_INDEX: [{}; {}] = [",
+		table_name,
+		index_type,
+		two_stage.stage1.len()
+	)?;
+	for line in two_stage.stage1.chunks(ITEMS_PER_LINE) {
+		let line = line.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+		writeln!(w, "\t{},", line.join(", "))?;
+	}
+	writeln!(w, "];")?;
+	writeln!(w)?;
+
+	writeln!(
+		w,
+		"pub static {}_DATA: [{}; {}] = [",
+		table_name,
+		value_type,
+		two_stage.stage2.len()
+	)?;
+	for line in two_stage.stage2.chunks(ITEMS_PER_LINE) {
+		let line = line.iter().map(|x| x.to_rust_literal()).collect::<Vec<_>>();
+		writeln!(w, "\t{},", line.join(", "))?;
+	}
+	writeln!(w, "];")?;
+	writeln!(w)?;
+
+	writeln!(w, "pub fn {}(code: u32) -> {} {{", opts.name, value_type)?;
+	writeln!(
+		w,
+		"\tlet block = match {}_INDEX.get((code >> {}) as usize) {{",
+		table_name, opts.block_bits
+	)?;
+	writeln!(w, "\t\tSome(block) => *block as usize,")?;
+	writeln!(w, "\t\tNone => return None,")?;
+	writeln!(w, "\t}};")?;
+	writeln!(
+		w,
+		"\t{}_DATA[(block << {}) | (code as usize & 0x{:X})]",
+		table_name,
+		opts.block_bits,
+		(1u32 << opts.block_bits) - 1
+	)?;
+	writeln!(w, "}}")
+}
+
+impl RangeTable {
+	/// Emit the source for a two-stage trie with the values of a property in
+	/// the table, using blocks of `1 << block_bits` codepoints.
+	///
+	/// This is a shorthand for [`emit_two_stage`] with the given name and
+	/// block size. Identical blocks are only emitted once. Panics if
+	/// `block_bits` is not in the `1..=16` range.
+	///
+	/// ```
+	/// # use ucd_property_ranges::*;
+	/// #[derive(Clone, PartialEq)]
+	/// struct Key;
+	///
+	/// impl PropertyKey for Key {
+	///     type Value = u8;
+	/// }
+	///
+	/// let mut table = RangeTable::new();
+	/// table.set_range(0x30..=0x39, Key, 1);
+	/// let code = table.emit_trie(Key, "digit", 5);
+	/// assert!(code.contains("pub static DIGIT_INDEX: [u8; 2] = [\n\t0, 1,\n];"));
+	/// assert!(code.contains("pub static DIGIT_DATA: [Option<u8>; 64] = ["));
+	/// assert!(code.contains("pub fn digit(code: u32) -> Option<u8> {"));
+	/// ```
+	pub fn emit_trie<T: PropertyKey>(&self, key: T, name: &str, block_bits: u32) -> String
+	where
+		T::Value: ToRustLiteral,
+	{
+		to_string(|w| self.write_trie(w, key, name, block_bits))
+	}
+
+	/// Same as [`emit_trie`](RangeTable::emit_trie), but writes the output
+	/// directly to `w`.
+	pub fn write_trie<W: Write, T: PropertyKey>(
+		&self,
+		w: &mut W,
+		key: T,
+		name: &str,
+		block_bits: u32,
+	) -> io::Result<()>
+	where
+		T::Value: ToRustLiteral,
+	{
+		let opts = TwoStageOptions { name, block_bits };
+		write_two_stage(w, self, key, &opts)
+	}
+}
+
+/// Check a two-stage lookup against the source table for every codepoint in
+/// the `0..=0x10FFFF` range.
+///
+/// The lookup can be either the function generated by [`emit_two_stage`],
+/// compiled as part of a build test, or [`TwoStageTable::get`] as an
+/// in-memory reference implementation.
+///
+/// This will panic on the first codepoint where the lookup does not match
+/// [`RangeTable::lookup`].
+pub fn verify_two_stage<T: PropertyKey, F: Fn(u32) -> Option<T::Value>>(
+	table: &RangeTable,
+	key: T,
+	generated_lookup: F,
+) where
+	T::Value: std::fmt::Debug,
+{
+	for code in 0..=0x10FFFF {
+		let expected = table.lookup(code, key.clone());
+		let actual = generated_lookup(code);
+		if actual != expected {
+			panic!(
+				"two-stage lookup mismatch at codepoint {:04X} (expected {:?}, got {:?})",
+				code, expected, actual
+			);
+		}
+	}
+}
+
+/// Emit the source for a compact lookup returning a per-value index for a
+/// property, along with the array of values for each index.
+///
+/// This generates a `NAME_VALUES` static array with the distinct values
+/// (including [`None`] for unmapped codepoints, if any) sorted by frequency
+/// as in [`RangeTable::values_by_frequency`], and a `name(code: u32) -> u8`
+/// function so that `NAME_VALUES[name(code) as usize]` is the value for the
+/// codepoint.
+///
+/// The most frequent value is the fallback in the lookup, so it does not
+/// need any match arms.
+///
+/// This will panic if the property has more than 256 distinct values.
+///
+/// ```
+/// # use ucd_property_ranges::{codegen::*, *};
+/// #[derive(Clone, PartialEq)]
+/// struct Key;
+///
+/// impl PropertyKey for Key {
+///     type Value = bool;
+/// }
+///
+/// let mut table = RangeTable::new();
+/// table.set_range(0x30..=0x39, Key, true);
+/// let code = emit_index_lookup(&table, Key, "digit");
+/// assert!(code.contains("pub static DIGIT_VALUES: [Option<bool>; 2] = ["));
+/// assert!(code.contains("\t\t0x0030..=0x0039 => 1,\n"));
+/// ```
+pub fn emit_index_lookup<T: PropertyKey>(table: &RangeTable, key: T, name: &str) -> String
+where
+	T::Value: ToRustLiteral,
+{
+	to_string(|w| write_index_lookup(w, table, key, name))
+}
+
+/// Same as [`emit_index_lookup`], but writes the output directly to `w`.
+pub fn write_index_lookup<W: Write, T: PropertyKey>(
+	w: &mut W,
+	table: &RangeTable,
+	key: T,
+	name: &str,
+) -> io::Result<()>
+where
+	T::Value: ToRustLiteral,
+{
+	let mut values = table
+		.values_by_frequency(key.clone())
+		.into_iter()
+		.map(|(value, count)| (Some(value), count))
+		.collect::<Vec<_>>();
+	let mapped = values.iter().map(|(_, count)| count).sum::<usize>();
+	let unmapped = 0x110000 - mapped;
+	if unmapped > 0 {
+		let index = values.partition_point(|(_, count)| *count >= unmapped);
+		values.insert(index, (None, unmapped));
+	}
+	if values.len() > 0x100 {
+		panic!("emit_index_lookup: too many values ({})", values.len());
+	}
+
+	let index_of = |value: &Option<T::Value>| values.iter().position(|(it, _)| it == value);
+	let table_name = name.to_uppercase();
+	writeln!(
+		w,
+		"pub static {}_VALUES: [{}; {}] = [",
+		table_name,
+		<Option<T::Value>>::rust_type(),
+		values.len()
+	)?;
+	for (value, _) in values.iter() {
+		writeln!(w, "\t{},", value.to_rust_literal())?;
+	}
+	writeln!(w, "];")?;
+	writeln!(w)?;
+
+	writeln!(w, "pub fn {}(code: u32) -> u8 {{", name)?;
+	writeln!(w, "\tmatch code {{")?;
+
+	// cover the entire codespace, including the gaps between rows which are
+	// unmapped, so that the fallback arm is only used for the fallback value
+	let mut ranges: Vec<(u32, u32, usize)> = Vec::new();
+	let mut next = 0;
+	for row in table.rows() {
+		if row.first > next {
+			ranges.push((next, row.first - 1, index_of(&None).unwrap()));
+		}
+		ranges.push((
+			row.first,
+			row.last,
+			index_of(&row.get(key.clone())).unwrap(),
+		));
+		next = row.last + 1;
+	}
+	if next <= 0x10FFFF {
+		ranges.push((next, 0x10FFFF, index_of(&None).unwrap()));
+	}
+
+	// merge contiguous ranges with the same index, skipping the fallback
+	let mut arms: Vec<(u32, u32, usize)> = Vec::new();
+	for (first, last, value_index) in ranges {
+		match arms.last_mut() {
+			Some(arm) if arm.1 + 1 == first && arm.2 == value_index => {
+				arm.1 = last;
+			}
+			_ => arms.push((first, last, value_index)),
+		}
+	}
+
+	let width = table.max_hex_width();
+	for (first, last, index) in arms.into_iter().filter(|x| x.2 != 0) {
+		if first == last {
+			writeln!(w, "\t\t0x{:0width$X} => {},", first, index)?;
+		} else {
+			writeln!(
+				w,
+				"\t\t0x{:0width$X}..=0x{:0width$X} => {},",
+				first, last, index
+			)?;
+		}
+	}
+	writeln!(w, "\t\t_ => 0,")?;
+	writeln!(w, "\t}}")?;
+	writeln!(w, "}}")
+}
+
+/// Run a `write_*` function into a [`String`].
+fn to_string<F: FnOnce(&mut Vec<u8>) -> io::Result<()>>(write: F) -> String {
+	let mut output = Vec::new();
+	write(&mut output).expect("writing to a Vec should not fail");
+	String::from_utf8(output).expect("generated code should be valid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use tux::assert_panic;
+
+	#[derive(Clone, PartialEq)]
+	struct Name;
+
+	impl PropertyKey for Name {
+		type Value = &'static str;
+	}
+
+	#[derive(Clone, PartialEq)]
+	struct Number;
+
+	impl PropertyKey for Number {
+		type Value = Option<u32>;
+	}
+
+	fn sample_table() -> RangeTable {
+		let mut table = RangeTable::new();
+		table.set_range(0x30..=0x39, Name, "digit");
+		table.set_range(0x41..=0x5A, Name, "upper");
+		table.set_range(0x5F..=0x5F, Name, "under \"score\"");
+		table.set_range(0x30..=0x31, Number, Some(1));
+		table.set_range(0x41..=0x41, Number, None);
+		table
+	}
+
+	#[test]
+	fn emits_rust_literals() {
+		assert_eq!(u8::rust_type(), "u8");
+		assert_eq!(42u8.to_rust_literal(), "42");
+		assert_eq!((-1i32).to_rust_literal(), "-1");
+		assert_eq!(bool::rust_type(), "bool");
+		assert_eq!(true.to_rust_literal(), "true");
+		assert_eq!('a'.to_rust_literal(), "'a'");
+		assert_eq!(<&str>::rust_type(), "&'static str");
+		assert_eq!("a\nb".to_rust_literal(), "\"a\\nb\"");
+		assert_eq!(<Option<bool>>::rust_type(), "Option<bool>");
+		assert_eq!(Some(false).to_rust_literal(), "Some(false)");
+		assert_eq!(None::<bool>.to_rust_literal(), "None");
+	}
+
+	#[test]
+	fn emits_match_fn() {
+		let code = emit_match_fn(&sample_table(), "name", Name);
+		assert_eq!(code, include_str!("../testdata/codegen/match_fn_name.rs"));
+
+		let code = emit_match_fn(&sample_table(), "number", Number);
+		assert_eq!(code, include_str!("../testdata/codegen/match_fn_number.rs"));
+	}
+
+	#[test]
+	fn emits_match_fn_padded_to_max_width() {
+		let mut table = RangeTable::new();
+		table.set_range(0x41..=0x41, Name, "a");
+		table.set_range(0x1F600..=0x1F64F, Name, "emoji");
+		let code = emit_match_fn(&table, "name", Name);
+		assert!(code.contains("\t\t0x00041 => Some(\"a\"),\n"));
+		assert!(code.contains("\t\t0x1F600..=0x1F64F => Some(\"emoji\"),\n"));
+	}
+
+	fn sample_map() -> CodepointRangeMap<&'static str> {
+		// ranges are set out of order and overlapping on purpose
+		let mut map = CodepointRangeMap::default();
+		map.set(0x41, 0x5A, |v| *v = "upper");
+		map.set(0x30, 0x39, |v| *v = "digit");
+		map.set(0x4E00, 0x9FFF, |v| *v = "ideograph");
+		map.set(0x5A, 0x61, |v| *v = "other");
+		map
+	}
+
+	// the included files are the expected output for the emit functions
+	#[allow(clippy::redundant_static_lifetimes)]
+	mod generated {
+		include!("../testdata/codegen/match_fn_name.rs");
+		include!("../testdata/codegen/match_fn_number.rs");
+		include!("../testdata/codegen/slice_table.rs");
+		include!("../testdata/codegen/two_stage.rs");
+		include!("../testdata/codegen/trie.rs");
+		include!("../testdata/codegen/index_lookup.rs");
+	}
+
+	#[test]
+	fn emits_slice_table() {
+		let code = sample_map().emit_slice_table("sample");
+		assert_eq!(code, include_str!("../testdata/codegen/slice_table.rs"));
+	}
+
+	#[test]
+	fn slice_table_pads_codepoints_to_max_width() {
+		let mut map = sample_map();
+		map.set(0x1F600, 0x1F64F, |v| *v = "emoji");
+		let code = map.emit_slice_table("sample");
+		assert!(code.contains("\t(0x00030, 0x00039, \"digit\"),\n"));
+		assert!(code.contains("\t(0x1F600, 0x1F64F, \"emoji\"),\n"));
+
+		map.set(0x100000, 0x10FFFD, |v| *v = "private");
+		let code = map.emit_slice_table("sample");
+		assert!(code.contains("\t(0x000030, 0x000039, \"digit\"),\n"));
+		assert!(code.contains("\t(0x100000, 0x10FFFD, \"private\"),\n"));
+	}
+
+	#[test]
+	fn emitted_slice_table_is_sorted_and_compiles() {
+		use generated::*;
+
+		assert_eq!(SAMPLE.len(), sample_map().count());
+		for (first, last, _) in SAMPLE.iter() {
+			assert!(first <= last);
+		}
+		for pair in SAMPLE.windows(2) {
+			assert!(pair[0].1 < pair[1].0);
+		}
+
+		assert_eq!(sample(0x2F), None);
+		assert_eq!(sample(0x30), Some("digit"));
+		assert_eq!(sample(0x41), Some("upper"));
+		assert_eq!(sample(0x59), Some("upper"));
+		assert_eq!(sample(0x5A), Some("other"));
+		assert_eq!(sample(0x61), Some("other"));
+		assert_eq!(sample(0x62), None);
+		assert_eq!(sample(0x6C34), Some("ideograph"));
+		assert_eq!(sample(0xA000), None);
+	}
+
+	#[test]
+	fn emitted_match_fn_compiles() {
+		use generated::*;
+
+		assert_eq!(name(0x2F), None);
+		assert_eq!(name(0x30), Some("digit"));
+		assert_eq!(name(0x39), Some("digit"));
+		assert_eq!(name(0x41), Some("upper"));
+		assert_eq!(name(0x5F), Some("under \"score\""));
+		assert_eq!(name(0x60), None);
+
+		assert_eq!(number(0x30), Some(Some(1)));
+		assert_eq!(number(0x32), None);
+		assert_eq!(number(0x41), Some(None));
+	}
+
+	#[test]
+	fn emits_enum() {
+		let code = emit_enum("Value", &[("A", None), ("B", None)]);
+		assert_eq!(
+			code,
+			concat!(
+				"#[derive(Copy, Clone, Debug, PartialEq, Eq)]\n",
+				"pub enum Value {\n",
+				"\tA,\n",
+				"\tB,\n",
+				"}\n",
+			)
+		);
+	}
+
+	#[test]
+	fn emits_enum_variant_docs() {
+		let code = emit_enum(
+			"Category",
+			&[
+				("Lu", Some("`Lu` Uppercase_Letter")),
+				("Ll", None),
+				("Lt", Some("`Lt` Titlecase_Letter\n\nSecond paragraph.")),
+			],
+		);
+		assert_eq!(
+			code,
+			concat!(
+				"#[derive(Copy, Clone, Debug, PartialEq, Eq)]\n",
+				"pub enum Category {\n",
+				"\t/// `Lu` Uppercase_Letter\n",
+				"\tLu,\n",
+				"\tLl,\n",
+				"\t/// `Lt` Titlecase_Letter\n",
+				"\t///\n",
+				"\t/// Second paragraph.\n",
+				"\tLt,\n",
+				"}\n",
+			)
+		);
+	}
+
+	fn two_stage_options() -> TwoStageOptions<'static> {
+		TwoStageOptions {
+			name: "stage_name",
+			block_bits: 4,
+		}
+	}
+
+	#[test]
+	fn emits_two_stage() {
+		let code = emit_two_stage(&sample_table(), Name, &two_stage_options());
+		assert_eq!(code, include_str!("../testdata/codegen/two_stage.rs"));
+	}
+
+	#[test]
+	fn emitted_two_stage_compiles() {
+		use generated::*;
+
+		let table = sample_table();
+		let two_stage = TwoStageTable::build(&table, Name, 4);
+		assert_eq!(STAGE_NAME_INDEX.len(), two_stage.stage1.len());
+		assert_eq!(STAGE_NAME_DATA.len(), two_stage.stage2.len());
+		for code in 0..0x100 {
+			assert_eq!(stage_name(code), table.lookup(code, Name));
+			assert_eq!(two_stage.get(code), table.lookup(code, Name));
+		}
+		assert_eq!(stage_name(0x10FFFF), None);
+	}
+
+	#[test]
+	fn verifies_two_stage_lookup() {
+		let table = sample_table();
+		let reference = TwoStageTable::build(&table, Name, 4);
+		verify_two_stage(&table, Name, |code| reference.get(code));
+		verify_two_stage(&table, Name, generated::stage_name);
+	}
+
+	#[test]
+	fn verify_two_stage_panics_on_mismatch() {
+		assert_panic!("two-stage lookup mismatch at codepoint 005F" in {
+			let table = sample_table();
+			verify_two_stage(&table, Name, |code| {
+				if code == 0x5F {
+					None
+				} else {
+					table.lookup(code, Name)
+				}
+			});
+		});
+	}
+
+	#[test]
+	fn two_stage_table_shares_blocks() {
+		let mut table = RangeTable::new();
+		table.set_range(0x00..=0x0F, Name, "a");
+		table.set_range(0x20..=0x2F, Name, "a");
+		let two_stage = TwoStageTable::build(&table, Name, 4);
+		assert_eq!(two_stage.stage1, vec![0, 1, 0]);
+		assert_eq!(two_stage.stage2.len(), 32);
+	}
+
+	#[test]
+	fn emits_trie() {
+		let code = sample_table().emit_trie(Name, "trie_name", 5);
+		assert_eq!(code, include_str!("../testdata/codegen/trie.rs"));
+	}
+
+	#[test]
+	fn emitted_trie_returns_table_values() {
+		use generated::*;
+
+		let table = sample_table();
+		assert_eq!(TRIE_NAME_INDEX.len(), 3);
+		assert_eq!(TRIE_NAME_DATA.len(), 3 * 32);
+		for code in [
+			0x00, 0x2F, 0x30, 0x39, 0x3A, 0x40, 0x41, 0x5A, 0x5F, 0x60, 0x10FFFF,
+		] {
+			assert_eq!(trie_name(code), table.lookup(code, Name));
+		}
+		verify_two_stage(&table, Name, trie_name);
+	}
+
+	#[test]
+	fn trie_block_size_is_configurable() {
+		let table = sample_table();
+		for block_bits in [2, 4, 6, 8] {
+			let code = table.emit_trie(Name, "name", block_bits);
+			let two_stage = TwoStageTable::build(&table, Name, block_bits);
+			let data = format!(
+				"pub static NAME_DATA: [Option<&'static str>; {}] = [",
+				two_stage.stage2.len()
+			);
+			assert!(code.contains(&data));
+			assert!(code.contains(&format!("(code >> {}) as usize", block_bits)));
+		}
+	}
+
+	#[test]
+	fn trie_rejects_invalid_block_bits() {
+		assert_panic!("block_bits must be in 1..=16, got 0" in {
+			sample_table().emit_trie(Name, "name", 0);
+		});
+		assert_panic!("block_bits must be in 1..=16, got 17" in {
+			TwoStageTable::build(&sample_table(), Name, 17);
+		});
+	}
+
+	#[test]
+	fn emits_index_lookup() {
+		let code = emit_index_lookup(&sample_table(), Name, "name_index");
+		assert_eq!(code, include_str!("../testdata/codegen/index_lookup.rs"));
+	}
+
+	#[test]
+	fn emitted_index_lookup_reproduces_values() {
+		use generated::*;
+
+		let table = sample_table();
+		for code in [
+			0x00, 0x2F, 0x30, 0x39, 0x3A, 0x41, 0x5A, 0x5F, 0x60, 0x10FFFF,
+		] {
+			let value = NAME_INDEX_VALUES[name_index(code) as usize];
+			assert_eq!(value, table.lookup(code, Name));
+		}
+	}
+
+	#[test]
+	fn index_lookup_maps_gaps_when_fallback_is_some() {
+		#[derive(Clone, PartialEq)]
+		struct Flag;
+
+		impl PropertyKey for Flag {
+			type Value = bool;
+		}
+
+		let mut table = RangeTable::new();
+		table.set_range(0..=0x10FFFE, Flag, true);
+		let code = emit_index_lookup(&table, Flag, "flag");
+		assert!(code.contains("\tSome(true),\n\tNone,\n"));
+		assert!(code.contains("\t\t0x10FFFF => 1,\n\t\t_ => 0,\n"));
+
+		let mut table = RangeTable::new();
+		table.set_range(0x100..=0x10FFFF, Flag, true);
+		table.set_range(0x20..=0x2F, Flag, false);
+		let code = emit_index_lookup(&table, Flag, "flag");
+		assert!(code.contains("\tSome(true),\n\tNone,\n\tSome(false),\n"));
+		assert!(code.contains("\t\t0x000000..=0x00001F => 1,\n"));
+		assert!(code.contains("\t\t0x000020..=0x00002F => 2,\n"));
+		assert!(code.contains("\t\t0x000030..=0x0000FF => 1,\n\t\t_ => 0,\n"));
+	}
+
+	#[test]
+	fn index_lookup_merges_ranges_with_same_index() {
+		let mut table = RangeTable::new();
+		table.set_range(0..=0x10FFFF, Number, None);
+		table.set_range(0x30..=0x39, Number, Some(1));
+		table.set_range(0x32..=0x32, Name, "two");
+		let code = emit_index_lookup(&table, Number, "number");
+		assert!(code.contains("pub static NUMBER_VALUES: [Option<Option<u32>>; 2] = ["));
+		assert!(code.contains("\t\t0x000030..=0x000039 => 1,\n\t\t_ => 0,\n"));
+	}
+
+	#[test]
+	fn writers_match_emit_functions() {
+		let table = sample_table();
+
+		let mut output = Vec::new();
+		write_match_fn(&mut output, &table, "name", Name).unwrap();
+		assert_eq!(output, emit_match_fn(&table, "name", Name).into_bytes());
+
+		let variants = [("A", Some("doc")), ("B", None)];
+		let mut output = Vec::new();
+		write_enum(&mut output, "Value", &variants).unwrap();
+		assert_eq!(output, emit_enum("Value", &variants).into_bytes());
+
+		let mut output = Vec::new();
+		sample_map()
+			.write_slice_table(&mut output, "sample")
+			.unwrap();
+		assert_eq!(output, sample_map().emit_slice_table("sample").into_bytes());
+
+		let opts = two_stage_options();
+		let mut output = Vec::new();
+		write_two_stage(&mut output, &table, Name, &opts).unwrap();
+		assert_eq!(output, emit_two_stage(&table, Name, &opts).into_bytes());
+
+		let mut output = Vec::new();
+		write_index_lookup(&mut output, &table, Name, "name_index").unwrap();
+		assert_eq!(
+			output,
+			emit_index_lookup(&table, Name, "name_index").into_bytes()
+		);
+	}
+}
@@ -2,3 +2,9 @@ mod table;
 pub use table::*;
 
 pub mod ranges;
+
+pub mod codegen;
+
+pub mod report;
+
+pub mod binary;
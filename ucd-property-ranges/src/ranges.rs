@@ -1,3 +1,5 @@
+use std::{cmp::Ordering, collections::BTreeMap, sync::OnceLock};
+
 /// Single range in a [`CodepointRangeMap`].
 #[derive(Clone, Eq, PartialEq)]
 pub struct CodepointRange<T> {
@@ -6,6 +8,46 @@ pub struct CodepointRange<T> {
 	pub value: T,
 }
 
+impl<T> CodepointRange<T> {
+	/// Compare two ranges by their `(first, last)` bounds, ignoring the value.
+	///
+	/// This can be used to sort ranges by position with `sort_by`.
+	pub fn cmp_bounds(&self, other: &Self) -> Ordering {
+		(self.first, self.last).cmp(&(other.first, other.last))
+	}
+}
+
+/// Ranges are ordered by their bounds (see [`CodepointRange::cmp_bounds`]),
+/// then by their value.
+impl<T: Ord> Ord for CodepointRange<T> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.cmp_bounds(other)
+			.then_with(|| self.value.cmp(&other.value))
+	}
+}
+
+impl<T: Ord> PartialOrd for CodepointRange<T> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<T> std::fmt::Display for CodepointRange<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{:04X}..{:04X}", self.first, self.last)
+	}
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for CodepointRange<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"{:04X}..{:04X} => {:?}",
+			self.first, self.last, self.value
+		)
+	}
+}
+
 /// Map ranges of [`u32`] codepoints to their respective values.
 ///
 /// This map supports building a sorted list of codepoint ranges mapping to
@@ -28,7 +70,9 @@ pub struct CodepointRange<T> {
 /// assert!(map.get(2) == &CodepointRange{ first: 6, last: 9, value: 25  });
 /// ```
 pub struct CodepointRangeMap<T: Default + Clone> {
-	ranges: Vec<CodepointRange<T>>,
+	ranges: BTreeMap<u32, CodepointRange<T>>,
+	// sorted copy of the ranges for indexed access, cleared on changes
+	sorted: OnceLock<Vec<CodepointRange<T>>>,
 }
 
 impl<T: Default + Clone> CodepointRangeMap<T> {
@@ -36,6 +80,12 @@ impl<T: Default + Clone> CodepointRangeMap<T> {
 		self.ranges.len()
 	}
 
+	/// Return the total number of codepoints covered by the ranges.
+	pub fn codepoint_count(&self) -> u64 {
+		let counts = self.iter().map(|x| (x.last - x.first) as u64 + 1);
+		counts.sum()
+	}
+
 	/// Set the value for an inclusive range using an updater function.
 	///
 	/// If the input range overlaps existing ranges, this will split the input
@@ -44,82 +94,178 @@ impl<T: Default + Clone> CodepointRangeMap<T> {
 	/// For each sub-range, the updater will receive a mutable reference for
 	/// the current value of that sub-range. If the sub-range is not yet mapped,
 	/// then the reference value will be the default.
+	///
+	/// This will panic if the range is invalid or goes past `U+10FFFF`. Note
+	/// that surrogate codepoints are accepted.
 	pub fn set<Fn: FnMut(&mut T)>(&mut self, first: u32, last: u32, mut updater: Fn) {
 		if last < first {
 			panic!("CodepointRangeMap: invalid range (last < first)");
 		}
+		if last > 0x10FFFF {
+			panic!(
+				"CodepointRangeMap: invalid range (last {:04X} is greater than 10FFFF)",
+				last
+			);
+		}
 
-		// this is very un-optimized, but we are only supposed to be used
-		// during code generation, so unless build times become an issue we
-		// should be okay
-
-		let mut entries_to_add = Vec::new();
+		self.sorted.take();
 		let mut next_first = first;
-		for range in self.ranges.iter_mut() {
+		for key in self.overlapping_keys(first, last) {
+			let mut range = self.ranges.remove(&key).unwrap();
+
 			let needs_range_before = next_first < range.first;
 			if needs_range_before {
-				let mut new_value = T::default();
-				updater(&mut new_value);
-
-				let last = std::cmp::min(range.first - 1, last);
-				entries_to_add.push(CodepointRange {
-					first: next_first,
-					last,
-					value: new_value,
-				});
+				let mut value = T::default();
+				updater(&mut value);
+				self.insert(next_first, range.first - 1, value);
 				next_first = range.first;
 			}
 
-			let overlaps_current = next_first <= range.last && last >= range.first;
-			if overlaps_current {
-				let update_from = next_first;
-				next_first = range.last + 1;
-
-				let range_to_update = range;
-
-				let has_unchanged_prefix = update_from > range_to_update.first;
-				if has_unchanged_prefix {
-					let mut prefix = range_to_update.clone();
-					prefix.last = update_from - 1;
-					range_to_update.first = update_from;
-					entries_to_add.push(prefix);
-				}
-
-				let has_unchanged_suffix = last < range_to_update.last;
-				if has_unchanged_suffix {
-					let mut suffix = range_to_update.clone();
-					suffix.first = last + 1;
-					range_to_update.last = last;
-					entries_to_add.push(suffix);
-				}
-
-				updater(&mut range_to_update.value);
+			let has_unchanged_prefix = next_first > range.first;
+			if has_unchanged_prefix {
+				self.insert(range.first, next_first - 1, range.value.clone());
+				range.first = next_first;
 			}
 
-			if next_first > last {
-				break;
+			let has_unchanged_suffix = last < range.last;
+			if has_unchanged_suffix {
+				self.insert(last + 1, range.last, range.value.clone());
+				range.last = last;
 			}
+
+			next_first = range.last + 1;
+			updater(&mut range.value);
+			self.ranges.insert(range.first, range);
 		}
 
 		if next_first <= last {
 			let mut value = T::default();
 			updater(&mut value);
-			entries_to_add.push(CodepointRange {
-				first: next_first,
-				last,
-				value,
-			});
+			self.insert(next_first, last, value);
+		}
+	}
+
+	/// Append a range after the last range in the map.
+	///
+	/// This is a fast path to build a map from ranges that are already sorted
+	/// and don't overlap, since it doesn't need to split existing ranges. In
+	/// debug builds, this will panic if the range is invalid or is not after
+	/// the last range.
+	pub fn push(&mut self, first: u32, last: u32, value: T) {
+		debug_assert!(
+			first <= last && last <= 0x10FFFF,
+			"CodepointRangeMap: invalid range ({:04X}..{:04X})",
+			first,
+			last
+		);
+		debug_assert!(
+			self.ranges.values().next_back().map(|x| x.last < first) != Some(false),
+			"CodepointRangeMap: pushed range {:04X}..{:04X} is not after the last range",
+			first,
+			last
+		);
+		self.sorted.take();
+		self.insert(first, last, value);
+	}
+
+	/// Remove an inclusive range from the map.
+	///
+	/// Existing ranges overlapping the removed range are deleted or trimmed,
+	/// and a range containing the removed range is split in two.
+	pub fn remove(&mut self, first: u32, last: u32) {
+		if last < first {
+			panic!("CodepointRangeMap: invalid range (last < first)");
+		}
+
+		self.sorted.take();
+		for key in self.overlapping_keys(first, last) {
+			let range = self.ranges.remove(&key).unwrap();
+			if range.first < first {
+				self.insert(range.first, first - 1, range.value.clone());
+			}
+			if range.last > last {
+				self.insert(last + 1, range.last, range.value);
+			}
 		}
-		self.ranges.append(&mut entries_to_add);
-		self.ranges.sort_by_key(|x| x.first);
 	}
 
 	/// Get the value for a given range. This will panic if the index is out
 	/// of bounds.
 	///
 	/// Note that ranges don't overlap and are stored in sorted order.
+	///
+	/// Indexed access uses a sorted copy of the ranges, which is built on the
+	/// first call after the map is changed.
 	pub fn get(&self, index: usize) -> &CodepointRange<T> {
-		&self.ranges[index]
+		let sorted = self
+			.sorted
+			.get_or_init(|| self.ranges.values().cloned().collect());
+		match sorted.get(index) {
+			Some(range) => range,
+			None => panic!(
+				"CodepointRangeMap: index {} out of bounds (count is {})",
+				index,
+				self.count()
+			),
+		}
+	}
+
+	/// Iterate over the ranges in sorted order.
+	pub fn iter(&self) -> impl DoubleEndedIterator<Item = &CodepointRange<T>> {
+		self.ranges.values()
+	}
+
+	/// Find the range containing the given codepoint, or [`None`] if the
+	/// codepoint is not mapped.
+	pub fn find(&self, code: u32) -> Option<&CodepointRange<T>> {
+		let (_, range) = self.ranges.range(..=code).next_back()?;
+		Some(range).filter(|x| x.last >= code)
+	}
+
+	/// Return the value for the range containing the given codepoint, or
+	/// [`None`] if the codepoint is not mapped.
+	pub fn value_at(&self, code: u32) -> Option<&T> {
+		self.find(code).map(|x| &x.value)
+	}
+
+	/// Return the ranges overlapping the inclusive range, in sorted order.
+	pub(crate) fn overlapping(
+		&self,
+		first: u32,
+		last: u32,
+	) -> impl Iterator<Item = &CodepointRange<T>> {
+		let start = self.find(first).map(|x| x.first).unwrap_or(first);
+		self.ranges.range(start..=last).map(|(_, x)| x)
+	}
+
+	fn overlapping_keys(&self, first: u32, last: u32) -> Vec<u32> {
+		self.overlapping(first, last).map(|x| x.first).collect()
+	}
+
+	fn insert(&mut self, first: u32, last: u32, value: T) {
+		let range = CodepointRange { first, last, value };
+		self.ranges.insert(first, range);
+	}
+}
+
+impl<T: Default + Clone + PartialEq> CodepointRangeMap<T> {
+	/// Merge adjacent ranges that have equal values.
+	///
+	/// Setting values can leave contiguous ranges with the same value split
+	/// apart, so this can be used to minimize the number of ranges.
+	pub fn coalesce(&mut self) {
+		self.sorted.take();
+		let mut merged: Vec<CodepointRange<T>> = Vec::with_capacity(self.ranges.len());
+		for range in std::mem::take(&mut self.ranges).into_values() {
+			if let Some(prev) = merged.last_mut() {
+				if prev.last + 1 == range.first && prev.value == range.value {
+					prev.last = range.last;
+					continue;
+				}
+			}
+			merged.push(range);
+		}
+		self.ranges = merged.into_iter().map(|x| (x.first, x)).collect();
 	}
 }
 
@@ -127,6 +273,7 @@ impl<T: Default + Clone> Default for CodepointRangeMap<T> {
 	fn default() -> Self {
 		CodepointRangeMap {
 			ranges: Default::default(),
+			sorted: Default::default(),
 		}
 	}
 }
@@ -142,6 +289,376 @@ mod test_codepoint_map {
 		assert_eq!(map.count(), 0);
 	}
 
+	#[test]
+	fn supports_large_number_of_ranges() {
+		const COUNT: u32 = 50_000;
+
+		// insert disjoint ranges out of order, then split each one
+		let mut map = CodepointRangeMap::default();
+		for index in 0..COUNT {
+			let code = (index * 7919 % COUNT) * 4;
+			map.set(code, code + 2, |v| *v = code);
+		}
+		for index in 0..COUNT {
+			let code = index * 4;
+			map.set(code + 1, code + 1, |v| *v += 1);
+		}
+
+		assert_eq!(map.count(), COUNT as usize * 3);
+		assert_eq!(map.codepoint_count(), COUNT as u64 * 3);
+		assert_eq!(map.find(4).map(|x| x.value), Some(4));
+		assert_eq!(map.find(5).map(|x| x.value), Some(5));
+		assert_eq!(map.find(7).map(|x| x.value), None);
+
+		let ranges = map.iter().collect::<Vec<_>>();
+		assert!(ranges.windows(2).all(|x| x[0].last < x[1].first));
+		for (index, range) in ranges.into_iter().enumerate() {
+			assert!(map.get(index) == range);
+		}
+	}
+
+	#[test]
+	fn get_reflects_changes_to_the_map() {
+		let mut map = CodepointRangeMap::default();
+		map.set(10, 19, |v| *v = 1);
+		assert!(
+			map.get(0)
+				== &CodepointRange {
+					first: 10,
+					last: 19,
+					value: 1
+				}
+		);
+
+		map.set(0, 9, |v| *v = 2);
+		assert!(
+			map.get(0)
+				== &CodepointRange {
+					first: 0,
+					last: 9,
+					value: 2
+				}
+		);
+		assert!(
+			map.get(1)
+				== &CodepointRange {
+					first: 10,
+					last: 19,
+					value: 1
+				}
+		);
+
+		map.push(30, 39, 1);
+		assert!(
+			map.get(2)
+				== &CodepointRange {
+					first: 30,
+					last: 39,
+					value: 1
+				}
+		);
+
+		map.remove(0, 9);
+		assert!(
+			map.get(0)
+				== &CodepointRange {
+					first: 10,
+					last: 19,
+					value: 1
+				}
+		);
+
+		map.set(20, 29, |v| *v = 1);
+		map.coalesce();
+		assert_eq!(map.count(), 1);
+		assert!(
+			map.get(0)
+				== &CodepointRange {
+					first: 10,
+					last: 39,
+					value: 1
+				}
+		);
+	}
+
+	#[test]
+	fn value_at_returns_value_for_codepoint() {
+		let mut map = CodepointRangeMap::default();
+		map.set(10, 19, |v| *v = 1);
+		map.set(20, 29, |v| *v = 2);
+		map.set(40, 40, |v| *v = 4);
+
+		assert_eq!(map.value_at(10), Some(&1));
+		assert_eq!(map.value_at(19), Some(&1));
+		assert_eq!(map.value_at(20), Some(&2));
+		assert_eq!(map.value_at(40), Some(&4));
+		assert_eq!(map.value_at(9), None);
+		assert_eq!(map.value_at(30), None);
+		assert_eq!(map.value_at(41), None);
+
+		for code in 0..=50 {
+			assert_eq!(map.value_at(code), map.find(code).map(|x| &x.value));
+		}
+	}
+
+	#[test]
+	fn get_out_of_bounds_panics() {
+		let mut map = CodepointRangeMap::default();
+		map.set(0, 1, |v| *v = 1);
+		assert_panic!("index 1 out of bounds" in {
+			map.get(1);
+		});
+	}
+
+	#[test]
+	fn range_supports_display_and_debug() {
+		let range = CodepointRange {
+			first: 1,
+			last: 255,
+			value: (),
+		};
+		assert_eq!(range.to_string(), "0001..00FF");
+		assert_eq!(format!("{:?}", range), "0001..00FF => ()");
+
+		let range = CodepointRange {
+			first: 0x10000,
+			last: 0x10FFFF,
+			value: "abc",
+		};
+		assert_eq!(range.to_string(), "10000..10FFFF");
+		assert_eq!(format!("{:?}", range), "10000..10FFFF => \"abc\"");
+	}
+
+	#[test]
+	fn ranges_are_ordered_by_bounds() {
+		let range = |first, last, value| CodepointRange { first, last, value };
+		let mut ranges = vec![
+			range(30, 39, 'c'),
+			range(0, 9, 'z'),
+			range(10, 19, 'a'),
+			range(10, 12, 'b'),
+			range(0x10000, 0x10FFFF, 'x'),
+		];
+
+		let mut sorted = ranges.clone();
+		sorted.sort();
+		ranges.sort_by(CodepointRange::cmp_bounds);
+		assert_eq!(sorted, ranges);
+
+		let bounds = ranges.iter().map(|x| (x.first, x.last)).collect::<Vec<_>>();
+		assert_eq!(
+			bounds,
+			vec![(0, 9), (10, 12), (10, 19), (30, 39), (0x10000, 0x10FFFF)]
+		);
+
+		assert_eq!(
+			range(1, 2, 'a').cmp_bounds(&range(1, 2, 'b')),
+			Ordering::Equal
+		);
+		assert!(range(1, 2, 'a') < range(1, 2, 'b'));
+		assert_eq!(range(1, 2, 'a').cmp(&range(1, 2, 'a')), Ordering::Equal);
+		assert!(range(1, 2, 'z') < range(1, 3, 'a'));
+		assert!(range(2, 2, 'a') > range(1, 9, 'z'));
+	}
+
+	#[test]
+	fn push_appends_sorted_ranges() {
+		let mut map = CodepointRangeMap::default();
+		map.push(0, 9, 1);
+		map.push(20, 29, 2);
+		map.push(30, 0x10FFFF, 3);
+		assert_eq!(map.count(), 3);
+		assert_eq!(map.value_at(25), Some(&2));
+		assert_eq!(map.value_at(15), None);
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	fn push_overlapping_range_panics() {
+		assert_panic!("pushed range 000F..0014 is not after the last range" in {
+			let mut map = CodepointRangeMap::default();
+			map.push(10, 19, 1);
+			map.push(15, 20, 2);
+		});
+	}
+
+	#[test]
+	fn find_returns_range_containing_codepoint() {
+		let mut map = CodepointRangeMap::default();
+		map.set(10, 19, |v| *v = 1);
+		map.set(30, 39, |v| *v = 3);
+
+		assert!(map.find(9).is_none());
+		assert_eq!(map.find(10).map(|x| x.value), Some(1));
+		assert_eq!(map.find(19).map(|x| x.value), Some(1));
+		assert!(map.find(20).is_none());
+		assert!(map.find(29).is_none());
+		assert_eq!(map.find(30).map(|x| x.value), Some(3));
+		assert_eq!(map.find(39).map(|x| x.value), Some(3));
+		assert!(map.find(40).is_none());
+	}
+
+	#[test]
+	fn codepoint_count_sums_ranges() {
+		let mut map = CodepointRangeMap::default();
+		assert_eq!(map.codepoint_count(), 0);
+
+		map.set(10, 19, |v| *v = 1);
+		map.set(0x41, 0x41, |v| *v = 2);
+		assert_eq!(map.codepoint_count(), 11);
+
+		map.set(0, 0x10FFFF, |v| *v += 1);
+		assert_eq!(map.codepoint_count(), 0x110000);
+	}
+
+	#[test]
+	fn coalesce_merges_adjacent_equal_ranges() {
+		let mut map = CodepointRangeMap::default();
+		map.set(0, 9, |v| *v = 1);
+		map.set(10, 19, |v| *v = 1);
+		map.set(20, 29, |v| *v = 2);
+		map.set(31, 39, |v| *v = 2);
+		map.set(40, 49, |v| *v = 2);
+		map.coalesce();
+
+		assert_eq!(map.count(), 3);
+		assert!(
+			map.get(0)
+				== &CodepointRange {
+					first: 0,
+					last: 19,
+					value: 1
+				}
+		);
+		assert!(
+			map.get(1)
+				== &CodepointRange {
+					first: 20,
+					last: 29,
+					value: 2
+				}
+		);
+		assert!(
+			map.get(2)
+				== &CodepointRange {
+					first: 31,
+					last: 49,
+					value: 2
+				}
+		);
+	}
+
+	#[test]
+	fn remove_deletes_whole_range() {
+		let mut map = CodepointRangeMap::default();
+		map.set(10, 19, |v| *v = 1);
+		map.set(30, 39, |v| *v = 3);
+		map.remove(5, 25);
+
+		assert_eq!(map.count(), 1);
+		assert!(map.find(10).is_none());
+		assert!(map.find(19).is_none());
+		assert!(
+			map.get(0)
+				== &CodepointRange {
+					first: 30,
+					last: 39,
+					value: 3
+				}
+		);
+	}
+
+	#[test]
+	fn remove_trims_range_edges() {
+		let mut map = CodepointRangeMap::default();
+		map.set(10, 19, |v| *v = 1);
+		map.set(20, 29, |v| *v = 2);
+		map.remove(15, 24);
+
+		assert_eq!(map.count(), 2);
+		assert!(
+			map.get(0)
+				== &CodepointRange {
+					first: 10,
+					last: 14,
+					value: 1
+				}
+		);
+		assert!(
+			map.get(1)
+				== &CodepointRange {
+					first: 25,
+					last: 29,
+					value: 2
+				}
+		);
+		assert!(map.find(15).is_none());
+		assert!(map.find(24).is_none());
+	}
+
+	#[test]
+	fn remove_splits_range() {
+		let mut map = CodepointRangeMap::default();
+		map.set(10, 29, |v| *v = 1);
+		map.remove(15, 19);
+
+		assert_eq!(map.count(), 2);
+		assert!(
+			map.get(0)
+				== &CodepointRange {
+					first: 10,
+					last: 14,
+					value: 1
+				}
+		);
+		assert!(
+			map.get(1)
+				== &CodepointRange {
+					first: 20,
+					last: 29,
+					value: 1
+				}
+		);
+		assert!(map.find(17).is_none());
+		assert_eq!(map.find(20).map(|x| x.value), Some(1));
+	}
+
+	#[test]
+	fn remove_unmapped_range_is_noop() {
+		let mut map = CodepointRangeMap::default();
+		map.set(10, 19, |v| *v = 1);
+		map.remove(20, 29);
+		map.remove(0, 9);
+
+		assert_eq!(map.count(), 1);
+		assert!(
+			map.get(0)
+				== &CodepointRange {
+					first: 10,
+					last: 19,
+					value: 1
+				}
+		);
+	}
+
+	#[test]
+	fn set_past_max_codepoint_panics() {
+		assert_panic!("last 110000 is greater than 10FFFF" in {
+			let mut map: CodepointRangeMap<()> = Default::default();
+			map.set(0, 0x110000, |_| {});
+		});
+	}
+
+	#[test]
+	fn set_accepts_full_codespace_and_surrogates() {
+		let mut map = CodepointRangeMap::default();
+		map.set(0xD800, 0xDFFF, |v| *v = 1);
+		map.set(0x10FFFF, 0x10FFFF, |v| *v = 2);
+		assert_eq!(map.count(), 2);
+		assert_eq!(map.find(0xDC00).map(|x| x.value), Some(1));
+		assert_eq!(map.find(0x10FFFF).map(|x| x.value), Some(2));
+	}
+
 	#[test]
 	fn add_invalid_range_panics() {
 		assert_panic!("invalid range" in {
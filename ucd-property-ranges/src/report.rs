@@ -0,0 +1,112 @@
+//! Helpers for reporting on the size of property tables.
+
+use crate::RangeTable;
+
+/// Generate a text report with the number of ranges, distinct values, and
+/// mapped codepoints for each of the given tables.
+///
+/// This is meant to help choosing the representation for each property in
+/// the generated code (e.g. `match`, binary search, or a two-stage table).
+///
+/// ```
+/// # use ucd_property_ranges::{report::range_counts, *};
+/// #[derive(Clone, PartialEq)]
+/// struct Key;
+///
+/// impl PropertyKey for Key {
+///     type Value = bool;
+/// }
+///
+/// let mut table = RangeTable::new();
+/// table.set_range(0..=9, Key, true);
+/// println!("{}", range_counts(&[("Some_Property", &table)]));
+/// ```
+pub fn range_counts(tables: &[(&str, &RangeTable)]) -> String {
+	const HEADER: [&str; 4] = ["property", "ranges", "values", "codepoints"];
+
+	let rows = tables
+		.iter()
+		.map(|(name, table)| {
+			let mut distinct = Vec::new();
+			let mut codepoints = 0u64;
+			for row in table.rows() {
+				codepoints += (row.last - row.first) as u64 + 1;
+				if !distinct.iter().any(|x| row.has_same_properties(x)) {
+					distinct.push(row);
+				}
+			}
+			[
+				name.to_string(),
+				table.count().to_string(),
+				distinct.len().to_string(),
+				codepoints.to_string(),
+			]
+		})
+		.collect::<Vec<_>>();
+
+	let mut widths = HEADER.map(|x| x.len());
+	for row in rows.iter() {
+		for (width, cell) in widths.iter_mut().zip(row.iter()) {
+			*width = std::cmp::max(*width, cell.len());
+		}
+	}
+
+	let mut output = String::new();
+	let header = HEADER.map(|x| x.to_string());
+	for row in std::iter::once(&header).chain(rows.iter()) {
+		output.push_str(&format!("{:1$}", row[0], widths[0]));
+		for (cell, width) in row.iter().zip(widths.iter()).skip(1) {
+			output.push_str(&format!("  {:>1$}", cell, width));
+		}
+		output.push('\n');
+	}
+	output
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::PropertyKey;
+
+	#[derive(Clone, PartialEq)]
+	struct Key(&'static str);
+
+	impl PropertyKey for Key {
+		type Value = u32;
+	}
+
+	#[test]
+	fn reports_range_counts() {
+		let mut table_a = RangeTable::new();
+		table_a.set_range(0..=9, Key("a"), 1);
+		table_a.set_range(10..=19, Key("a"), 2);
+		table_a.set_range(30..=39, Key("a"), 1);
+
+		let mut table_b = RangeTable::new();
+		table_b.set_range(0..=0x10FFFF, Key("b"), 0);
+		table_b.set_range(0x41..=0x5A, Key("b"), 1);
+
+		let report = range_counts(&[("Prop_A", &table_a), ("Longer_Prop_B", &table_b)]);
+		assert_eq!(
+			report,
+			concat!(
+				"property       ranges  values  codepoints\n",
+				"Prop_A              3       2          30\n",
+				"Longer_Prop_B       3       2     1114112\n",
+			)
+		);
+	}
+
+	#[test]
+	fn reports_empty_table() {
+		let table = RangeTable::new();
+		let report = range_counts(&[("Empty", &table)]);
+		assert_eq!(
+			report,
+			concat!(
+				"property  ranges  values  codepoints\n",
+				"Empty          0       0           0\n",
+			)
+		);
+	}
+}
@@ -1,6 +1,7 @@
 use std::{
 	any::Any,
 	ops::{Range, RangeInclusive},
+	rc::Rc,
 };
 
 use crate::ranges::CodepointRangeMap;
@@ -54,8 +55,13 @@ use crate::ranges::CodepointRangeMap;
 /// assert_eq!(row.get(IntProperty("answer")), None);
 /// assert_eq!(row.get(IntProperty("some")), Some(123));
 /// ```
+///
+/// Property values are shared between the sub-ranges created when splitting
+/// a range. Use [`RangeTable::with_interning`] to also share equal values
+/// set by separate calls.
 pub struct RangeTable {
 	ranges: CodepointRangeMap<Properties>,
+	interned: Option<Vec<InternedValues>>,
 }
 
 impl Default for RangeTable {
@@ -68,7 +74,45 @@ impl RangeTable {
 	pub fn new() -> Self {
 		RangeTable {
 			ranges: Default::default(),
+			interned: None,
+		}
+	}
+
+	/// Create a new table that interns property values, so that equal
+	/// values for the same key share a single allocation.
+	///
+	/// This reduces memory usage for large tables where a small set of
+	/// values is repeated across many ranges (e.g. the general category).
+	/// Interned values are grouped by key, and each [`set_range`] compares
+	/// the new value only with the existing values for the same key.
+	///
+	/// [`set_range`]: RangeTable::set_range
+	pub fn with_interning() -> Self {
+		RangeTable {
+			ranges: Default::default(),
+			interned: Some(Vec::new()),
+		}
+	}
+
+	/// Create a table for a single property from ranges that are sorted and
+	/// don't overlap (e.g. as parsed from a UCD file).
+	///
+	/// This is faster than calling [`set_range`](RangeTable::set_range) for
+	/// each range, since the ranges are appended without splitting. In debug
+	/// builds, this will panic if the ranges are not sorted or overlap.
+	pub fn from_sorted_ranges<T: PropertyKey>(
+		ranges: impl Iterator<Item = (u32, u32, T::Value)>,
+		key: T,
+	) -> RangeTable {
+		let mut table = RangeTable::new();
+		for (sta, end, value) in ranges {
+			let value = table.intern(&key, value);
+			let properties = Properties {
+				values: vec![(key.as_base(), value)],
+			};
+			table.ranges.push(sta, end, properties);
 		}
+		table
 	}
 
 	/// Return the number of unique ranges mapped.
@@ -79,11 +123,26 @@ impl RangeTable {
 		self.ranges.count()
 	}
 
+	/// Return the total number of codepoints that have at least one property
+	/// set in the table.
+	pub fn codepoint_count(&self) -> u64 {
+		self.ranges.codepoint_count()
+	}
+
+	/// Return the number of hex digits needed to print the largest codepoint
+	/// in the table, with a minimum of 4 as in the UCD files.
+	///
+	/// This is used to pad codepoints consistently in generated output.
+	pub fn max_hex_width(&self) -> usize {
+		let last = self.ranges.iter().next_back().map(|x| x.last).unwrap_or(0);
+		format!("{:04X}", last).len()
+	}
+
 	/// Return a range by its index. Ranges don't overlap and are stored in
 	/// sorted order.
 	///
 	/// This will panic if the index is out of bounds.
-	pub fn get(&self, index: usize) -> RangeRow {
+	pub fn get(&self, index: usize) -> RangeRow<'_> {
 		let range = self.ranges.get(index);
 		RangeRow {
 			first: range.first,
@@ -92,6 +151,209 @@ impl RangeTable {
 		}
 	}
 
+	/// Iterate over the ranges in sorted order.
+	pub fn rows(&self) -> impl DoubleEndedIterator<Item = RangeRow<'_>> {
+		self.ranges.iter().map(|range| RangeRow {
+			first: range.first,
+			last: range.last,
+			properties: &range.value,
+		})
+	}
+
+	/// Return the range containing the given codepoint, or [`None`] if the
+	/// codepoint is not mapped.
+	pub fn find(&self, code: u32) -> Option<RangeRow<'_>> {
+		let range = self.ranges.find(code)?;
+		Some(RangeRow {
+			first: range.first,
+			last: range.last,
+			properties: &range.value,
+		})
+	}
+
+	/// Return a property's value for the given codepoint or [`None`] if it
+	/// is not set.
+	pub fn lookup<T: PropertyKey>(&self, code: u32, key: T) -> Option<T::Value> {
+		self.find(code).and_then(|row| row.get(key))
+	}
+
+	/// Return an owned snapshot of a property's values for the ranges in the
+	/// table, which does not borrow the table.
+	///
+	/// Contiguous ranges with the same value for the property are merged.
+	/// Ranges in the table that don't have the property set are included
+	/// with a [`None`] value.
+	pub fn collect_property<T: PropertyKey + 'static>(
+		&self,
+		key: T,
+	) -> Vec<(RangeInclusive<u32>, Option<T::Value>)> {
+		let mut output: Vec<(RangeInclusive<u32>, Option<T::Value>)> = Vec::new();
+		for row in self.rows() {
+			let value = row.get(key.clone());
+			match output.last_mut() {
+				Some((range, last)) if *range.end() + 1 == row.first && *last == value => {
+					*range = *range.start()..=row.last;
+				}
+				_ => output.push((row.first..=row.last, value)),
+			}
+		}
+		output
+	}
+
+	/// Return a dense array with a property's value for each codepoint in
+	/// the `0..len` range. Codepoints without the property are set to the
+	/// given default.
+	pub fn to_dense<T: PropertyKey>(
+		&self,
+		key: T,
+		len: usize,
+		default: &T::Value,
+	) -> Vec<T::Value> {
+		(0..len as u32)
+			.map(|code| {
+				self.lookup(code, key.clone())
+					.unwrap_or_else(|| default.clone())
+			})
+			.collect()
+	}
+
+	/// Check that a property's values for the table match the given dense
+	/// array, where each codepoint is an index in the array. Codepoints for
+	/// which the property is not set must match the default value.
+	///
+	/// This will panic on the first mismatched codepoint.
+	pub fn verify_dense<T: PropertyKey>(&self, key: T, dense: &[T::Value], default: &T::Value) {
+		for (code, expected) in dense.iter().enumerate() {
+			let code = code as u32;
+			let actual = self.lookup(code, key.clone());
+			let actual = actual.as_ref().unwrap_or(default);
+			if actual != expected {
+				panic!("RangeTable: dense value mismatch at codepoint {:04X}", code);
+			}
+		}
+	}
+
+	/// Return the distinct values for a property with the number of
+	/// codepoints for each, sorted with the most frequent value first.
+	///
+	/// Values with the same count are kept in order of first appearance.
+	/// Codepoints without the property are not counted.
+	pub fn values_by_frequency<T: PropertyKey>(&self, key: T) -> Vec<(T::Value, usize)> {
+		let mut values: Vec<(T::Value, usize)> = Vec::new();
+		for row in self.rows() {
+			if let Some(value) = row.get(key.clone()) {
+				let count = (row.last - row.first + 1) as usize;
+				match values.iter_mut().find(|(it, _)| it == &value) {
+					Some((_, total)) => *total += count,
+					None => values.push((value, count)),
+				}
+			}
+		}
+		values.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+		values
+	}
+
+	/// Merge adjacent ranges with the same set of property values.
+	///
+	/// Setting properties for overlapping ranges splits them, which may leave
+	/// contiguous ranges with equal properties. This minimizes the table by
+	/// merging those ranges back.
+	pub fn coalesce(&mut self) {
+		self.ranges.coalesce();
+	}
+
+	/// Return a new table with a boolean property set for the codepoints
+	/// that have it `true` in either this table or the other.
+	///
+	/// Only the codepoints where the result is `true` are mapped in the new
+	/// table, and codepoints without the property count as `false`.
+	pub fn union_with<T: PropertyKey<Value = bool>>(
+		&self,
+		other: &RangeTable,
+		key: T,
+	) -> RangeTable {
+		self.combine_with(other, key, |a, b| a || b)
+	}
+
+	/// Return a new table with a boolean property set for the codepoints
+	/// that have it `true` in both this table and the other.
+	///
+	/// See [`RangeTable::union_with`].
+	pub fn intersect_with<T: PropertyKey<Value = bool>>(
+		&self,
+		other: &RangeTable,
+		key: T,
+	) -> RangeTable {
+		self.combine_with(other, key, |a, b| a && b)
+	}
+
+	/// Return a new table with a boolean property set for the codepoints
+	/// that have it `true` in this table but not in the other.
+	///
+	/// See [`RangeTable::union_with`].
+	pub fn subtract<T: PropertyKey<Value = bool>>(&self, other: &RangeTable, key: T) -> RangeTable {
+		self.combine_with(other, key, |a, b| a && !b)
+	}
+
+	fn combine_with<T: PropertyKey<Value = bool>, F: Fn(bool, bool) -> bool>(
+		&self,
+		other: &RangeTable,
+		key: T,
+		op: F,
+	) -> RangeTable {
+		let mut map = CodepointRangeMap::<(bool, bool)>::default();
+		for row in self.rows() {
+			if row.get(key.clone()) == Some(true) {
+				map.set(row.first, row.last, |value| value.0 = true);
+			}
+		}
+		for row in other.rows() {
+			if row.get(key.clone()) == Some(true) {
+				map.set(row.first, row.last, |value| value.1 = true);
+			}
+		}
+
+		let mut table = RangeTable::new();
+		for range in map.iter() {
+			if op(range.value.0, range.value.1) {
+				table.set_range(range.first..=range.last, key.clone(), true);
+			}
+		}
+		table.coalesce();
+		table
+	}
+
+	fn intern<T: PropertyKey>(&mut self, key: &T, value: T::Value) -> Rc<dyn Any> {
+		let interned = match self.interned.as_mut() {
+			Some(interned) => interned,
+			None => return Rc::from(T::box_value(value)),
+		};
+
+		let index = match interned
+			.iter()
+			.position(|(it_key, _)| key.equals_key(it_key))
+		{
+			Some(index) => index,
+			None => {
+				interned.push((key.as_base(), Vec::new()));
+				interned.len() - 1
+			}
+		};
+
+		let values = &mut interned[index].1;
+		match values
+			.iter()
+			.find(|it| key.equals_value(it.as_ref(), &value))
+		{
+			Some(value) => value.clone(),
+			None => {
+				let value: Rc<dyn Any> = Rc::from(T::box_value(value));
+				values.push(value.clone());
+				value
+			}
+		}
+	}
+
 	/// Set a property value for a range.
 	///
 	/// If the specified range partially overlaps with existing ranges, those
@@ -99,9 +361,10 @@ impl RangeTable {
 	pub fn set_range<R: CodeRange, T: PropertyKey>(&mut self, range: R, key: T, value: T::Value) {
 		let sta = range.start();
 		let end = range.end_inclusive();
+		let value = self.intern(&key, value);
 		self.ranges.set(sta, end, |property| {
 			let key = key.as_base();
-			let value = T::box_value(value.clone());
+			let value = value.clone();
 			for (prop_key, prop_value) in property.values.iter_mut() {
 				if prop_key.equals_key(&key) {
 					*prop_value = value;
@@ -111,6 +374,33 @@ impl RangeTable {
 			property.values.push((key, value));
 		});
 	}
+
+	/// Set a property value for a range that must not have the property set
+	/// for any of its codepoints.
+	///
+	/// This is useful when building a table for a single-valued property from
+	/// a file where each codepoint must be listed only once.
+	///
+	/// This will panic with the first overlapping codepoint if the property is
+	/// already set for any codepoint in the range.
+	pub fn set_range_once<R: CodeRange, T: PropertyKey>(
+		&mut self,
+		range: R,
+		key: T,
+		value: T::Value,
+	) {
+		let sta = range.start();
+		let end = range.end_inclusive();
+		for it in self.ranges.overlapping(sta, end) {
+			if it.value.get(key.clone()).is_some() {
+				panic!(
+					"RangeTable: property already set at codepoint {:04X}",
+					it.first.max(sta)
+				);
+			}
+		}
+		self.set_range(sta..=end, key, value);
+	}
 }
 
 /// Row of data in a [`RangeTable`] representing a single range with uniform
@@ -126,10 +416,50 @@ impl<'a> RangeRow<'a> {
 	pub fn get<T: PropertyKey + 'static>(&self, key: T) -> Option<T::Value> {
 		self.properties.get(key)
 	}
+
+	/// Return a reference to a property's value for this range or [`None`]
+	/// if it is not set.
+	///
+	/// Unlike [`get`](RangeRow::get) this borrows the stored value instead
+	/// of cloning it.
+	pub fn get_ref<T: PropertyKey + 'static>(&self, key: T) -> Option<&'a T::Value> {
+		self.properties.get_ref(key)
+	}
+
+	/// Returns true if both rows have the same set of property values,
+	/// regardless of their ranges.
+	pub fn has_same_properties(&self, other: &RangeRow) -> bool {
+		self.properties == other.properties
+	}
+
+	/// Return the number of properties set for this range.
+	pub fn property_count(&self) -> usize {
+		self.properties.len()
+	}
+
+	/// Return a debug description for each property set in this range, in
+	/// the order they were first set.
+	///
+	/// Each property is rendered as `Key: value`, with the key type name
+	/// and the value as returned by [`PropertyKey::format_value`]. Keys that
+	/// don't format their values are rendered as just `Key`.
+	pub fn describe(&self) -> Vec<String> {
+		let values = self.properties.values.iter();
+		values
+			.map(|(key, value)| match key.debug_value(value.as_ref()) {
+				Some(value) => format!("{}: {}", key.key_name(), value),
+				None => key.key_name().to_string(),
+			})
+			.collect()
+	}
 }
 
+type PropertyValue = (Box<dyn PropertyKeyBase>, Rc<dyn Any>);
+
+type InternedValues = (Box<dyn PropertyKeyBase>, Vec<Rc<dyn Any>>);
+
 struct Properties {
-	values: Vec<(Box<dyn PropertyKeyBase>, Box<dyn Any>)>,
+	values: Vec<PropertyValue>,
 }
 
 impl Properties {
@@ -139,12 +469,20 @@ impl Properties {
 		}
 	}
 
+	pub fn len(&self) -> usize {
+		self.values.len()
+	}
+
 	pub fn get<T: PropertyKey + 'static>(&self, key: T) -> Option<T::Value> {
+		self.get_ref(key).cloned()
+	}
+
+	pub fn get_ref<T: PropertyKey + 'static>(&self, key: T) -> Option<&T::Value> {
 		let key = key.as_base();
 		for (prop_key, prop_val) in self.values.iter() {
 			if prop_key.equals_key(&key) {
 				let val = prop_val.downcast_ref::<T::Value>();
-				return Some(val.unwrap().clone());
+				return Some(val.unwrap());
 			}
 		}
 		None
@@ -157,13 +495,24 @@ impl Default for Properties {
 	}
 }
 
+impl PartialEq for Properties {
+	fn eq(&self, other: &Self) -> bool {
+		if self.values.len() != other.values.len() {
+			return false;
+		}
+		self.values.iter().all(|(key, value)| {
+			other.values.iter().any(|(other_key, other_value)| {
+				key.equals_key(other_key) && key.equals_value(value.as_ref(), other_value.as_ref())
+			})
+		})
+	}
+}
+
 impl Clone for Properties {
 	fn clone(&self) -> Self {
 		let mut clone = Properties::new();
 		for (my_key, val) in self.values.iter() {
-			clone
-				.values
-				.push((my_key.as_base(), my_key.clone_value(val)));
+			clone.values.push((my_key.as_base(), val.clone()));
 		}
 		clone
 	}
@@ -194,6 +543,15 @@ pub trait PropertyKey: PropertyKeyBase + Clone + PartialEq + 'static {
 	fn box_value(value: Self::Value) -> Box<dyn Any> {
 		Box::new(value)
 	}
+
+	/// Format a value for [`RangeRow::describe`].
+	///
+	/// Values are not required to implement [`Debug`](std::fmt::Debug), so
+	/// this returns [`None`] by default. Override it to include the value in
+	/// the description.
+	fn format_value(_value: &Self::Value) -> Option<String> {
+		None
+	}
 }
 
 /// Base methods for [`PropertyKey`].
@@ -201,10 +559,16 @@ pub trait PropertyKey: PropertyKeyBase + Clone + PartialEq + 'static {
 /// This trait provides the virtual interface for a [`PropertyKey`], which
 /// contains only methods compatible with a [`Box<dyn Any>`].
 pub trait PropertyKeyBase {
+	#[allow(clippy::borrowed_box)]
 	fn equals_key(&self, other: &Box<dyn PropertyKeyBase>) -> bool;
+	fn equals_value(&self, a: &dyn Any, b: &dyn Any) -> bool;
 	fn as_any(&self) -> Box<dyn Any>;
 	fn as_base(&self) -> Box<dyn PropertyKeyBase>;
+	#[deprecated(note = "property values are now shared with `Rc` and cloned directly")]
+	#[allow(clippy::borrowed_box)]
 	fn clone_value(&self, value: &Box<dyn Any>) -> Box<dyn Any>;
+	fn key_name(&self) -> &'static str;
+	fn debug_value(&self, value: &dyn Any) -> Option<String>;
 }
 
 impl<T: PropertyKey> PropertyKeyBase for T {
@@ -216,6 +580,12 @@ impl<T: PropertyKey> PropertyKeyBase for T {
 		}
 	}
 
+	fn equals_value(&self, a: &dyn Any, b: &dyn Any) -> bool {
+		let a = a.downcast_ref::<T::Value>();
+		let b = b.downcast_ref::<T::Value>();
+		matches!((a, b), (Some(a), Some(b)) if a == b)
+	}
+
 	fn as_any(&self) -> Box<dyn Any> {
 		Box::new(self.clone())
 	}
@@ -228,6 +598,21 @@ impl<T: PropertyKey> PropertyKeyBase for T {
 		let value = value.downcast_ref::<T::Value>().unwrap();
 		Box::new(value.clone())
 	}
+
+	fn key_name(&self) -> &'static str {
+		// strip the module path, but not from generic arguments
+		let name = std::any::type_name::<T>();
+		let base = name.split('<').next().unwrap_or(name);
+		match base.rfind("::") {
+			Some(index) => &name[index + 2..],
+			None => name,
+		}
+	}
+
+	fn debug_value(&self, value: &dyn Any) -> Option<String> {
+		let value = value.downcast_ref::<T::Value>().unwrap();
+		T::format_value(value)
+	}
 }
 
 /// Trait implemented by ranges that can be used with [`RangeTable::set_range`].
@@ -262,6 +647,184 @@ impl CodeRange for RangeInclusive<u32> {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use tux::assert_panic;
+
+	#[test]
+	fn row_returns_property_by_reference() {
+		#[derive(Clone, PartialEq)]
+		struct Name;
+
+		impl PropertyKey for Name {
+			type Value = String;
+		}
+
+		let mut table = RangeTable::new();
+		table.set_range(0..10, Name, "some name".to_string());
+
+		let row = table.get(0);
+		let a = row.get_ref(Name).unwrap();
+		let b = table.get(0).get_ref(Name).unwrap();
+		assert_eq!(a, "some name");
+		assert!(std::ptr::eq(a, b));
+		assert!(std::ptr::eq(a.as_ptr(), b.as_ptr()));
+
+		#[derive(Clone, PartialEq)]
+		struct Other;
+
+		impl PropertyKey for Other {
+			type Value = String;
+		}
+
+		assert_eq!(row.get_ref(Other), None);
+	}
+
+	#[test]
+	fn interning_shares_equal_values() {
+		#[derive(Clone, PartialEq)]
+		struct Name;
+
+		impl PropertyKey for Name {
+			type Value = String;
+		}
+
+		#[derive(Clone, PartialEq)]
+		struct Other;
+
+		impl PropertyKey for Other {
+			type Value = String;
+		}
+
+		fn build(mut table: RangeTable) -> RangeTable {
+			for index in 0..1000 {
+				let name = if index % 2 == 0 { "even" } else { "odd" };
+				table.set_range(index * 2..=index * 2, Name, name.to_string());
+			}
+			table.set_range(0..=10, Other, "even".to_string());
+			table
+		}
+
+		let plain = build(RangeTable::new());
+		let interned = build(RangeTable::with_interning());
+		assert_eq!(plain.count(), interned.count());
+		for (a, b) in plain.rows().zip(interned.rows()) {
+			assert_eq!((a.first, a.last), (b.first, b.last));
+			assert!(a.has_same_properties(&b));
+			assert_eq!(a.get(Name), b.get(Name));
+			assert_eq!(a.get(Other), b.get(Other));
+		}
+
+		let first = interned.find(0).unwrap();
+		let third = interned.find(4).unwrap();
+		let a = first.get_ref(Name).unwrap();
+		let b = third.get_ref(Name).unwrap();
+		assert_eq!(a, "even");
+		assert!(std::ptr::eq(a, b));
+
+		// values are only shared for the same key
+		let other = first.get_ref(Other).unwrap();
+		assert_eq!(a, other);
+		assert!(!std::ptr::eq(a, other));
+
+		// without interning only split ranges share values
+		let a = plain.find(0).unwrap().get_ref(Name).unwrap();
+		let b = plain.find(4).unwrap().get_ref(Name).unwrap();
+		assert!(!std::ptr::eq(a, b));
+	}
+
+	#[test]
+	fn builds_table_from_sorted_ranges() {
+		#[derive(Clone, PartialEq)]
+		struct Name;
+
+		impl PropertyKey for Name {
+			type Value = String;
+		}
+
+		let ranges = [
+			(0x00, 0x1F, "control"),
+			(0x20, 0x20, "space"),
+			(0x41, 0x5A, "upper"),
+			(0x61, 0x7A, "lower"),
+			(0x10000, 0x10FFFF, "other"),
+		];
+
+		let mut expected = RangeTable::new();
+		for (sta, end, name) in ranges {
+			expected.set_range(sta..=end, Name, name.to_string());
+		}
+
+		let ranges = ranges.iter().map(|&(a, b, name)| (a, b, name.to_string()));
+		let table = RangeTable::from_sorted_ranges(ranges, Name);
+		assert_eq!(table.count(), expected.count());
+		assert_eq!(table.codepoint_count(), expected.codepoint_count());
+		assert_eq!(
+			table.collect_property(Name),
+			expected.collect_property(Name)
+		);
+		for (a, b) in table.rows().zip(expected.rows()) {
+			assert_eq!((a.first, a.last), (b.first, b.last));
+			assert!(a.has_same_properties(&b));
+		}
+		assert_eq!(table.lookup(0x50, Name), Some("upper".to_string()));
+		assert_eq!(table.lookup(0x30, Name), None);
+	}
+
+	#[test]
+	fn row_describes_properties() {
+		#[derive(Clone, PartialEq)]
+		struct Name;
+
+		impl PropertyKey for Name {
+			type Value = String;
+
+			fn format_value(value: &String) -> Option<String> {
+				Some(format!("{:?}", value))
+			}
+		}
+
+		#[derive(Clone, PartialEq)]
+		struct Count;
+
+		impl PropertyKey for Count {
+			type Value = u32;
+
+			fn format_value(value: &u32) -> Option<String> {
+				Some(value.to_string())
+			}
+		}
+
+		let mut table = RangeTable::new();
+		table.set_range(0..10, Name, "abc".to_string());
+		table.set_range(5..10, Count, 42);
+
+		let row = table.get(0);
+		assert_eq!(row.property_count(), 1);
+		assert_eq!(row.describe(), vec!["Name: \"abc\"".to_string()]);
+
+		let row = table.get(1);
+		assert_eq!(row.property_count(), 2);
+		assert_eq!(row.describe(), vec!["Name: \"abc\"", "Count: 42"]);
+	}
+
+	#[test]
+	fn row_describes_generic_keys() {
+		#[derive(Clone, PartialEq)]
+		struct Tagged<T>(std::marker::PhantomData<T>);
+
+		impl<T: Clone + PartialEq + 'static> PropertyKey for Tagged<T> {
+			type Value = u32;
+		}
+
+		let mut table = RangeTable::new();
+		table.set_range(0..10, Tagged::<u8>(Default::default()), 1);
+		table.set_range(0..10, Tagged::<Option<u8>>(Default::default()), 2);
+
+		let row = table.get(0);
+		assert_eq!(
+			row.describe(),
+			vec!["Tagged<u8>", "Tagged<core::option::Option<u8>>"]
+		);
+	}
 
 	#[test]
 	fn can_create_empty() {
@@ -352,6 +915,300 @@ mod tests {
 		assert_eq!(b.get(Key), Some(3));
 	}
 
+	#[test]
+	fn coalesce_merges_ranges_with_equal_properties() {
+		#[derive(Clone, PartialEq)]
+		struct Key(&'static str);
+
+		impl PropertyKey for Key {
+			type Value = u32;
+		}
+
+		let mut table = RangeTable::new();
+		table.set_range(0..=9, Key("a"), 1);
+		table.set_range(0..=9, Key("b"), 2);
+		table.set_range(10..=19, Key("b"), 2);
+		table.set_range(10..=19, Key("a"), 1);
+		table.set_range(20..=29, Key("a"), 1);
+		table.set_range(30..=39, Key("a"), 1);
+		table.set_range(30..=39, Key("b"), 3);
+		assert_eq!(table.count(), 4);
+
+		table.coalesce();
+		assert_eq!(table.count(), 3);
+
+		// same values set in a different order
+		let row = table.get(0);
+		assert_eq!((row.first, row.last), (0, 19));
+		assert_eq!(row.get(Key("a")), Some(1));
+		assert_eq!(row.get(Key("b")), Some(2));
+
+		// missing property
+		let row = table.get(1);
+		assert_eq!((row.first, row.last), (20, 29));
+		assert_eq!(row.get(Key("b")), None);
+
+		// different value
+		let row = table.get(2);
+		assert_eq!((row.first, row.last), (30, 39));
+		assert_eq!(row.get(Key("b")), Some(3));
+	}
+
+	#[test]
+	fn find_returns_range_for_codepoint() {
+		#[derive(Clone, PartialEq)]
+		struct Key;
+
+		impl PropertyKey for Key {
+			type Value = u32;
+		}
+
+		let mut table = RangeTable::new();
+		table.set_range(10..=19, Key, 1);
+		table.set_range(30..=39, Key, 3);
+
+		let row = table.find(15).unwrap();
+		assert_eq!(row.first, 10);
+		assert_eq!(row.last, 19);
+		assert_eq!(row.get(Key), Some(1));
+
+		assert_eq!(table.find(30).unwrap().get(Key), Some(3));
+		assert!(table.find(25).is_none());
+		assert!(table.find(40).is_none());
+	}
+
+	#[test]
+	fn lookup_returns_value_for_codepoint() {
+		#[derive(Clone, PartialEq)]
+		struct Key;
+
+		impl PropertyKey for Key {
+			type Value = u32;
+		}
+
+		let mut table = RangeTable::new();
+		table.set_range(10..=19, Key, 1);
+		assert_eq!(table.lookup(9, Key), None);
+		assert_eq!(table.lookup(10, Key), Some(1));
+		assert_eq!(table.lookup(19, Key), Some(1));
+		assert_eq!(table.lookup(20, Key), None);
+	}
+
+	#[test]
+	fn set_range_once_accepts_disjoint_ranges() {
+		#[derive(Clone, PartialEq)]
+		struct Key(&'static str);
+
+		impl PropertyKey for Key {
+			type Value = u32;
+		}
+
+		let mut table = RangeTable::new();
+		table.set_range(0..=100, Key("other"), 0);
+		table.set_range_once(10..=19, Key("a"), 1);
+		table.set_range_once(20..=29, Key("a"), 2);
+		table.set_range_once(5..=9, Key("a"), 3);
+		table.set_range_once(15..=25, Key("b"), 4);
+		assert_eq!(table.lookup(5, Key("a")), Some(3));
+		assert_eq!(table.lookup(19, Key("a")), Some(1));
+		assert_eq!(table.lookup(20, Key("a")), Some(2));
+		assert_eq!(table.lookup(20, Key("b")), Some(4));
+	}
+
+	#[test]
+	fn set_range_once_panics_on_overlap() {
+		#[derive(Clone, PartialEq)]
+		struct Key;
+
+		impl PropertyKey for Key {
+			type Value = u32;
+		}
+
+		assert_panic!("property already set at codepoint 0014" in {
+			let mut table = RangeTable::new();
+			table.set_range_once(10..=19, Key, 1);
+			table.set_range_once(20..=29, Key, 2);
+			table.set_range_once(0..=9, Key, 3);
+			table.set_range_once(30..=39, Key, 4);
+			table.set_range_once(20..=20, Key, 5);
+		});
+	}
+
+	#[test]
+	fn returns_values_by_frequency() {
+		#[derive(Clone, PartialEq)]
+		struct Key(&'static str);
+
+		impl PropertyKey for Key {
+			type Value = &'static str;
+		}
+
+		let mut table = RangeTable::new();
+		table.set_range(0..=9, Key("a"), "x");
+		table.set_range(10..=14, Key("a"), "y");
+		table.set_range(15..=24, Key("a"), "z");
+		table.set_range(30..=39, Key("a"), "y");
+		table.set_range(40..=99, Key("b"), "w");
+		assert_eq!(
+			table.values_by_frequency(Key("a")),
+			vec![("y", 15), ("x", 10), ("z", 10)]
+		);
+		assert_eq!(table.values_by_frequency(Key("c")), vec![]);
+	}
+
+	mod set_operations {
+		use super::super::*;
+
+		#[derive(Clone, PartialEq)]
+		struct Key;
+
+		impl PropertyKey for Key {
+			type Value = bool;
+		}
+
+		fn table(ranges: &[(u32, u32)]) -> RangeTable {
+			let mut table = RangeTable::new();
+			table.set_range(0..=99, Key, false);
+			for &(sta, end) in ranges {
+				table.set_range(sta..=end, Key, true);
+			}
+			table
+		}
+
+		fn ranges(table: &RangeTable) -> Vec<(u32, u32)> {
+			let rows = table.rows().filter(|row| row.get(Key) == Some(true));
+			rows.map(|row| (row.first, row.last)).collect()
+		}
+
+		#[test]
+		fn with_disjoint_ranges() {
+			let a = table(&[(0, 9), (30, 39)]);
+			let b = table(&[(10, 19), (50, 59)]);
+			assert_eq!(
+				ranges(&a.union_with(&b, Key)),
+				vec![(0, 19), (30, 39), (50, 59)]
+			);
+			assert_eq!(ranges(&a.intersect_with(&b, Key)), vec![]);
+			assert_eq!(ranges(&a.subtract(&b, Key)), vec![(0, 9), (30, 39)]);
+		}
+
+		#[test]
+		fn with_overlapping_ranges() {
+			let a = table(&[(0, 19)]);
+			let b = table(&[(10, 29)]);
+			assert_eq!(ranges(&a.union_with(&b, Key)), vec![(0, 29)]);
+			assert_eq!(ranges(&a.intersect_with(&b, Key)), vec![(10, 19)]);
+			assert_eq!(ranges(&a.subtract(&b, Key)), vec![(0, 9)]);
+			assert_eq!(ranges(&b.subtract(&a, Key)), vec![(20, 29)]);
+		}
+
+		#[test]
+		fn with_contained_ranges() {
+			let a = table(&[(0, 29)]);
+			let b = table(&[(10, 19)]);
+			assert_eq!(ranges(&a.union_with(&b, Key)), vec![(0, 29)]);
+			assert_eq!(ranges(&a.intersect_with(&b, Key)), vec![(10, 19)]);
+			assert_eq!(ranges(&a.subtract(&b, Key)), vec![(0, 9), (20, 29)]);
+			assert_eq!(ranges(&b.subtract(&a, Key)), vec![]);
+
+			let result = a.subtract(&b, Key);
+			assert_eq!(result.count(), 2);
+			assert_eq!(result.lookup(15, Key), None);
+		}
+	}
+
+	#[test]
+	fn returns_codepoint_count() {
+		#[derive(Clone, PartialEq)]
+		struct Key;
+
+		impl PropertyKey for Key {
+			type Value = u32;
+		}
+
+		let mut table = RangeTable::new();
+		assert_eq!(table.codepoint_count(), 0);
+
+		table.set_range(10..=19, Key, 1);
+		table.set_range(15..=24, Key, 2);
+		table.set_range(30..=30, Key, 3);
+		assert_eq!(table.codepoint_count(), 16);
+
+		table.set_range(0..=0x10FFFF, Key, 0);
+		assert_eq!(table.codepoint_count(), 0x110000);
+	}
+
+	#[test]
+	fn collects_property_snapshot() {
+		#[derive(Clone, PartialEq)]
+		struct Key(&'static str);
+
+		impl PropertyKey for Key {
+			type Value = String;
+		}
+
+		let mut table = RangeTable::new();
+		table.set_range(10..=19, Key("a"), "x".to_string());
+		table.set_range(20..=29, Key("a"), "x".to_string());
+		table.set_range(15..=24, Key("b"), "y".to_string());
+		table.set_range(40..=49, Key("b"), "z".to_string());
+
+		let snapshot = table.collect_property(Key("a"));
+		assert_eq!(
+			snapshot,
+			vec![(10..=29, Some("x".to_string())), (40..=49, None)]
+		);
+
+		// the snapshot can be sent to another thread
+		let snapshot = std::thread::spawn(move || snapshot).join().unwrap();
+		for (range, value) in snapshot {
+			for code in range {
+				assert_eq!(table.lookup(code, Key("a")), value);
+			}
+		}
+	}
+
+	#[test]
+	fn returns_max_hex_width() {
+		#[derive(Clone, PartialEq)]
+		struct Key;
+
+		impl PropertyKey for Key {
+			type Value = u32;
+		}
+
+		let mut table = RangeTable::new();
+		assert_eq!(table.max_hex_width(), 4);
+
+		table.set_range(0x41..=0x5A, Key, 1);
+		table.set_range(0xFFF0..=0xFFFF, Key, 2);
+		assert_eq!(table.max_hex_width(), 4);
+
+		table.set_range(0x1F600..=0x1F64F, Key, 3);
+		assert_eq!(table.max_hex_width(), 5);
+
+		table.set_range(0x10FFFF..=0x10FFFF, Key, 4);
+		assert_eq!(table.max_hex_width(), 6);
+	}
+
+	#[test]
+	fn converts_to_dense_array() {
+		#[derive(Clone, PartialEq)]
+		struct Key;
+
+		impl PropertyKey for Key {
+			type Value = u32;
+		}
+
+		let mut table = RangeTable::new();
+		table.set_range(2..=3, Key, 1);
+		table.set_range(5..=5, Key, 2);
+
+		let dense = table.to_dense(Key, 8, &0);
+		assert_eq!(dense, vec![0, 0, 1, 1, 0, 2, 0, 0]);
+		table.verify_dense(Key, &dense, &0);
+	}
+
 	#[test]
 	fn supports_non_inclusive_range() {
 		#[derive(Clone, PartialEq)]
@@ -367,12 +1224,12 @@ mod tests {
 		assert_eq!(table.get(0).last, 9);
 	}
 
+	impl PropertyKey for &'static str {
+		type Value = u32;
+	}
+
 	#[test]
 	fn returns_none_for_unset_property() {
-		impl PropertyKey for &'static str {
-			type Value = u32;
-		}
-
 		let mut table = RangeTable::new();
 		table.set_range(0..10, "key", 0);
 
@@ -386,6 +1243,32 @@ mod tests {
 		type Value = u32;
 	}
 
+	#[test]
+	fn verify_dense_panics_on_mismatch() {
+		assert_panic!("mismatch at codepoint 0005" in {
+			let mut table = RangeTable::new();
+			table.set_range(2..=3, Key("x"), 1);
+			table.set_range(5..=5, Key("x"), 2);
+
+			let mut dense = table.to_dense(Key("x"), 8, &0);
+			dense[5] = 3;
+			table.verify_dense(Key("x"), &dense, &0);
+		});
+	}
+
+	#[test]
+	fn verify_dense_panics_on_mismatch_with_default() {
+		assert_panic!("mismatch at codepoint 0007" in {
+			let mut table = RangeTable::new();
+			table.set_range(2..=3, Key("x"), 1);
+			table.set_range(5..=5, Key("x"), 2);
+
+			let mut dense = table.to_dense(Key("x"), 8, &0);
+			dense[7] = 1;
+			table.verify_dense(Key("x"), &dense, &0);
+		});
+	}
+
 	macro_rules! check_table {
 		($($tokens:tt)*) => {
 			let mut table = RangeTable::new();
@@ -0,0 +1,15 @@
+pub static NAME_INDEX_VALUES: [Option<&'static str>; 4] = [
+	None,
+	Some("upper"),
+	Some("digit"),
+	Some("under \"score\""),
+];
+
+pub fn name_index(code: u32) -> u8 {
+	match code {
+		0x0030..=0x0039 => 2,
+		0x0041..=0x005A => 1,
+		0x005F => 3,
+		_ => 0,
+	}
+}
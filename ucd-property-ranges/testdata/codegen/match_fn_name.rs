@@ -0,0 +1,10 @@
+pub fn name(code: u32) -> Option<&'static str> {
+	match code {
+		0x0030..=0x0031 => Some("digit"),
+		0x0032..=0x0039 => Some("digit"),
+		0x0041 => Some("upper"),
+		0x0042..=0x005A => Some("upper"),
+		0x005F => Some("under \"score\""),
+		_ => None,
+	}
+}
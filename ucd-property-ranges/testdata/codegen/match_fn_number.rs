@@ -0,0 +1,7 @@
+pub fn number(code: u32) -> Option<Option<u32>> {
+	match code {
+		0x0030..=0x0031 => Some(Some(1)),
+		0x0041 => Some(None),
+		_ => None,
+	}
+}
@@ -0,0 +1,20 @@
+pub static SAMPLE: [(u32, u32, &'static str); 5] = [
+	(0x0030, 0x0039, "digit"),
+	(0x0041, 0x0059, "upper"),
+	(0x005A, 0x005A, "other"),
+	(0x005B, 0x0061, "other"),
+	(0x4E00, 0x9FFF, "ideograph"),
+];
+
+pub fn sample(code: u32) -> Option<&'static str> {
+	let index = SAMPLE.binary_search_by(|&(first, last, _)| {
+		if code < first {
+			std::cmp::Ordering::Greater
+		} else if code > last {
+			std::cmp::Ordering::Less
+		} else {
+			std::cmp::Ordering::Equal
+		}
+	});
+	index.ok().map(|index| SAMPLE[index].2)
+}
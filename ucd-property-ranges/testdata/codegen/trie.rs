@@ -0,0 +1,20 @@
+pub static TRIE_NAME_INDEX: [u8; 3] = [
+	0, 1, 2,
+];
+
+pub static TRIE_NAME_DATA: [Option<&'static str>; 96] = [
+	None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+	None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+	None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+	Some("digit"), Some("digit"), Some("digit"), Some("digit"), Some("digit"), Some("digit"), Some("digit"), Some("digit"), Some("digit"), Some("digit"), None, None, None, None, None, None,
+	None, Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"),
+	Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), None, None, None, None, Some("under \"score\""),
+];
+
+pub fn trie_name(code: u32) -> Option<&'static str> {
+	let block = match TRIE_NAME_INDEX.get((code >> 5) as usize) {
+		Some(block) => *block as usize,
+		None => return None,
+	};
+	TRIE_NAME_DATA[(block << 5) | (code as usize & 0x1F)]
+}
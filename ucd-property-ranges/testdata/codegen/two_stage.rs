@@ -0,0 +1,18 @@
+pub static STAGE_NAME_INDEX: [u8; 6] = [
+	0, 0, 0, 1, 2, 3,
+];
+
+pub static STAGE_NAME_DATA: [Option<&'static str>; 64] = [
+	None, None, None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+	Some("digit"), Some("digit"), Some("digit"), Some("digit"), Some("digit"), Some("digit"), Some("digit"), Some("digit"), Some("digit"), Some("digit"), None, None, None, None, None, None,
+	None, Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"),
+	Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), Some("upper"), None, None, None, None, Some("under \"score\""),
+];
+
+pub fn stage_name(code: u32) -> Option<&'static str> {
+	let block = match STAGE_NAME_INDEX.get((code >> 4) as usize) {
+		Some(block) => *block as usize,
+		None => return None,
+	};
+	STAGE_NAME_DATA[(block << 4) | (code as usize & 0xF)]
+}